@@ -0,0 +1,52 @@
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+/// Calls the same instance functions in a loop, which should almost always be
+/// resolved through the inline cache.
+#[bench]
+fn instance_fn_cache(b: &mut Bencher) -> runestick::Result<()> {
+    let vm = rune_tests::rune_vm! {
+        struct Counter { value }
+
+        impl Counter {
+            fn add(self, n) {
+                self.value += n;
+            }
+        }
+
+        pub fn main(n) {
+            let counter = Counter { value: 0 };
+            let values = [];
+            let i = 0;
+
+            while i < n {
+                counter.add(i);
+                values.push(i);
+                i += 1;
+            }
+
+            counter.value + values.len()
+        }
+    };
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    // NB: step through a single execution so that we can inspect the cache
+    // once it's done.
+    let mut execution = vm.clone().execute(entry, (1000,))?;
+    while execution.step()?.is_none() {}
+
+    let cache = execution.vm()?.inline_cache();
+    assert!(cache.hits() > cache.misses() * 100);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, (1000,));
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+/// A loop which only consists of cheap instructions, so that the time spent is
+/// dominated by instruction dispatch.
+#[bench]
+fn tight_loop(b: &mut Bencher) -> runestick::Result<()> {
+    let vm = rune_tests::rune_vm! {
+        pub fn main(n) {
+            let i = 0;
+            let sum = 0;
+
+            while i < n {
+                sum = sum + i;
+                i = i + 1;
+            }
+
+            sum
+        }
+    };
+
+    let entry = runestick::Hash::type_hash(&["main"]);
+
+    b.iter(|| {
+        let execution = vm.clone().execute(entry, (10000,));
+        let mut execution = execution.expect("successful setup");
+        execution.complete().expect("successful execution")
+    });
+
+    Ok(())
+}
@@ -51,7 +51,7 @@
 
 use anyhow::{Context as _, Result};
 use rune::termcolor::{ColorChoice, StandardStream};
-use rune::{DumpInstructions as _, EmitDiagnostics as _, EmitSource as _};
+use rune::{DumpInstructions as _, EmitDiagnostics as _};
 use std::fs;
 use std::io;
 use std::io::Write as _;
@@ -137,6 +137,14 @@ struct SharedArgs {
     /// macros[=<true/false>] - Enable or disable macros (experimental).
     ///
     /// bytecode[=<true/false>] - Enable or disable bytecode caching (experimental).
+    ///
+    /// panic-on-divide-by-zero[=<true/false>] - Panic instead of raising an error when dividing by zero.
+    ///
+    /// shadowed-const-error[=<true/false>] - Raise an error instead of a warning when a binding shadows a constant.
+    ///
+    /// warnings-as-errors[=<true/false>] - Fail compilation if any warnings are produced.
+    ///
+    /// embed-sources[=<true/false>] - Embed the text of compiled sources in the unit.
     #[structopt(name = "option", short = "O", number_of_values = 1)]
     compiler_options: Vec<String>,
 
@@ -403,9 +411,9 @@ fn load_path(
 
     // TODO: how do we deal with tests discovery for bytecode loading
     let maybe_unit = if use_cache {
-        let f = fs::File::open(&bytecode_path)?;
+        let bytes = fs::read(&bytecode_path)?;
 
-        match bincode::deserialize_from::<_, Unit>(f) {
+        match Unit::from_bytes(&bytes) {
             Ok(unit) => {
                 log::trace!("using cache: {}", bytecode_path.display());
                 Some(Arc::new(unit))
@@ -447,8 +455,7 @@ fn load_path(
 
             if options.bytecode {
                 log::trace!("serializing cache: {}", bytecode_path.display());
-                let f = fs::File::create(&bytecode_path)?;
-                bincode::serialize_into(f, &unit)?;
+                fs::write(&bytecode_path, unit.to_bytes()?)?;
             }
 
             let test_finder = match Rc::try_unwrap(test_finder) {
@@ -754,33 +761,8 @@ async fn do_trace(
                 writeln!(out, "fn {} ({}):", signature, hash)?;
             }
 
-            let debug = vm
-                .unit()
-                .debug_info()
-                .and_then(|d| d.instruction_at(vm.ip()));
-
-            if with_source {
-                let debug_info = debug.and_then(|d| sources.get(d.source_id).map(|s| (s, d.span)));
-                if let Some((source, span)) = debug_info {
-                    source.emit_source_line(&mut out, span)?;
-                }
-            }
-
-            if let Some(label) = debug.and_then(|d| d.label.as_ref()) {
-                writeln!(out, "{}:", label)?;
-            }
-
-            if let Some(inst) = vm.unit().instruction_at(vm.ip()) {
-                write!(out, "  {:04} = {}", vm.ip(), inst)?;
-            } else {
-                write!(out, "  {:04} = *out of bounds*", vm.ip())?;
-            }
-
-            if let Some(comment) = debug.and_then(|d| d.comment.as_ref()) {
-                write!(out, " // {}", comment)?;
-            }
-
-            writeln!(out)?;
+            vm.unit()
+                .emit_instruction(&mut out, sources, vm.ip(), with_source)?;
         }
 
         let result = match execution.async_step().await {
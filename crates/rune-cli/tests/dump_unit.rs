@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Run the given script with `--dump-unit` and collect the names of the
+/// instructions in the dumped unit.
+fn dump_instructions(name: &str, source: &str) -> Vec<String> {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("{}.rn", name));
+    std::fs::write(&path, source).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rune"))
+        .args(["--color", "never", "run", "--dump-unit"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    stdout
+        .lines()
+        .skip_while(|line| *line != "# instructions")
+        .skip(1)
+        .take_while(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once(" = ").map(|(_, inst)| inst))
+        .filter_map(|inst| inst.split_whitespace().next())
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn test_dump_unit_not() {
+    let insts = dump_instructions("bool_not", "pub fn main() { !true }");
+    assert!(insts.iter().any(|inst| inst == "bool-not"));
+    assert!(!insts.iter().any(|inst| inst == "bit-not" || inst == "not"));
+
+    let insts = dump_instructions("bit_not", "pub fn main() { !5 }");
+    assert!(insts.iter().any(|inst| inst == "bit-not"));
+    assert!(!insts.iter().any(|inst| inst == "bool-not" || inst == "not"));
+
+    let insts = dump_instructions("not", "pub fn main() { let a = true; !a }");
+    assert!(insts.iter().any(|inst| inst == "not"));
+}
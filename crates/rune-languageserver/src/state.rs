@@ -181,6 +181,16 @@ impl State {
                                         display_to_error,
                                     );
                                 }
+                                rune::ErrorKind::Warning(warning) => {
+                                    report(
+                                        &sources,
+                                        &mut by_url,
+                                        warning.span(),
+                                        source_id,
+                                        warning.kind(),
+                                        display_to_error,
+                                    );
+                                }
                                 rune::ErrorKind::LinkError(error) => match error {
                                     rune::LinkerError::MissingFunction { hash, spans } => {
                                         for (span, _) in spans {
@@ -22,6 +22,7 @@
 
 use rune::ast;
 use rune::macros;
+use rune::T;
 use rune::{quote, Parser, TokenStream};
 
 /// Construct the `std::core` module.
@@ -29,6 +30,7 @@ pub fn module(_stdio: bool) -> Result<runestick::Module, runestick::ContextError
     let mut module = runestick::Module::with_crate("std");
     module.macro_(&["stringify"], stringify_macro)?;
     module.macro_(&["panic"], panic_macro)?;
+    module.macro_(&["matches"], matches_macro)?;
     Ok(module)
 }
 
@@ -49,3 +51,21 @@ pub(crate) fn panic_macro(
     let expanded = args.expand()?;
     Ok(quote!(::std::panic(#expanded)).into_token_stream())
 }
+
+/// Implementation for the `matches!` macro, which tests if a value matches a
+/// pattern like `matches!(value, Some(n) if n > 1)`.
+pub(crate) fn matches_macro(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream);
+    let expr = p.parse::<ast::Expr>()?;
+    p.parse::<T![,]>()?;
+    let pat = p.parse::<ast::Pat>()?;
+    let condition = p.parse::<Option<(T![if], ast::Expr)>>()?;
+    p.parse::<Option<T![,]>>()?;
+    p.eof()?;
+
+    Ok(quote!(match #expr {
+        #pat #condition => true,
+        _ => false,
+    })
+    .into_token_stream())
+}
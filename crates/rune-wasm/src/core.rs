@@ -1,4 +1,5 @@
-use runestick::{ContextError, Module, Panic, Stack, Value, VmError};
+use runestick::{ContextError, Module, Stack, Value, VmError, VmErrorKind};
+use std::fmt::Write as _;
 use std::io::Write as _;
 
 /// Provide a bunch of `std` functions which does something appropriate to the
@@ -23,21 +24,24 @@ pub fn drain_output() -> Option<String> {
     String::from_utf8(o).ok()
 }
 
-fn print_impl(m: &str) -> Result<(), Panic> {
-    write!(OUT.lock(), "{}", m).map_err(Panic::custom)
+fn print_impl(value: Value) -> Result<(), VmError> {
+    let out = value.display_string()?;
+    write!(OUT.lock(), "{}", out).map_err(VmError::panic)
 }
 
-fn println_impl(m: &str) -> Result<(), Panic> {
-    writeln!(OUT.lock(), "{}", m).map_err(Panic::custom)
+fn println_impl(value: Value) -> Result<(), VmError> {
+    let out = value.display_string()?;
+    writeln!(OUT.lock(), "{}", out).map_err(VmError::panic)
 }
 
 fn dbg_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
-    let mut o = OUT.lock();
+    let mut out = String::new();
 
     for value in stack.drain_stack_top(args)? {
-        writeln!(o, "{:?}", value).map_err(VmError::panic)?;
+        writeln!(out, "{:?}", value).map_err(|_| VmErrorKind::FormatError)?;
     }
 
+    OUT.lock().extend_from_slice(out.as_bytes());
     stack.push(Value::Unit);
     Ok(())
 }
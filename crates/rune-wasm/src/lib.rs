@@ -257,6 +257,23 @@ async fn inner_compile(input: String, config: JsValue) -> Result<CompileResult,
                                 message: error.to_string(),
                             });
                         }
+                        rune::ErrorKind::Warning(warning) => {
+                            let span = warning.span();
+
+                            let start = Position::from(
+                                source.position_to_unicode_line_char(span.start.into_usize()),
+                            );
+                            let end = Position::from(
+                                source.position_to_unicode_line_char(span.end.into_usize()),
+                            );
+
+                            diagnostics.push(Diagnostic {
+                                kind: DiagnosticKind::Error,
+                                start,
+                                end,
+                                message: warning.to_string(),
+                            });
+                        }
                         rune::ErrorKind::LinkError(error) => match error {
                             rune::LinkerError::MissingFunction { hash, spans } => {
                                 for (span, _) in spans {
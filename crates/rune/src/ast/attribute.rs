@@ -75,6 +75,35 @@ impl Parse for Attribute {
     }
 }
 
+impl Attribute {
+    /// Parse the structured form of the attribute, like `#[a]`, `#[a = "b"]`
+    /// or `#[a(b, c = "d")]`.
+    ///
+    /// This errors if the attribute doesn't follow one of these forms, like
+    /// `#[x+1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rune::{ast, parse_all};
+    ///
+    /// let attribute = parse_all::<ast::Attribute>("#[a(b, c = \"d\")]").unwrap();
+    ///
+    /// match attribute.meta().unwrap() {
+    ///     ast::AttrMeta::List(list) => assert_eq!(list.items.len(), 2),
+    ///     meta => panic!("unexpected meta: {:?}", meta),
+    /// }
+    ///
+    /// assert!(parse_all::<ast::Attribute>("#[x+1]").unwrap().meta().is_err());
+    /// ```
+    pub fn meta(&self) -> Result<AttrMeta, ParseError> {
+        let mut p = Parser::from_token_stream(&self.input);
+        let meta = AttrMeta::parse_with_path(self.path.clone(), &mut p)?;
+        p.eof()?;
+        Ok(meta)
+    }
+}
+
 impl Peek for Attribute {
     fn peek(p: &mut Peeker<'_>) -> bool {
         match (p.nth(0), p.nth(1)) {
@@ -94,6 +123,95 @@ impl Description for &Attribute {
     }
 }
 
+/// The structured form of an attribute.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub enum AttrMeta {
+    /// A bare path, like `#[a]`.
+    Path(Box<ast::Path>),
+    /// A key-value pair, like `#[a = "b"]`.
+    NameValue(Box<AttrNameValue>),
+    /// A list, like `#[a(b, c = "d")]`.
+    List(Box<AttrList>),
+}
+
+impl AttrMeta {
+    /// Parse the structured form of an attribute with the path already
+    /// parsed.
+    pub(crate) fn parse_with_path(path: ast::Path, p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(match p.nth(0)? {
+            K![=] => Self::NameValue(Box::new(AttrNameValue {
+                path,
+                eq: p.parse()?,
+                value: p.parse()?,
+            })),
+            K!['('] => Self::List(Box::new(AttrList {
+                path,
+                items: p.parse()?,
+            })),
+            _ => Self::Path(Box::new(path)),
+        })
+    }
+}
+
+/// Parsing the structured form of an attribute.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::AttrMeta>("a");
+/// testing::roundtrip::<ast::AttrMeta>("a = \"b\"");
+/// testing::roundtrip::<ast::AttrMeta>("a(b, c = \"d\")");
+/// testing::roundtrip::<ast::AttrMeta>("a(b(\"c\", 1), d::e)");
+/// ```
+impl Parse for AttrMeta {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let path = p.parse()?;
+        Self::parse_with_path(path, p)
+    }
+}
+
+/// A key-value attribute, like `#[a = "b"]`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct AttrNameValue {
+    /// The path of the attribute.
+    pub path: ast::Path,
+    /// The `=` token.
+    pub eq: T![=],
+    /// The value of the attribute.
+    pub value: ast::Lit,
+}
+
+/// A list attribute, like `#[a(b, c = "d")]`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct AttrList {
+    /// The path of the attribute.
+    pub path: ast::Path,
+    /// The items in the list.
+    pub items: ast::Parenthesized<AttrNested, T![,]>,
+}
+
+/// An item in a list attribute, like `b` or `c = "d"` in `#[a(b, c = "d")]`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub enum AttrNested {
+    /// A nested attribute.
+    Meta(AttrMeta),
+    /// A literal, like `"message"` in `#[deprecated("message")]`.
+    Lit(ast::Lit),
+}
+
+impl Parse for AttrNested {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(match p.nth(0)? {
+            K![true] | K![false] | K![byte] | K![number] | K![char] | K![str] | K![bytestr] => {
+                Self::Lit(p.parse()?)
+            }
+            _ => Self::Meta(p.parse()?),
+        })
+    }
+}
+
 /// Whether or not the attribute is an outer `#!` or inner `#` attribute
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ToTokens)]
 pub enum AttrStyle {
@@ -173,3 +291,38 @@ fn test_parse_attribute() {
         crate::parse_all::<ast::Attribute>(&withbang).expect(&withbang);
     }
 }
+
+#[test]
+fn test_parse_attribute_meta() {
+    let meta = |s: &str| {
+        let attribute = crate::testing::roundtrip::<ast::Attribute>(s);
+        attribute.meta().expect(s)
+    };
+
+    assert!(matches!(meta("#[a]"), AttrMeta::Path(..)));
+    assert!(matches!(meta("#[a::b]"), AttrMeta::Path(..)));
+
+    match meta("#[a = \"b\"]") {
+        AttrMeta::NameValue(name_value) if matches!(name_value.value, ast::Lit::Str(..)) => (),
+        meta => panic!("unexpected meta: {:?}", meta),
+    }
+
+    match meta("#[a(b, c = \"d\")]") {
+        AttrMeta::List(list) => {
+            let items = list.items.iter().map(|(item, _)| item).collect::<Vec<_>>();
+
+            match items.as_slice() {
+                [AttrNested::Meta(AttrMeta::Path(..)), AttrNested::Meta(AttrMeta::NameValue(..))] =>
+                    {}
+                items => panic!("unexpected items: {:?}", items),
+            }
+        }
+        meta => panic!("unexpected meta: {:?}", meta),
+    }
+
+    assert!(matches!(meta("#[a()]"), AttrMeta::List(..)));
+    assert!(crate::parse_all::<ast::Attribute>("#[a = 1 + 2]")
+        .unwrap()
+        .meta()
+        .is_err());
+}
@@ -112,6 +112,8 @@ pub enum Expr {
     Tuple(Box<ast::ExprTuple>),
     /// A vec literal
     Vec(Box<ast::ExprVec>),
+    /// A vec literal with a repeated value.
+    VecRepeat(Box<ast::ExprVecRepeat>),
     /// A range expression.
     Range(Box<ast::ExprRange>),
 }
@@ -180,6 +182,7 @@ impl Expr {
             Self::Object(expr) => take(&mut expr.attributes),
             Self::Range(expr) => take(&mut expr.attributes),
             Self::Vec(expr) => take(&mut expr.attributes),
+            Self::VecRepeat(expr) => take(&mut expr.attributes),
             Self::Tuple(expr) => take(&mut expr.attributes),
             Self::MacroCall(expr) => take(&mut expr.attributes),
         }
@@ -219,6 +222,7 @@ impl Expr {
             Self::Range(expr) => &expr.attributes,
             Self::Tuple(expr) => &expr.attributes,
             Self::Vec(expr) => &expr.attributes,
+            Self::VecRepeat(expr) => &expr.attributes,
         }
     }
 
@@ -263,7 +267,7 @@ impl Expr {
         Self::parse_with(p, EagerBrace(false), EagerBinary(true), Callable(true))
     }
 
-    /// ull, configurable parsing of an expression.F
+    /// Full, configurable parsing of an expression.
     pub(crate) fn parse_with(
         p: &mut Parser<'_>,
         eager_brace: EagerBrace,
@@ -295,7 +299,15 @@ impl Expr {
         path: ast::Path,
         eager_brace: EagerBrace,
     ) -> Result<Self, ParseError> {
-        if *eager_brace && p.peek::<T!['{']>()? {
+        // Outside of eager brace contexts, `Foo {` might start a block. It is
+        // only an object literal if it's followed by a `field:` pair.
+        let is_object = if *eager_brace {
+            p.peek::<T!['{']>()?
+        } else {
+            matches!(p.peek3()?, (K!['{'], K![ident], K![:]))
+        };
+
+        if is_object {
             let ident = ast::ObjectIdent::Named(path);
 
             return Ok(Self::Object(Box::new(ast::ExprObject::parse_with_meta(
@@ -343,6 +355,41 @@ impl Expr {
         )))
     }
 
+    /// Parse an expression starting with an open bracket, which is either a
+    /// vector literal or a repeated vector like `[0; 5]`.
+    pub(crate) fn parse_open_bracket(
+        p: &mut Parser<'_>,
+        attributes: Vec<ast::Attribute>,
+    ) -> Result<Self, ParseError> {
+        // Special case: empty vector, or a vector starting with a spread. A
+        // `..` which isn't followed by an expression is a full range.
+        let spread = matches!(p.nth(1)?, K![..]) && !matches!(p.nth(2)?, K![,] | K![']']);
+
+        if matches!(p.nth(1)?, K![']']) || spread {
+            return Ok(Self::Vec(Box::new(ast::ExprVec::parse_with_meta(
+                p, attributes,
+            )?)));
+        }
+
+        let open = p.parse::<T!['[']>()?;
+        let expr = p.parse::<Self>()?;
+
+        if p.peek::<T![;]>()? {
+            return Ok(Self::VecRepeat(Box::new(ast::ExprVecRepeat {
+                attributes,
+                open,
+                value: expr,
+                semi: p.parse()?,
+                count: p.parse()?,
+                close: p.parse()?,
+            })));
+        }
+
+        Ok(Self::Vec(Box::new(ast::ExprVec::parse_from_first_expr(
+            p, attributes, open, expr,
+        )?)))
+    }
+
     pub(crate) fn parse_with_meta(
         p: &mut Parser<'_>,
         attributes: &mut Vec<ast::Attribute>,
@@ -389,11 +436,11 @@ impl Expr {
         let expr = match p.nth(0)? {
             K![..] => {
                 let limits = ast::ExprRangeLimits::HalfOpen(p.parse()?);
-                Self::parse_range(p, take(attributes), None, limits)?
+                Self::parse_range(p, take(attributes), None, limits, eager_brace)?
             }
             K![..=] => {
                 let limits = ast::ExprRangeLimits::Closed(p.parse()?);
-                Self::parse_range(p, take(attributes), None, limits)?
+                Self::parse_range(p, take(attributes), None, limits, eager_brace)?
             }
             K![#] => {
                 let ident = ast::ObjectIdent::Anonymous(p.parse()?);
@@ -441,10 +488,7 @@ impl Expr {
                 p,
                 take(attributes),
             )?)),
-            K!['['] => Self::Vec(Box::new(ast::ExprVec::parse_with_meta(
-                p,
-                take(attributes),
-            )?)),
+            K!['['] => Self::parse_open_bracket(p, take(attributes))?,
             K!['('] => Self::parse_open_paren(p, take(attributes))?,
             K!['{'] => Self::Block(Box::new(ast::ExprBlock::parse_with_meta(
                 p,
@@ -470,7 +514,57 @@ impl Expr {
                 take(attributes),
             )?)),
             _ => {
-                return Err(ParseError::expected(&p.tok_at(0)?, "expression"));
+                // NB: the set of expected tokens is only built here. Consuming
+                // a label or a modifier clears it, so only what may follow
+                // the last consumed token is listed.
+                let modifiers = if move_token.is_some() {
+                    &[][..]
+                } else if const_token.is_some() {
+                    &[K![move]][..]
+                } else if async_token.is_some() {
+                    &[K![const], K![move]][..]
+                } else {
+                    &[K![async], K![const], K![move]][..]
+                };
+
+                if label.is_none()
+                    && async_token.is_none()
+                    && const_token.is_none()
+                    && move_token.is_none()
+                {
+                    p.expected("path");
+                    p.expected("literal");
+                    p.expected("label");
+                }
+
+                p.expected_kinds(modifiers);
+                p.expected_kinds(&[
+                    K![..],
+                    K![..=],
+                    K![#],
+                    K![||],
+                    K![|],
+                    K![select],
+                    K![!],
+                    K![-],
+                    K![&],
+                    K![*],
+                    K![while],
+                    K![loop],
+                    K![for],
+                    K![let],
+                    K![if],
+                    K![match],
+                    K!['['],
+                    K!['('],
+                    K!['{'],
+                    K![break],
+                    K![continue],
+                    K![yield],
+                    K![return],
+                ]);
+
+                return Err(p.expected_error());
             }
         };
 
@@ -499,11 +593,12 @@ impl Expr {
         attributes: Vec<ast::Attribute>,
         from: Option<Self>,
         limits: ast::ExprRangeLimits,
+        eager_brace: EagerBrace,
     ) -> Result<Self, ParseError> {
         let to = if Self::peek(p.peeker()) {
             Some(Self::parse_with(
                 p,
-                EagerBrace(true),
+                eager_brace,
                 EagerBinary(true),
                 Callable(true),
             )?)
@@ -556,6 +651,9 @@ impl Expr {
                         try_token: p.parse()?,
                     }));
                 }
+                // NB: the right-hand side is parsed in full, which makes
+                // assignment right-associative, so `a = b = c` parses as
+                // `a = (b = c)`.
                 K![=] => {
                     let eq = p.parse()?;
                     let rhs =
@@ -619,20 +717,30 @@ impl Expr {
         op: ast::BinOp,
         lookahead_tok: &mut Option<ast::BinOp>,
     ) -> Result<Self, ParseError> {
-        let rhs = Self::parse_base(p, &mut vec![], eager_brace)?;
+        let mut attributes = p.parse()?;
+
+        let rhs = Self::parse_base(p, &mut attributes, eager_brace)?;
         let mut rhs = Self::parse_chain(p, rhs, Callable(true))?;
 
+        if let Some(span) = attributes.option_span() {
+            return Err(ParseError::unsupported(span, "attributes"));
+        }
+
         *lookahead_tok = ast::BinOp::from_peeker(p.peeker());
 
         loop {
             let lh = match *lookahead_tok {
                 Some(lh) if lh.precedence() > op.precedence() => lh,
-                Some(lh) if lh.precedence() == op.precedence() && !op.is_assoc() => {
-                    return Err(ParseError::new(
-                        lhs.span().join(rhs.span()),
-                        ParseErrorKind::PrecedenceGroupRequired,
-                    ));
-                }
+                Some(lh) if lh.precedence() == op.precedence() => match op.assoc() {
+                    ast::Assoc::Left => break,
+                    ast::Assoc::Right => lh,
+                    ast::Assoc::None => {
+                        return Err(ParseError::new(
+                            lhs.span().join(rhs.span()),
+                            ParseErrorKind::PrecedenceGroupRequired,
+                        ));
+                    }
+                },
                 _ => break,
             };
 
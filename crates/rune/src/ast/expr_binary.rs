@@ -1,6 +1,6 @@
 use crate::ast;
-use crate::{ParseError, Parser, Peek, Peeker, Spanned, ToTokens};
-use runestick::Span;
+use crate::{Lexer, ParseError, Parser, Peek, Peeker, Spanned, ToTokens};
+use runestick::{Hash, Span};
 use std::fmt;
 
 /// A binary expression.
@@ -48,6 +48,8 @@ pub enum BinOp {
     Mul,
     /// Remainder operator `a % b`.
     Rem,
+    /// Exponent operator `a ** b`.
+    Pow,
     /// Equality check `a == b`.
     Eq,
     /// Inequality check `a != b`.
@@ -64,6 +66,8 @@ pub enum BinOp {
     Is,
     /// Negated instance of test `a is not b`.
     IsNot,
+    /// Conversion `a as b`.
+    As,
     /// Lazy and operator `&&`.
     And,
     /// Lazy or operator `||`.
@@ -88,6 +92,8 @@ pub enum BinOp {
     DivAssign,
     /// Remainder assign `a %= b`.
     RemAssign,
+    /// Exponent assign `a **= b`.
+    PowAssign,
     /// Bitwise and assign `a &= b`.
     BitAndAssign,
     /// Bitwise xor assign `a ^= b`.
@@ -102,6 +108,13 @@ pub enum BinOp {
     DotDot,
     /// `a ..= b`.
     DotDotEq,
+    /// A custom operator registered in the context, like `a <> b`.
+    Custom {
+        /// The precedence of the operator.
+        precedence: usize,
+        /// The hash of the function implementing the operator.
+        hash: Hash,
+    },
 }
 
 impl BinOp {
@@ -113,6 +126,7 @@ impl BinOp {
             Self::MulAssign => true,
             Self::DivAssign => true,
             Self::RemAssign => true,
+            Self::PowAssign => true,
             Self::BitAndAssign => true,
             Self::BitXorAssign => true,
             Self::BitOrAssign => true,
@@ -133,61 +147,81 @@ impl BinOp {
 
     /// Get the precedence for the current operator.
     pub(super) fn precedence(self) -> usize {
-        // NB: Rules from: https://doc.rust-lang.org/reference/expressions.html#expression-precedence
-        match self {
-            Self::Is | Self::IsNot => 12,
-            Self::Mul | Self::Div | Self::Rem => 11,
-            Self::Add | Self::Sub => 10,
-            Self::Shl | Self::Shr => 9,
-            Self::BitAnd => 8,
-            Self::BitXor => 7,
-            Self::BitOr => 6,
-            Self::Eq | Self::Neq | Self::Lt | Self::Gt | Self::Lte | Self::Gte => 5,
-            Self::And => 4,
-            Self::Or => 3,
-            Self::DotDot | Self::DotDotEq => 2,
-            // assign operators
-            _ => 1,
-        }
+        self.binding_power().0
     }
 
-    /// Test if operator is left associative.
-    pub(super) fn is_assoc(self) -> bool {
-        match self {
-            Self::Mul => true,
-            Self::Div => true,
-            Self::Add => true,
-            Self::Sub => true,
-            Self::Or => true,
-            Self::And => true,
-            Self::Rem => true,
-            Self::Shl => true,
-            Self::Shr => true,
-            Self::BitAnd => true,
-            Self::BitOr => true,
-            Self::BitXor => true,
-            _ => false,
-        }
+    /// Get the associativity of the current operator.
+    pub(super) fn assoc(self) -> Assoc {
+        self.binding_power().1
+    }
+
+    /// Get the [binding_power] of the token which starts the operator, except
+    /// for custom operators which carry their own precedence and are always
+    /// left-associative.
+    fn binding_power(self) -> (usize, Assoc) {
+        let kind = match self {
+            Self::Add => K![+],
+            Self::Sub => K![-],
+            Self::Div => K![/],
+            Self::Mul => K![*],
+            Self::Rem => K![%],
+            Self::Pow => K![**],
+            Self::Eq => K![==],
+            Self::Neq => K![!=],
+            Self::Gt => K![>],
+            Self::Lt => K![<],
+            Self::Gte => K![>=],
+            Self::Lte => K![<=],
+            Self::Is | Self::IsNot => ast::Kind::Is,
+            Self::As => K![as],
+            Self::And => K![&&],
+            Self::Or => K![||],
+            Self::Shl => K![<<],
+            Self::Shr => K![>>],
+            Self::BitAnd => K![&],
+            Self::BitXor => K![^],
+            Self::BitOr => K![|],
+            Self::AddAssign => K![+=],
+            Self::SubAssign => K![-=],
+            Self::MulAssign => K![*=],
+            Self::DivAssign => K![/=],
+            Self::RemAssign => K![%=],
+            Self::PowAssign => K![**=],
+            Self::BitAndAssign => K![&=],
+            Self::BitXorAssign => K![^=],
+            Self::BitOrAssign => K![|=],
+            Self::ShlAssign => K![<<=],
+            Self::ShrAssign => K![>>=],
+            Self::DotDot => K![..],
+            Self::DotDotEq => K![..=],
+            Self::Custom { precedence, .. } => return (precedence, Assoc::Left),
+        };
+
+        binding_power(kind)
     }
 
     /// Convert from a token.
     pub(super) fn from_peeker(p: &mut Peeker<'_>) -> Option<BinOp> {
-        Some(match p.nth(0) {
+        let op = match p.nth(0) {
             K![+] => Self::Add,
             K![-] => Self::Sub,
             K![*] => Self::Mul,
             K![/] => Self::Div,
             K![%] => Self::Rem,
+            K![**] => Self::Pow,
             K![==] => Self::Eq,
             K![!=] => Self::Neq,
             K![<] => Self::Lt,
             K![>] => Self::Gt,
             K![<=] => Self::Lte,
             K![>=] => Self::Gte,
-            ast::Kind::Is => match p.nth(1) {
-                K![not] => Self::IsNot,
-                _ => Self::Is,
-            },
+            ast::Kind::Is => {
+                return Some(match p.nth(1) {
+                    K![not] => Self::IsNot,
+                    _ => Self::Is,
+                })
+            }
+            K![as] => return Some(Self::As),
             K![&&] => Self::And,
             K![||] => Self::Or,
             K![<<] => Self::Shl,
@@ -200,6 +234,7 @@ impl BinOp {
             K![*=] => Self::MulAssign,
             K![/=] => Self::DivAssign,
             K![%=] => Self::RemAssign,
+            K![**=] => Self::PowAssign,
             K![&=] => Self::BitAndAssign,
             K![^=] => Self::BitXorAssign,
             K![|=] => Self::BitOrAssign,
@@ -207,7 +242,23 @@ impl BinOp {
             K![>>=] => Self::ShrAssign,
             K![..] => Self::DotDot,
             K![..=] => Self::DotDotEq,
-            _ => return None,
+            _ => return Self::from_custom(p),
+        };
+
+        // NB: a custom operator might start with a builtin one, like `<>` which
+        // starts with `<`. Custom operators can't be registered if they could
+        // be confused with builtin syntax, so the tokens are only treated as
+        // one operator if they are written together and registered as such.
+        Some(Self::from_custom(p).unwrap_or(op))
+    }
+
+    /// Convert from the custom operator made up of the next two tokens.
+    fn from_custom(p: &mut Peeker<'_>) -> Option<BinOp> {
+        let op = p.custom_operator()?;
+
+        Some(Self::Custom {
+            precedence: op.precedence,
+            hash: op.hash,
         })
     }
 
@@ -217,12 +268,62 @@ impl BinOp {
         p: &mut Parser<'_>,
     ) -> Result<(ast::Token, Option<ast::Token>), ParseError> {
         Ok(match self {
-            Self::IsNot => (p.next()?, Some(p.next()?)),
+            Self::IsNot | Self::Custom { .. } => (p.next()?, Some(p.next()?)),
             _ => (p.next()?, None),
         })
     }
 }
 
+/// The associativity of a binary operator, which determines how a chain of
+/// operators with the same precedence is grouped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    /// `a - b - c` is grouped as `(a - b) - c`.
+    Left,
+    /// `a = b = c` is grouped as `a = (b = c)`.
+    Right,
+    /// Chaining the operator requires an explicit group, like `(a < b) < c`.
+    None,
+}
+
+/// The precedence table for binary operators, mapping the kind of the token
+/// which starts an operator to its binding power and associativity. Operators
+/// with a higher binding power bind more tightly, and tokens which don't start
+/// a binary operator have a binding power of `0`.
+///
+/// NB: Rules from: https://doc.rust-lang.org/reference/expressions.html#expression-precedence
+pub(crate) fn binding_power(kind: ast::Kind) -> (usize, Assoc) {
+    match kind {
+        ast::Kind::Is | K![as] => (13, Assoc::None),
+        K![**] => (12, Assoc::Right),
+        K![*] | K![/] | K![%] => (11, Assoc::Left),
+        K![+] | K![-] => (10, Assoc::Left),
+        K![<<] | K![>>] => (9, Assoc::Left),
+        K![&] => (8, Assoc::Left),
+        K![^] => (7, Assoc::Left),
+        K![|] => (6, Assoc::Left),
+        K![==] | K![!=] | K![<] | K![>] | K![<=] | K![>=] => (5, Assoc::None),
+        K![&&] => (4, Assoc::Left),
+        K![||] => (3, Assoc::Left),
+        K![..] | K![..=] => (2, Assoc::None),
+        // NB: a plain `=` is parsed as an assignment expression rather than a
+        // binary one, but it still binds like the other assignments.
+        K![=]
+        | K![+=]
+        | K![-=]
+        | K![*=]
+        | K![/=]
+        | K![%=]
+        | K![**=]
+        | K![&=]
+        | K![^=]
+        | K![|=]
+        | K![<<=]
+        | K![>>=] => (1, Assoc::Right),
+        _ => (0, Assoc::None),
+    }
+}
+
 impl fmt::Display for BinOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -231,6 +332,7 @@ impl fmt::Display for BinOp {
             Self::Div => write!(f, "/"),
             Self::Mul => write!(f, "*"),
             Self::Rem => write!(f, "%"),
+            Self::Pow => write!(f, "**"),
             Self::Eq => write!(f, "=="),
             Self::Neq => write!(f, "!="),
             Self::Gt => write!(f, ">"),
@@ -239,6 +341,7 @@ impl fmt::Display for BinOp {
             Self::Lte => write!(f, "<="),
             Self::Is => write!(f, "is"),
             Self::IsNot => write!(f, "is not"),
+            Self::As => write!(f, "as"),
             Self::And => write!(f, "&&"),
             Self::Or => write!(f, "||"),
             Self::Shl => write!(f, "<<"),
@@ -254,10 +357,12 @@ impl fmt::Display for BinOp {
             Self::BitXorAssign => write!(f, "^="),
             Self::BitOrAssign => write!(f, "|="),
             Self::RemAssign => write!(f, "%="),
+            Self::PowAssign => write!(f, "**="),
             Self::ShlAssign => write!(f, "<<="),
             Self::ShrAssign => write!(f, ">>="),
             Self::DotDot => write!(f, ".."),
             Self::DotDotEq => write!(f, "..="),
+            Self::Custom { .. } => write!(f, "custom operator"),
         }
     }
 }
@@ -267,3 +372,135 @@ impl Peek for BinOp {
         Self::from_peeker(p).is_some()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{binding_power, Assoc};
+    use crate::{ast, Spanned as _};
+
+    /// Render the parsed expression with every binary and range expression
+    /// wrapped in parenthesis, so that its shape can be compared.
+    fn shape(source: &str) -> String {
+        fn render(source: &str, expr: &ast::Expr) -> String {
+            match expr {
+                ast::Expr::Binary(b) => format!(
+                    "({} {} {})",
+                    render(source, &b.lhs),
+                    b.op,
+                    render(source, &b.rhs)
+                ),
+                ast::Expr::Range(r) => format!(
+                    "({}{}{})",
+                    r.from
+                        .as_ref()
+                        .map(|e| render(source, e))
+                        .unwrap_or_default(),
+                    &source[r.limits.span().range()],
+                    r.to.as_ref().map(|e| render(source, e)).unwrap_or_default()
+                ),
+                ast::Expr::Assign(a) => {
+                    format!("({} = {})", render(source, &a.lhs), render(source, &a.rhs))
+                }
+                ast::Expr::Group(g) => render(source, &g.expr),
+                expr => source[expr.span().range()].to_owned(),
+            }
+        }
+
+        render(
+            source,
+            &crate::parse_all::<ast::Expr>(source).expect("parse"),
+        )
+    }
+
+    #[test]
+    fn test_binding_power() {
+        let bp = |kind| binding_power(kind).0;
+
+        assert!(bp(ast::Kind::Is) > bp(K![**]));
+        assert_eq!(bp(K![as]), bp(ast::Kind::Is));
+        assert!(bp(K![**]) > bp(K![*]));
+        assert!(bp(K![*]) > bp(K![+]));
+        assert!(bp(K![+]) > bp(K![<<]));
+        assert!(bp(K![<<]) > bp(K![&]));
+        assert!(bp(K![&]) > bp(K![^]));
+        assert!(bp(K![^]) > bp(K![|]));
+        assert!(bp(K![|]) > bp(K![==]));
+        assert!(bp(K![==]) > bp(K![&&]));
+        assert!(bp(K![&&]) > bp(K![||]));
+        assert!(bp(K![||]) > bp(K![..]));
+        assert!(bp(K![..]) > bp(K![=]));
+        assert_eq!(bp(K![=]), bp(K![+=]));
+        assert_eq!(bp(K![;]), 0);
+    }
+
+    #[test]
+    fn test_associativity() {
+        let assoc = |kind| binding_power(kind).1;
+
+        assert_eq!(assoc(K![-]), Assoc::Left);
+        assert_eq!(assoc(K![**]), Assoc::Right);
+        assert_eq!(assoc(K![=]), Assoc::Right);
+        assert_eq!(assoc(K![+=]), Assoc::Right);
+        assert_eq!(assoc(K![<]), Assoc::None);
+    }
+
+    #[test]
+    fn test_precedence_shape() {
+        assert_eq!(shape("1 + 2 * 3"), "(1 + (2 * 3))");
+        assert_eq!(shape("1 * 2 + 3"), "((1 * 2) + 3)");
+        assert_eq!(shape("1 - 2 - 3"), "((1 - 2) - 3)");
+        assert_eq!(shape("(1 + 2) * 3"), "((1 + 2) * 3)");
+        assert_eq!(shape("1 << 2 + 3"), "(1 << (2 + 3))");
+        assert_eq!(shape("a & b | c ^ d"), "((a & b) | (c ^ d))");
+        assert_eq!(shape("a || b && c == d"), "(a || (b && (c == d)))");
+        assert_eq!(shape("1 + 2 .. 3 * 4"), "((1 + 2)..(3 * 4))");
+        assert_eq!(shape("a += 1 + 2"), "(a += (1 + 2))");
+        assert_eq!(shape("2 ** 3 ** 2"), "(2 ** (3 ** 2))");
+        assert_eq!(shape("2 * 3 ** 2"), "(2 * (3 ** 2))");
+        assert_eq!(shape("2 ** 3 * 2"), "((2 ** 3) * 2)");
+        assert_eq!(shape("a = b = c"), "(a = (b = c))");
+        assert_eq!(shape("a = b + c"), "(a = (b + c))");
+        assert_eq!(shape("a.b = c[0] = 1 + 2"), "(a.b = (c[0] = (1 + 2)))");
+        assert_eq!(shape("a = b += c"), "(a = (b += c))");
+        assert_eq!(shape("a **= 2 ** 3"), "(a **= (2 ** 3))");
+        assert_eq!(shape("a = `${b}`"), "(a = `${b}`)");
+        assert_eq!(shape("a + `${b}`"), "(a + `${b}`)");
+    }
+}
+
+/// Test if the given custom operator lexes as exactly two tokens which can't be
+/// confused with builtin syntax.
+pub(crate) fn is_valid_custom_operator(op: &str) -> bool {
+    // NB: other punctuation either starts something else, like a string or a
+    // comment, or already has a meaning after an expression, like `?` or `.`.
+    let is_operator_char = |c| {
+        matches!(
+            c,
+            '!' | '$' | '%' | '&' | '*' | '+' | '-' | '/' | '<' | '=' | '>' | '@' | '^' | '|' | '~'
+        )
+    };
+
+    // NB: custom operators are looked up without allocating, which limits
+    // them to three characters.
+    if !(2..=3).contains(&op.len()) || !op.chars().all(is_operator_char) {
+        return false;
+    }
+
+    let mut lexer = Lexer::new(op);
+
+    let (first, second) = match (lexer.next(), lexer.next(), lexer.next()) {
+        (Ok(Some(first)), Ok(Some(second)), Ok(None)) => (first, second),
+        _ => return false,
+    };
+
+    // NB: closures aren't covered by `Expr`, since they can't be told apart
+    // from a binary `|` without knowing what came before.
+    let starts_expr = match second.kind {
+        K![|] | K![||] => true,
+        _ => ast::Expr::peek(Parser::new(&op[second.span.range()]).peeker()),
+    };
+
+    // A builtin binary operator followed by the start of an expression already
+    // has a meaning, like `<-` in `a <-1`.
+    binding_power(first.kind).0 == 0 || !starts_expr
+}
@@ -10,6 +10,8 @@ use crate::{Id, Spanned, ToTokens};
 /// use rune::{testing, ast};
 ///
 /// testing::roundtrip::<ast::ExprCall>("test()");
+/// testing::roundtrip::<ast::ExprCall>("test(1, 2)");
+/// testing::roundtrip::<ast::ExprCall>("test(1, 2,)");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct ExprCall {
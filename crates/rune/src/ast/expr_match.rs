@@ -8,7 +8,9 @@ use crate::{Parse, ParseError, Parser, Spanned, ToTokens};
 /// ```rust
 /// use rune::{testing, ast};
 ///
+/// testing::roundtrip::<ast::ExprMatch>("match 0 { _ => 1 }");
 /// testing::roundtrip::<ast::ExprMatch>("match 0 { _ => 1, }");
+/// testing::roundtrip::<ast::ExprMatch>("match 0 { 0 => 1, _ => 2, }");
 /// let expr = testing::roundtrip::<ast::ExprMatch>("#[jit(always)] match 0 { _ => 1, }");
 /// assert_eq!(expr.attributes.len(), 1);
 /// ```
@@ -16,6 +16,7 @@ use std::borrow::Cow;
 /// testing::roundtrip::<ast::ExprObject>("Foo {\"foo\": 42}");
 /// testing::roundtrip::<ast::ExprObject>("#{\"foo\": 42}");
 /// testing::roundtrip::<ast::ExprObject>("#{\"foo\": 42,}");
+/// testing::roundtrip::<ast::ExprObject>("Foo {\"foo\": 42, bar: 43,}");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 pub struct ExprObject {
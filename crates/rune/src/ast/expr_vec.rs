@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::{Parse, Spanned, ToTokens};
+use crate::{Parse, ParseError, Parser, Spanned, ToTokens};
 
 /// A literal vector.
 ///
@@ -11,6 +11,9 @@ use crate::{Parse, Spanned, ToTokens};
 /// testing::roundtrip::<ast::ExprVec>("[1, \"two\"]");
 /// testing::roundtrip::<ast::ExprVec>("[1, 2,]");
 /// testing::roundtrip::<ast::ExprVec>("[1, 2, foo()]");
+/// testing::roundtrip::<ast::ExprVec>("[1, ..others, 5]");
+/// testing::roundtrip::<ast::ExprVec>("[..a, ..b]");
+/// testing::roundtrip::<ast::ExprVec>("[1, ..]");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 pub struct ExprVec {
@@ -18,5 +21,39 @@ pub struct ExprVec {
     #[rune(iter, meta)]
     pub attributes: Vec<ast::Attribute>,
     /// Items in the vector.
-    pub items: ast::Bracketed<ast::Expr, T![,]>,
+    pub items: ast::Bracketed<ExprVecItem, T![,]>,
+}
+
+impl ExprVec {
+    /// Start parsing literal vector from the middle of an expression.
+    pub fn parse_from_first_expr(
+        parser: &mut Parser<'_>,
+        attributes: Vec<ast::Attribute>,
+        open: ast::OpenBracket,
+        expr: ast::Expr,
+    ) -> Result<Self, ParseError> {
+        Ok(Self {
+            attributes,
+            items: ast::Bracketed::parse_from_first(parser, open, ExprVecItem::Expr(expr))?,
+        })
+    }
+}
+
+/// An item in a literal vector.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub enum ExprVecItem {
+    /// A single element.
+    Expr(ast::Expr),
+    /// A spread `..expr`, where the elements of `expr` are spliced into the
+    /// vector. A `..` which isn't followed by an expression is a full range.
+    Spread(T![..], ast::Expr),
+}
+
+impl Parse for ExprVecItem {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        Ok(match p.nth(0)? {
+            K![..] if !matches!(p.nth(1)?, K![,] | K![']']) => Self::Spread(p.parse()?, p.parse()?),
+            _ => Self::Expr(p.parse()?),
+        })
+    }
 }
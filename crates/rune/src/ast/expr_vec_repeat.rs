@@ -0,0 +1,32 @@
+use crate::ast;
+use crate::{Spanned, ToTokens};
+
+/// A vector with a repeated value `[value; count]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::ExprVecRepeat>("[0; 5]");
+/// testing::roundtrip::<ast::ExprVecRepeat>("[foo(); n + 1]");
+/// testing::roundtrip::<ast::ExprVecRepeat>("[[1, 2]; 2]");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct ExprVecRepeat {
+    /// Attributes associated with vector.
+    #[rune(iter)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The open bracket.
+    pub open: T!['['],
+    /// The value being repeated.
+    pub value: ast::Expr,
+    /// The semi-colon separator.
+    pub semi: T![;],
+    /// The number of times the value is repeated.
+    pub count: ast::Expr,
+    /// The close bracket.
+    pub close: T![']'],
+}
+
+expr_parse!(VecRepeat, ExprVecRepeat, "vector repeat expression");
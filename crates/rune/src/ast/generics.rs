@@ -0,0 +1,105 @@
+use crate::ast;
+use crate::{Parse, ParseError, Parser, Peek, Peeker, Spanned, ToTokens};
+use runestick::Span;
+
+/// A generic parameter, like `T` or `T: Bound`.
+///
+/// Generic parameters are currently parsed but ignored by the compiler.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::GenericParam>("T");
+/// testing::roundtrip::<ast::GenericParam>("T: Foo");
+/// testing::roundtrip::<ast::GenericParam>("T: Foo + bar::Baz");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
+pub struct GenericParam {
+    /// The name of the parameter.
+    pub name: ast::Ident,
+    /// Bounds of the parameter.
+    #[rune(iter)]
+    pub bounds: Option<(T![:], TypeBounds)>,
+}
+
+/// Type bounds separated by `+`, like `Foo + Bar`.
+#[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
+pub struct TypeBounds {
+    /// The first bound.
+    pub first: ast::Path,
+    /// The rest of the bounds.
+    #[rune(iter)]
+    pub rest: Vec<(T![+], ast::Path)>,
+}
+
+/// A `where` clause, like `where T: Foo, U: Bar`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::{testing, ast};
+///
+/// testing::roundtrip::<ast::WhereClause>("where T: Foo");
+/// testing::roundtrip::<ast::WhereClause>("where T: Foo + Bar, U: Baz,");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens)]
+pub struct WhereClause {
+    /// The `where` token.
+    pub where_token: T![where],
+    /// The predicates of the clause.
+    pub predicates: Vec<(WherePredicate, Option<T![,]>)>,
+}
+
+impl Parse for WhereClause {
+    fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
+        let where_token = p.parse()?;
+        let mut predicates = Vec::new();
+
+        while ast::Path::peek(p.peeker()) {
+            let predicate = p.parse()?;
+            let comma = p.parse::<Option<T![,]>>()?;
+            let is_end = comma.is_none();
+            predicates.push((predicate, comma));
+
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(Self {
+            where_token,
+            predicates,
+        })
+    }
+}
+
+impl Spanned for WhereClause {
+    fn span(&self) -> Span {
+        let span = self.where_token.span();
+
+        match self.predicates.last() {
+            Some((_, Some(comma))) => span.join(comma.span()),
+            Some((predicate, None)) => span.join(predicate.span()),
+            None => span,
+        }
+    }
+}
+
+impl Peek for WhereClause {
+    fn peek(p: &mut Peeker<'_>) -> bool {
+        matches!(p.nth(0), K![where])
+    }
+}
+
+/// A single predicate in a `where` clause, like `T: Foo + Bar`.
+#[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
+pub struct WherePredicate {
+    /// The type being bounded.
+    pub ty: ast::Path,
+    /// The `:` token.
+    pub colon: T![:],
+    /// The bounds of the type.
+    pub bounds: TypeBounds,
+}
@@ -25,8 +25,21 @@ impl Ident {
     /// # Panics
     ///
     /// This will panic if it's called outside of a macro context.
+    ///
+    /// The identifier is hygienic if it's constructed while a macro is being
+    /// expanded, so any local variable it declares won't clash with variables
+    /// of the same name outside of the expansion.
     pub fn new(ident: &str) -> Self {
-        crate::macros::current_context(|ctx| Self::new_with(ident, ctx.macro_span(), ctx.storage()))
+        crate::macros::current_context(|ctx| {
+            let mut this = Self::new_with(ident, ctx.macro_span(), ctx.storage());
+
+            if let (Some(hygiene), ast::StringSource::Synthetic(id)) = (ctx.hygiene, this.source) {
+                this.source = ast::StringSource::Hygienic { id, hygiene };
+                this.token.kind = ast::Kind::Ident(this.source);
+            }
+
+            this
+        })
     }
 
     /// Construct a new identifier from the given string.
@@ -45,6 +58,26 @@ impl Ident {
             source,
         }
     }
+
+    /// Resolve the identifier as the name of a local variable.
+    ///
+    /// Hygienic identifiers resolve to a name which is unique to the macro
+    /// expansion they were introduced in, and which can't be written in
+    /// source.
+    pub(crate) fn resolve_local<'a>(
+        &self,
+        storage: &Storage,
+        source: &'a Source,
+    ) -> Result<Cow<'a, str>, ResolveError> {
+        let ident = self.resolve(storage, source)?;
+
+        match self.source {
+            ast::StringSource::Hygienic { hygiene, .. } => {
+                Ok(Cow::Owned(format!("{}@{}", ident, hygiene)))
+            }
+            _ => Ok(ident),
+        }
+    }
 }
 
 impl Parse for Ident {
@@ -78,7 +111,7 @@ impl<'a> Resolve<'a> for Ident {
 
                 Ok(Cow::Borrowed(ident))
             }
-            ast::StringSource::Synthetic(id) => {
+            ast::StringSource::Synthetic(id) | ast::StringSource::Hygienic { id, .. } => {
                 let ident = storage.get_string(id).ok_or_else(|| {
                     ResolveError::new(span, ResolveErrorKind::BadSyntheticId { kind: "label", id })
                 })?;
@@ -79,7 +79,8 @@ impl Item {
             K![async] => matches!(p.nth(1), K![fn]),
             K![fn] => true,
             K![mod] => true,
-            K![const] => true,
+            // NB: `const { .. }` is a const block expression.
+            K![const] => !matches!(p.nth(1), K!['{']),
             _ => false,
         }
     }
@@ -37,6 +37,10 @@ use runestick::Span;
 /// assert_eq!(item.attributes.len(), 1);
 /// assert!(item.async_token.is_none());
 /// assert!(item.const_token.is_some());
+///
+/// let item = testing::roundtrip::<ast::ItemFn>("fn hello<T: Foo, U>(foo, bar) where U: Bar + Baz {}");
+/// assert_eq!(item.generics.map(|g| g.len()), Some(2));
+/// assert_eq!(item.where_clause.map(|w| w.predicates.len()), Some(1));
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 #[rune(parse = "meta_only")]
@@ -60,8 +64,14 @@ pub struct ItemFn {
     pub fn_token: T![fn],
     /// The name of the function.
     pub name: ast::Ident,
+    /// Generic parameters of the function, which are currently ignored.
+    #[rune(iter)]
+    pub generics: Option<ast::AngleBracketed<ast::GenericParam, T![,]>>,
     /// The arguments of the function.
     pub args: ast::Parenthesized<ast::FnArg, T![,]>,
+    /// The `where` clause of the function, which is currently ignored.
+    #[rune(iter)]
+    pub where_clause: Option<ast::WhereClause>,
     /// The body of the function.
     pub body: ast::Block,
 }
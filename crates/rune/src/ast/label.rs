@@ -81,7 +81,7 @@ impl<'a> Resolve<'a> for Label {
 
                 Ok(Cow::Borrowed(ident))
             }
-            ast::StringSource::Synthetic(id) => {
+            ast::StringSource::Synthetic(id) | ast::StringSource::Hygienic { id, .. } => {
                 let ident = storage.get_string(id).ok_or_else(|| {
                     ResolveError::new(span, ResolveErrorKind::BadSyntheticId { kind: "ident", id })
                 })?;
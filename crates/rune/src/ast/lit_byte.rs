@@ -52,11 +52,13 @@ impl<'a> Resolve<'a> for LitByte {
 
         let span = self.token.span();
 
+        let inner = span.trim_start(2).trim_end(1);
+
         let string = source
-            .source(span.trim_start(2).trim_end(1))
+            .source(inner)
             .ok_or_else(|| ResolveError::new(span, ResolveErrorKind::BadSlice))?;
 
-        let start = span.start.into_usize();
+        let start = inner.start.into_usize();
 
         let mut it = string
             .char_indices()
@@ -73,16 +75,7 @@ impl<'a> Resolve<'a> for LitByte {
         let c = match c {
             '\\' => {
                 let c =
-                    match ast::utils::parse_byte_escape(&mut it, ast::utils::WithLineCont(false)) {
-                        Ok(c) => c,
-                        Err(kind) => {
-                            let end = it
-                                .next()
-                                .map(|n| n.0)
-                                .unwrap_or_else(|| span.end.into_usize());
-                            return Err(ResolveError::new(Span::new(start, end), kind));
-                        }
-                    };
+                    ast::utils::parse_byte_escape(start, &mut it, ast::utils::WithLineCont(false))?;
 
                 match c {
                     Some(c) => c,
@@ -30,16 +30,7 @@ impl LitByteStr {
         while let Some((start, c)) = it.next() {
             buffer.extend(match c {
                 '\\' => {
-                    match ast::utils::parse_byte_escape(&mut it, ast::utils::WithLineCont(true)) {
-                        Ok(c) => c,
-                        Err(kind) => {
-                            let end = it
-                                .next()
-                                .map(|n| n.0)
-                                .unwrap_or_else(|| span.end.into_usize());
-                            return Err(ResolveError::new(Span::new(start, end), kind));
-                        }
-                    }
+                    ast::utils::parse_byte_escape(start, &mut it, ast::utils::WithLineCont(true))?
                 }
                 c => Some(c as u8),
             });
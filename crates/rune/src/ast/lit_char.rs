@@ -44,6 +44,8 @@ impl LitChar {
 /// testing::roundtrip::<ast::LitChar>("'\\n'");
 /// testing::roundtrip::<ast::LitChar>("'\\r'");
 /// testing::roundtrip::<ast::LitChar>("'\\''");
+/// testing::roundtrip::<ast::LitChar>("'\\x7F'");
+/// testing::roundtrip::<ast::LitChar>("'\\u{1F600}'");
 /// ```
 impl Parse for LitChar {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -67,11 +69,13 @@ impl<'a> Resolve<'a> for LitChar {
 
         let span = self.token.span();
 
+        let inner = span.narrow(1);
+
         let string = source
-            .source(span.narrow(1))
+            .source(inner)
             .ok_or_else(|| ResolveError::new(span, ResolveErrorKind::BadSlice))?;
 
-        let start = span.start.into_usize();
+        let start = inner.start.into_usize();
 
         let mut it = string
             .char_indices()
@@ -87,20 +91,12 @@ impl<'a> Resolve<'a> for LitChar {
 
         let c = match c {
             '\\' => {
-                let c = match ast::utils::parse_char_escape(
+                let c = ast::utils::parse_char_escape(
+                    start,
                     &mut it,
                     ast::utils::WithTemplate(false),
                     ast::utils::WithLineCont(false),
-                ) {
-                    Ok(c) => c,
-                    Err(kind) => {
-                        let end = it
-                            .next()
-                            .map(|n| n.0)
-                            .unwrap_or_else(|| span.end.into_usize());
-                        return Err(ResolveError::new(Span::new(start, end), kind));
-                    }
-                };
+                )?;
 
                 match c {
                     Some(c) => c,
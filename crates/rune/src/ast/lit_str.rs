@@ -49,6 +49,19 @@ impl LitStr {
             }
         };
 
+        if let Some(hashes) = text.raw {
+            let span = Span::new(
+                span.start.into_usize() + hashes + 2,
+                span.end.into_usize() - hashes - 1,
+            );
+
+            let string = source
+                .source(span)
+                .ok_or_else(|| ResolveError::new(span, ResolveErrorKind::BadSlice))?;
+
+            return Ok(Cow::Borrowed(string));
+        }
+
         let span = if text.wrapped { span.narrow(1) } else { span };
 
         let string = source
@@ -78,20 +91,12 @@ impl LitStr {
 
         while let Some((start, c)) = it.next() {
             buffer.extend(match c {
-                '\\' => match ast::utils::parse_char_escape(
+                '\\' => ast::utils::parse_char_escape(
+                    start,
                     &mut it,
                     with_template,
                     ast::utils::WithLineCont(true),
-                ) {
-                    Ok(c) => c,
-                    Err(kind) => {
-                        let end = it
-                            .next()
-                            .map(|n| n.0)
-                            .unwrap_or_else(|| span.end.into_usize());
-                        return Err(ResolveError::new(Span::new(start, end), kind));
-                    }
-                },
+                )?,
                 c => Some(c),
             });
         }
@@ -109,6 +114,8 @@ impl LitStr {
 ///
 /// testing::roundtrip::<ast::LitStr>("\"hello world\"");
 /// testing::roundtrip::<ast::LitStr>("\"hello\\nworld\"");
+/// testing::roundtrip::<ast::LitStr>("r\"C:\\foo\"");
+/// testing::roundtrip::<ast::LitStr>("r#\"say \"hi\"\"#");
 /// ```
 impl Parse for LitStr {
     fn parse(parser: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -66,11 +66,13 @@ mod expr_try;
 mod expr_tuple;
 mod expr_unary;
 mod expr_vec;
+mod expr_vec_repeat;
 mod expr_while;
 mod expr_yield;
 mod file;
 mod fn_arg;
 mod force_semi;
+mod generics;
 mod grouped;
 mod ident;
 mod item;
@@ -98,13 +100,14 @@ mod token;
 pub(super) mod utils;
 mod vis;
 
-pub use self::attribute::Attribute;
+pub use self::attribute::{AttrList, AttrMeta, AttrNameValue, AttrNested, Attribute};
 pub use self::block::Block;
 pub use self::condition::Condition;
 pub use self::expr::{Expr, ExprWithoutBinary};
 pub use self::expr_assign::ExprAssign;
 pub use self::expr_await::ExprAwait;
-pub use self::expr_binary::{BinOp, ExprBinary};
+pub(crate) use self::expr_binary::is_valid_custom_operator;
+pub use self::expr_binary::{Assoc, BinOp, ExprBinary};
 pub use self::expr_block::ExprBlock;
 pub use self::expr_break::{ExprBreak, ExprBreakValue};
 pub use self::expr_call::ExprCall;
@@ -126,13 +129,15 @@ pub use self::expr_select::{ExprSelect, ExprSelectBranch};
 pub use self::expr_try::ExprTry;
 pub use self::expr_tuple::ExprTuple;
 pub use self::expr_unary::{ExprUnary, UnOp};
-pub use self::expr_vec::ExprVec;
+pub use self::expr_vec::{ExprVec, ExprVecItem};
+pub use self::expr_vec_repeat::ExprVecRepeat;
 pub use self::expr_while::ExprWhile;
 pub use self::expr_yield::ExprYield;
 pub use self::file::File;
 pub use self::fn_arg::FnArg;
 pub use self::force_semi::ForceSemi;
 pub use self::generated::Kind;
+pub use self::generics::{GenericParam, TypeBounds, WhereClause, WherePredicate};
 pub use self::grouped::{AngleBracketed, Braced, Bracketed, Parenthesized};
 pub use self::ident::Ident;
 pub use self::item::Item;
@@ -153,7 +158,7 @@ pub use self::lit_number::LitNumber;
 pub use self::lit_str::LitStr;
 pub use self::local::Local;
 pub use self::macro_call::MacroCall;
-pub use self::pat::{Pat, PatBinding, PatLit, PatObject, PatPath, PatTuple, PatVec};
+pub use self::pat::{Pat, PatAt, PatBinding, PatLit, PatObject, PatPath, PatTuple, PatVec};
 pub use self::path::{Path, PathKind, PathSegment};
 pub use self::stmt::{ItemOrExpr, Stmt, StmtSortKey};
 pub use self::token::{
@@ -217,7 +222,117 @@ decl_tokens! {
 
 #[cfg(test)]
 mod tests {
-    use crate::{ast, parse_all};
+    use crate::{ast, parse_all, parse_partial};
+
+    #[test]
+    fn test_condition_eager_brace() {
+        let expr = parse_all::<ast::ExprIf>("if foo { }").unwrap();
+        assert!(matches!(
+            expr.condition,
+            ast::Condition::Expr(ast::Expr::Path(..))
+        ));
+
+        let expr = parse_all::<ast::ExprIf>("if (Foo { }) { }").unwrap();
+
+        match expr.condition {
+            ast::Condition::Expr(ast::Expr::Group(group)) => {
+                assert!(matches!(group.expr, ast::Expr::Object(..)));
+            }
+            _ => panic!("expected group"),
+        }
+
+        let expr = parse_all::<ast::ExprWhile>("while ..foo { }").unwrap();
+        assert!(matches!(
+            expr.condition,
+            ast::Condition::Expr(ast::Expr::Range(..))
+        ));
+
+        let expr = parse_all::<ast::ExprMatch>("match foo { _ => () }").unwrap();
+        assert!(matches!(expr.expr, ast::Expr::Path(..)));
+    }
+
+    #[test]
+    fn test_trailing_comma() {
+        parse_all::<ast::Expr>("foo(1, 2,)").unwrap();
+        parse_all::<ast::Expr>("(1, 2,)").unwrap();
+        parse_all::<ast::Expr>("[1, 2,]").unwrap();
+        parse_all::<ast::Expr>("#{a: 1, b: 2,}").unwrap();
+        parse_all::<ast::Expr>("Foo {a: 1, b: 2,}").unwrap();
+        parse_all::<ast::Expr>("Foo(1, 2,)").unwrap();
+        parse_all::<ast::Expr>("match x { 1 => 2, _ => 3, }").unwrap();
+
+        // NB: a lone comma is not a valid unit, tuple, vector or argument list.
+        assert!(parse_all::<ast::Expr>("(,)").is_err());
+        assert!(parse_all::<ast::Expr>("[,]").is_err());
+        assert!(parse_all::<ast::Expr>("foo(,)").is_err());
+        assert!(parse_all::<ast::Expr>("#{,}").is_err());
+    }
+
+    #[test]
+    fn test_lookahead() {
+        use crate::Parser;
+
+        let mut p = Parser::new("const { 1 } + 2");
+        assert!(matches!(p.peeker().nth(3), K!['}']));
+        assert!(matches!(p.peeker().nth(5), K![number]));
+        assert!(matches!(p.peeker().nth(6), ast::Kind::Eof));
+
+        // NB: lookahead doesn't consume anything.
+        let expr = p.parse_all::<ast::Expr>().unwrap();
+        assert!(matches!(expr, ast::Expr::Binary(..)));
+
+        parse_all::<ast::File>("fn main() { const { 1 + 2 } }").unwrap();
+        parse_all::<ast::File>("fn main() { const FOO = 1; FOO }").unwrap();
+        parse_all::<ast::File>("fn main() { const fn foo() {} foo() }").unwrap();
+
+        // NB: needs three tokens of lookahead to tell that `Foo { a:` is an
+        // object and not `Foo` followed by a block.
+        let expr = parse_all::<ast::ExprMatch>("match Foo { a: 1 } { Foo { a } => a }").unwrap();
+        assert!(matches!(expr.expr, ast::Expr::Object(..)));
+
+        let expr = parse_all::<ast::ExprMatch>("match foo { a => a }").unwrap();
+        assert!(matches!(expr.expr, ast::Expr::Path(..)));
+    }
+
+    #[test]
+    fn test_fn_generics() {
+        use crate::testing::roundtrip;
+
+        let item =
+            roundtrip::<ast::ItemFn>("fn foo<T, U: Bar + baz::Baz,>(a, b) where T: Bar, U: Baz {}");
+
+        let generics = item.generics.expect("generics");
+        let params = generics.as_slice();
+        assert_eq!(params.len(), 2);
+        assert!(params[0].0.bounds.is_none());
+
+        let (_, bounds) = params[1].0.bounds.as_ref().expect("bounds");
+        assert_eq!(bounds.rest.len(), 1);
+
+        let where_clause = item.where_clause.expect("where clause");
+        assert_eq!(where_clause.predicates.len(), 2);
+
+        roundtrip::<ast::ItemFn>("fn foo<>() where {}");
+        roundtrip::<ast::File>("pub async fn foo<T>(a) where T: Bar, { a }");
+
+        assert!(parse_all::<ast::ItemFn>("fn foo<T>() where T {}").is_err());
+        assert!(parse_all::<ast::ItemFn>("fn foo<T: >() {}").is_err());
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        let source = "foo(1) + 2 bar(2)";
+
+        let (first, offset) = parse_partial::<ast::Expr>(source).unwrap();
+        assert!(matches!(first, ast::Expr::Binary(..)));
+        assert_eq!(offset, 10);
+
+        let (second, rest) = parse_partial::<ast::Expr>(&source[offset..]).unwrap();
+        assert!(matches!(second, ast::Expr::Call(..)));
+        assert_eq!(offset + rest, source.len());
+
+        assert!(parse_all::<ast::Expr>(source).is_err());
+    }
 
     #[test]
     fn test_expr() {
@@ -20,6 +20,8 @@ pub enum Pat {
     PatBinding(PatBinding),
     /// The rest pattern `..`.
     PatRest(PatRest),
+    /// A binding pattern `n @ pattern`.
+    PatAt(PatAt),
 }
 
 /// Parsing a block expression.
@@ -41,6 +43,11 @@ pub enum Pat {
 /// testing::roundtrip::<ast::Pat>("var");
 /// testing::roundtrip::<ast::Pat>("_");
 /// testing::roundtrip::<ast::Pat>("Foo(n)");
+/// testing::roundtrip::<ast::Pat>("1..=5");
+/// testing::roundtrip::<ast::Pat>("-10..0");
+/// testing::roundtrip::<ast::Pat>("10..");
+/// testing::roundtrip::<ast::Pat>("n @ 1..=5");
+/// testing::roundtrip::<ast::Pat>("n @ Foo(_)");
 /// ```
 impl Parse for Pat {
     fn parse(p: &mut Parser<'_>) -> Result<Self, ParseError> {
@@ -86,10 +93,8 @@ impl Parse for Pat {
                 });
             }
             K![number] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: ast::Expr::from_lit(ast::Lit::Number(p.parse()?)),
-                }));
+                let expr = ast::Expr::from_lit(ast::Lit::Number(p.parse()?));
+                return Ok(Self::PatLit(PatLit::parse_with_start(p, attributes, expr)?));
             }
             K![..] => {
                 return Ok(Self::PatRest(PatRest {
@@ -120,10 +125,10 @@ impl Parse for Pat {
                 }))
             }
             K![-] => {
-                let expr: ast::Expr = p.parse()?;
+                let expr = PatLit::parse_neg(p)?;
 
                 if expr.is_lit() {
-                    return Ok(Self::PatLit(PatLit { attributes, expr }));
+                    return Ok(Self::PatLit(PatLit::parse_with_start(p, attributes, expr)?));
                 }
             }
             K![_] => {
@@ -152,6 +157,15 @@ impl Parse for Pat {
                         colon: p.parse()?,
                         pat: p.parse()?,
                     }),
+                    K![@] => match path.try_as_ident() {
+                        Some(ident) => Self::PatAt(PatAt {
+                            attributes,
+                            ident: *ident,
+                            at: p.parse()?,
+                            pat: p.parse()?,
+                        }),
+                        None => return Err(ParseError::expected(&path, "binding identifier")),
+                    },
                     _ => Self::PatPath(PatPath { attributes, path }),
                 });
             }
@@ -188,6 +202,60 @@ pub struct PatLit {
     pub expr: ast::Expr,
 }
 
+impl PatLit {
+    /// Parse the rest of a number pattern which starts with `from`, which
+    /// might turn out to be a range pattern like `1..=5`.
+    fn parse_with_start(
+        p: &mut Parser<'_>,
+        attributes: Vec<ast::Attribute>,
+        from: ast::Expr,
+    ) -> Result<Self, ParseError> {
+        let limits = match p.nth(0)? {
+            K![..] | K![..=] => p.parse::<ast::ExprRangeLimits>()?,
+            _ => {
+                return Ok(Self {
+                    attributes,
+                    expr: from,
+                })
+            }
+        };
+
+        let to = match p.nth(0)? {
+            K![number] => Some(ast::Expr::from_lit(ast::Lit::Number(p.parse()?))),
+            K![-] => {
+                let expr = Self::parse_neg(p)?;
+
+                if !expr.is_lit() {
+                    return Err(ParseError::unsupported(&expr, "range bound"));
+                }
+
+                Some(expr)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            attributes,
+            expr: ast::Expr::Range(Box::new(ast::ExprRange {
+                attributes: Vec::new(),
+                from: Some(from),
+                limits,
+                to,
+            })),
+        })
+    }
+
+    /// Parse a negated literal without consuming any trailing range.
+    fn parse_neg(p: &mut Parser<'_>) -> Result<ast::Expr, ParseError> {
+        ast::Expr::parse_with(
+            p,
+            ast::expr::EagerBrace(false),
+            ast::expr::EagerBinary(false),
+            ast::expr::Callable(false),
+        )
+    }
+}
+
 /// The rest pattern `..` and associated attributes.
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct PatRest {
@@ -257,6 +325,21 @@ pub struct PatPath {
     pub path: ast::Path,
 }
 
+/// A binding pattern `n @ pattern`, which binds the matched value to `n` if
+/// the inner pattern matches.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+pub struct PatAt {
+    /// Attributes associate with the binding.
+    #[rune(iter)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The name the matched value is bound to.
+    pub ident: ast::Ident,
+    /// The `@` token.
+    pub at: T![@],
+    /// The pattern the value is matched against.
+    pub pat: Box<ast::Pat>,
+}
+
 /// A ignore pattern.
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 pub struct PatIgnore {
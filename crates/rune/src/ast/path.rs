@@ -20,6 +20,8 @@ use crate::{
 /// testing::roundtrip::<ast::Path>("super::bar");
 /// testing::roundtrip::<ast::Path>("HashMap::<Foo, Bar>");
 /// testing::roundtrip::<ast::Path>("super::HashMap::<Foo, Bar>");
+/// testing::roundtrip::<ast::Path>("a::<b>");
+/// testing::roundtrip::<ast::Path>("Vec::<i64>::new");
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned)]
 pub struct Path {
@@ -111,6 +113,8 @@ impl Description for &Path {
 }
 
 /// Resolve implementation for path which "stringifies" it.
+///
+/// Generic arguments are not part of the resolved string.
 impl<'a> Resolve<'a> for Path {
     type Output = Box<str>;
 
@@ -141,12 +145,15 @@ impl<'a> Resolve<'a> for Path {
             PathSegment::Super(_) => {
                 buf.push_str("super");
             }
-            PathSegment::Generics(_) => {
-                buf.push_str("<*>");
-            }
+            // NB: generic arguments are ignored for now.
+            PathSegment::Generics(_) => (),
         }
 
         for (_, segment) in &self.rest {
+            if let PathSegment::Generics(_) = segment {
+                continue;
+            }
+
             buf.push_str("::");
 
             match segment {
@@ -165,9 +172,7 @@ impl<'a> Resolve<'a> for Path {
                 PathSegment::Super(_) => {
                     buf.push_str("super");
                 }
-                PathSegment::Generics(_) => {
-                    buf.push_str("<*>");
-                }
+                PathSegment::Generics(_) => (),
             }
         }
 
@@ -275,3 +280,28 @@ impl Peek for PathSegment {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parsing::Parser;
+    use crate::{ast, testing, Resolve as _, Storage};
+    use runestick::Source;
+
+    #[test]
+    fn test_path_generics() {
+        let path = testing::roundtrip::<ast::Path>("a::<b>");
+        assert!(path.try_as_ident().is_none());
+
+        let source = Source::new("test", "Vec::<i64>::new");
+        let path = testing::roundtrip::<ast::Path>(source.as_str());
+        assert!(path.try_as_ident().is_none());
+
+        let resolved = path.resolve(&Storage::default(), &source).unwrap();
+        assert_eq!(&*resolved, "Vec::new");
+
+        testing::roundtrip::<ast::Expr>("foo::<Bar>()");
+
+        let mut parser = Parser::new("a::<");
+        assert!(parser.parse::<ast::Path>().is_err());
+    }
+}
@@ -111,7 +111,7 @@ impl Peek for ItemOrExpr {
             K![async] => matches!(p.nth(1), K![fn]),
             K![fn] => true,
             K![mod] => true,
-            K![const] => true,
+            K![const] => !matches!(p.nth(1), K!['{']),
             K![ident(..)] => true,
             K![::] => true,
             _ => ast::Expr::peek(p),
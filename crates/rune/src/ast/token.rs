@@ -26,7 +26,7 @@ impl Token {
                     let s = ctx.source().source(self.span).ok_or_else(|| fmt::Error)?;
                     write!(f, "{}", s)?;
                 }
-                StringSource::Synthetic(id) => {
+                StringSource::Synthetic(id) | StringSource::Hygienic { id, .. } => {
                     match ctx.storage().with_string(*id, |s| write!(f, "{}", s)) {
                         Some(result) => result?,
                         None => return Err(fmt::Error),
@@ -41,7 +41,7 @@ impl Token {
                     let s = ctx.source().source(self.span).ok_or_else(|| fmt::Error)?;
                     write!(f, "{}", s)?;
                 }
-                StringSource::Synthetic(id) => {
+                StringSource::Synthetic(id) | StringSource::Hygienic { id, .. } => {
                     match ctx.storage().with_string(*id, |s| write!(f, "'{}", s)) {
                         Some(result) => result?,
                         None => return Err(fmt::Error),
@@ -103,6 +103,10 @@ impl Token {
                 }
             },
             Kind::Str(s) => match s {
+                StrSource::Text(text) if text.raw.is_some() => {
+                    let s = ctx.source().source(self.span).ok_or_else(|| fmt::Error)?;
+                    write!(f, "{}", s)?;
+                }
                 StrSource::Text(text) => {
                     let span = if text.wrapped {
                         self.span.narrow(1)
@@ -347,6 +351,17 @@ pub enum StringSource {
     Text,
     /// The identifier is synthetic (generated in a macro).
     Synthetic(usize),
+    /// The identifier is synthetic and was introduced by the macro expansion
+    /// identified by `hygiene`.
+    ///
+    /// Local variables declared through a hygienic identifier are only visible
+    /// to identifiers from the same expansion.
+    Hygienic {
+        /// The id of the synthetic string.
+        id: usize,
+        /// The macro expansion the identifier belongs to.
+        hygiene: usize,
+    },
     /// Built-in strings.
     BuiltIn(BuiltIn),
 }
@@ -367,6 +382,9 @@ pub struct StrText {
     pub escaped: bool,
     /// Indicated if the buffer is wrapped or not.
     pub wrapped: bool,
+    /// The number of `#` hashes delimiting the string if it's a raw string,
+    /// like `r#"..."#`.
+    pub raw: Option<usize>,
 }
 
 /// The source of a number.
@@ -397,6 +415,8 @@ where
 pub struct NumberText {
     /// Indicates if it's a decimal number.
     pub is_fractional: bool,
+    /// Indicates if the number has an exponent, like `1e10`.
+    pub has_exponent: bool,
     /// The number literal kind.
     pub base: NumberBase,
 }
@@ -1,5 +1,6 @@
 use crate::ast;
-use crate::ResolveErrorKind;
+use crate::{ResolveError, ResolveErrorKind};
+use runestick::Span;
 use std::iter::Peekable;
 use std::ops;
 
@@ -27,14 +28,21 @@ impl ops::Deref for WithLineCont {
     }
 }
 
-/// Parse a byte escape sequence.
+/// Parse a byte escape sequence, where `start` is the position of the
+/// backslash which starts it.
 pub(super) fn parse_byte_escape(
+    start: usize,
     it: &mut Peekable<impl Iterator<Item = (usize, char)>>,
     with_line_cont: WithLineCont,
-) -> Result<Option<u8>, ResolveErrorKind> {
-    let (_, c) = it
-        .next()
-        .ok_or_else(|| ResolveErrorKind::BadEscapeSequence)?;
+) -> Result<Option<u8>, ResolveError> {
+    let (s, c) = it.next().ok_or_else(|| {
+        ResolveError::new(
+            Span::new(start, start + 1),
+            ResolveErrorKind::BadEscapeSequence,
+        )
+    })?;
+
+    let end = s + c.len_utf8();
 
     Ok(Some(match c {
         '\n' | '\r' if *with_line_cont => {
@@ -56,32 +64,48 @@ pub(super) fn parse_byte_escape(
         '\\' => b'\\',
         '0' => b'\0',
         'x' => {
-            let result = parse_hex_escape(it)?;
+            let result = parse_hex_escape(end, it)?;
 
             if result > 0xff {
-                return Err(ResolveErrorKind::BadHexEscapeByte);
+                return Err(ResolveError::new(
+                    Span::new(end, end + 2),
+                    ResolveErrorKind::BadHexEscapeByte,
+                ));
             }
 
             result as u8
         }
         'u' => {
-            return Err(ResolveErrorKind::BadUnicodeEscapeInByteString);
+            return Err(ResolveError::new(
+                Span::new(start, end),
+                ResolveErrorKind::BadUnicodeEscapeInByteString,
+            ));
         }
         _ => {
-            return Err(ResolveErrorKind::BadEscapeSequence);
+            return Err(ResolveError::new(
+                Span::new(start, end),
+                ResolveErrorKind::BadEscapeSequence,
+            ));
         }
     }))
 }
 
-/// Parse a byte escape sequence.
+/// Parse a character escape sequence, where `start` is the position of the
+/// backslash which starts it.
 pub(super) fn parse_char_escape(
+    start: usize,
     it: &mut Peekable<impl Iterator<Item = (usize, char)>>,
     with_template: WithTemplate,
     with_line_cont: WithLineCont,
-) -> Result<Option<char>, ResolveErrorKind> {
-    let (_, c) = it
-        .next()
-        .ok_or_else(|| ResolveErrorKind::BadEscapeSequence)?;
+) -> Result<Option<char>, ResolveError> {
+    let (s, c) = it.next().ok_or_else(|| {
+        ResolveError::new(
+            Span::new(start, start + 1),
+            ResolveErrorKind::BadEscapeSequence,
+        )
+    })?;
+
+    let end = s + c.len_utf8();
 
     Ok(Some(match c {
         '\n' | '\r' if *with_line_cont => {
@@ -105,85 +129,119 @@ pub(super) fn parse_char_escape(
         '\\' => '\\',
         '0' => '\0',
         'x' => {
-            let result = parse_hex_escape(it)?;
+            let result = parse_hex_escape(end, it)?;
+
+            // NB: both digits are known to be ASCII at this point.
+            let span = Span::new(end, end + 2);
 
             if result > 0x7f {
-                return Err(ResolveErrorKind::BadHexEscapeChar);
+                return Err(ResolveError::new(span, ResolveErrorKind::BadHexEscapeChar));
             }
 
             if let Some(c) = std::char::from_u32(result) {
                 c
             } else {
-                return Err(ResolveErrorKind::BadByteEscape);
+                return Err(ResolveError::new(span, ResolveErrorKind::BadByteEscape));
             }
         }
-        'u' => parse_unicode_escape(it)?,
+        'u' => parse_unicode_escape(end, it)?,
         _ => {
-            return Err(ResolveErrorKind::BadEscapeSequence);
+            return Err(ResolveError::new(
+                Span::new(start, end),
+                ResolveErrorKind::BadEscapeSequence,
+            ));
         }
     }))
 }
 
-/// Parse a hex escape.
+/// Parse the two digits of a hex escape, where `start` is the position of
+/// the first digit.
 fn parse_hex_escape(
+    start: usize,
     it: &mut Peekable<impl Iterator<Item = (usize, char)>>,
-) -> Result<u32, ResolveErrorKind> {
+) -> Result<u32, ResolveError> {
     let mut result = 0u32;
+    let mut end = start;
 
     for _ in 0..2 {
-        let (_, c) = it.next().ok_or_else(|| ResolveErrorKind::BadByteEscape)?;
+        let (s, c) = it.next().ok_or_else(|| {
+            ResolveError::new(Span::new(start, end), ResolveErrorKind::BadByteEscape)
+        })?;
+
+        end = s + c.len_utf8();
 
-        result = result
-            .checked_shl(4)
-            .ok_or_else(|| ResolveErrorKind::BadByteEscape)?;
+        result = result.checked_mul(16).ok_or_else(|| {
+            ResolveError::new(Span::new(start, end), ResolveErrorKind::BadByteEscape)
+        })?;
 
         result += match c {
             '0'..='9' => c as u32 - '0' as u32,
             'a'..='f' => c as u32 - 'a' as u32 + 10,
             'A'..='F' => c as u32 - 'A' as u32 + 10,
-            _ => return Err(ResolveErrorKind::BadByteEscape),
+            _ => {
+                return Err(ResolveError::new(
+                    Span::new(start, end),
+                    ResolveErrorKind::BadByteEscape,
+                ))
+            }
         };
     }
 
     Ok(result)
 }
 
-/// Parse a unicode escape.
+/// Parse a unicode escape, where `start` is the position of the opening
+/// brace.
 pub(super) fn parse_unicode_escape(
+    start: usize,
     it: &mut Peekable<impl Iterator<Item = (usize, char)>>,
-) -> Result<char, ResolveErrorKind> {
-    match it.next() {
-        Some((_, '{')) => (),
-        _ => return Err(ResolveErrorKind::BadUnicodeEscape),
+) -> Result<char, ResolveError> {
+    let mut end = match it.next() {
+        Some((s, '{')) => s + 1,
+        Some((s, c)) => {
+            return Err(ResolveError::new(
+                Span::new(s, s + c.len_utf8()),
+                ResolveErrorKind::BadUnicodeEscape,
+            ));
+        }
+        None => {
+            return Err(ResolveError::new(
+                Span::point(start),
+                ResolveErrorKind::BadUnicodeEscape,
+            ));
+        }
     };
 
     let mut first = true;
     let mut result = 0u32;
 
     loop {
-        let (_, c) = it
-            .next()
-            .ok_or_else(|| ResolveErrorKind::BadUnicodeEscape)?;
+        let (s, c) = it.next().ok_or_else(|| {
+            ResolveError::new(Span::new(start, end), ResolveErrorKind::BadUnicodeEscape)
+        })?;
+
+        end = s + c.len_utf8();
+        let err = || ResolveError::new(Span::new(start, end), ResolveErrorKind::BadUnicodeEscape);
 
         match c {
             '}' => {
                 if first {
-                    return Err(ResolveErrorKind::BadUnicodeEscape);
+                    return Err(err());
                 }
 
                 if let Some(c) = std::char::from_u32(result) {
                     return Ok(c);
                 }
 
-                return Err(ResolveErrorKind::BadUnicodeEscape);
+                return Err(err());
             }
             c => {
                 first = false;
 
-                result = match result.checked_shl(4) {
+                result = match result.checked_mul(16) {
                     Some(result) => result,
                     None => {
-                        return Err(ResolveErrorKind::BadUnicodeEscape);
+                        return Err(err());
                     }
                 };
 
@@ -192,7 +250,7 @@ pub(super) fn parse_unicode_escape(
                     'a'..='f' => c as u32 - 'a' as u32 + 10,
                     'A'..='F' => c as u32 - 'A' as u32 + 10,
                     _ => {
-                        return Err(ResolveErrorKind::BadUnicodeEscape);
+                        return Err(err());
                     }
                 };
             }
@@ -228,20 +286,20 @@ mod tests {
 
     #[test]
     fn test_parse_hex_escape() {
-        assert!(parse_hex_escape(input!("a")).is_err());
+        assert!(parse_hex_escape(0, input!("a")).is_err());
 
-        let c = parse_hex_escape(input!("7f")).unwrap();
+        let c = parse_hex_escape(0, input!("7f")).unwrap();
         assert_eq!(c, 0x7f);
     }
 
     #[test]
     fn test_parse_unicode_escape() {
-        parse_unicode_escape(input!("{0}")).unwrap();
+        parse_unicode_escape(0, input!("{0}")).unwrap();
 
-        let c = parse_unicode_escape(input!("{1F4AF}")).unwrap();
+        let c = parse_unicode_escape(0, input!("{1F4AF}")).unwrap();
         assert_eq!(c, '💯');
 
-        let c = parse_unicode_escape(input!("{1f4af}")).unwrap();
+        let c = parse_unicode_escape(0, input!("{1f4af}")).unwrap();
         assert_eq!(c, '💯');
     }
 }
@@ -1,7 +1,10 @@
 mod attributes;
 use crate::ast;
-use crate::{Parse, ParseError, Resolve as _, Storage};
-use runestick::Source;
+use crate::{
+    CompileError, CompileErrorKind, Parse, ParseError, Resolve as _, Spanned as _, Storage,
+};
+use runestick::{Protocol, Source, Span};
+use std::borrow::Cow;
 
 pub(crate) use self::attributes::Attributes;
 
@@ -59,3 +62,93 @@ impl Attribute for Test {
     /// Must match the specified name.
     const PATH: &'static str = "test";
 }
+
+/// The `#[deprecated("message")]` attribute.
+#[derive(Parse)]
+pub(crate) struct Deprecated {
+    /// The deprecation message.
+    pub message: ast::Parenthesized<ast::LitStr, T![,]>,
+}
+
+impl Deprecated {
+    /// Resolve the deprecation message, which must be a single string.
+    pub(crate) fn message<'a>(
+        &self,
+        storage: &Storage,
+        source: &'a Source,
+    ) -> Result<Cow<'a, str>, CompileError> {
+        match self.message.as_slice() {
+            [(message, _)] => Ok(message.resolve(storage, source)?),
+            _ => Err(CompileError::msg(
+                &self.message,
+                "expected a single deprecation message",
+            )),
+        }
+    }
+}
+
+impl Attribute for Deprecated {
+    /// Must match the specified name.
+    const PATH: &'static str = "deprecated";
+}
+
+/// The `#[derive(Debug, PartialEq)]` attribute.
+#[derive(Parse)]
+pub(crate) struct Derive {
+    /// The names of the derived traits.
+    pub derives: ast::Parenthesized<ast::Ident, T![,]>,
+}
+
+impl Derive {
+    /// Resolve the derived traits, erroring on ones which aren't supported.
+    pub(crate) fn kinds(
+        &self,
+        storage: &Storage,
+        source: &Source,
+    ) -> Result<Vec<(Span, DeriveKind)>, CompileError> {
+        let mut kinds = Vec::new();
+
+        for (ident, _) in &self.derives {
+            let name = ident.resolve(storage, source)?;
+
+            let kind = match name.as_ref() {
+                "Debug" => DeriveKind::Debug,
+                "PartialEq" => DeriveKind::PartialEq,
+                name => {
+                    return Err(CompileError::new(
+                        ident,
+                        CompileErrorKind::UnsupportedDerive { name: name.into() },
+                    ));
+                }
+            };
+
+            kinds.push((ident.span(), kind));
+        }
+
+        Ok(kinds)
+    }
+}
+
+impl Attribute for Derive {
+    /// Must match the specified name.
+    const PATH: &'static str = "derive";
+}
+
+/// A trait which can be derived for script types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeriveKind {
+    /// `Debug`, which implements [Protocol::STRING_DEBUG].
+    Debug,
+    /// `PartialEq`, which implements [Protocol::EQ].
+    PartialEq,
+}
+
+impl DeriveKind {
+    /// The protocol implemented by the derive.
+    pub(crate) fn protocol(self) -> Protocol {
+        match self {
+            Self::Debug => Protocol::STRING_DEBUG,
+            Self::PartialEq => Protocol::EQ,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::collections::HashMap;
 use crate::compiling::{CompileError, CompileErrorKind};
-use runestick::{Hash, Inst, Label, Location, Span};
+use runestick::{DebugVariable, Hash, Inst, Label, Location, Span};
 
 #[derive(Debug, Clone)]
 pub enum AssemblyInst {
@@ -29,6 +29,8 @@ pub struct Assembly {
     pub(crate) instructions: Vec<(AssemblyInst, Span)>,
     /// Comments associated with instructions.
     pub(crate) comments: HashMap<usize, Vec<String>>,
+    /// Variables declared before the instruction at the given offset.
+    pub(crate) variables: HashMap<usize, Vec<DebugVariable>>,
     /// The number of labels.
     pub(crate) label_count: usize,
     /// The collection of functions required by this assembly.
@@ -44,6 +46,7 @@ impl Assembly {
             labels_rev: Default::default(),
             instructions: Default::default(),
             comments: Default::default(),
+            variables: Default::default(),
             label_count,
             required_functions: Default::default(),
         }
@@ -140,4 +143,15 @@ impl Assembly {
 
         self.push(raw, span);
     }
+
+    /// Declare a named variable at the given stack offset, which is in scope
+    /// from the next instruction pushed.
+    pub(crate) fn declare(&mut self, offset: usize, name: &str) {
+        let pos = self.instructions.len();
+
+        self.variables.entry(pos).or_default().push(DebugVariable {
+            name: name.to_owned(),
+            offset,
+        });
+    }
 }
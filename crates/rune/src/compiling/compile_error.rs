@@ -142,8 +142,8 @@ pub enum CompileErrorKind {
     MissingItem { item: Item },
     #[error("unsupported crate prefix `::`")]
     UnsupportedGlobal,
-    #[error("cannot load modules using a source without an associated URL")]
-    UnsupportedModuleSource,
+    #[error("cannot load modules from `{name}`, since it's not associated with a path")]
+    UnsupportedModuleSource { name: String },
     #[error("cannot load modules relative to `{root}`")]
     UnsupportedModuleRoot { root: PathBuf },
     #[error("cannot load module for `{item}`")]
@@ -156,6 +156,8 @@ pub enum CompileErrorKind {
     UnsupportedUnaryOp { op: ast::UnOp },
     #[error("unsupported binary operator `{op}`")]
     UnsupportedBinaryOp { op: ast::BinOp },
+    #[error("unsupported derive `{name}`")]
+    UnsupportedDerive { name: Box<str> },
     #[error("{meta} is not an object")]
     UnsupportedLitObject { meta: CompileMeta },
     #[error("missing field `{field}` in declaration of `{item}`")]
@@ -166,6 +168,8 @@ pub enum CompileErrorKind {
     UnsupportedAssignExpr,
     #[error("unsupported binary expression")]
     UnsupportedBinaryExpr,
+    #[error("range without a start can't be iterated over")]
+    UnsupportedRangeIteration,
     #[error("cannot take reference of expression")]
     UnsupportedRef,
     #[error("unsupported select pattern")]
@@ -186,6 +190,10 @@ pub enum CompileErrorKind {
     UnsupportedPatternExpr,
     #[error("not a valid binding")]
     UnsupportedBinding,
+    #[error("binding shadows constant `{item}`")]
+    ShadowedConstError { item: Item },
+    #[error("`{op}` is not a valid operator")]
+    InvalidOperator { op: Box<str> },
     #[error("floating point numbers cannot be used in patterns")]
     MatchFloatInPattern,
     #[error("duplicate key in literal object")]
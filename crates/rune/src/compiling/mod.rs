@@ -1,4 +1,5 @@
 use crate::ast;
+use crate::attrs::DeriveKind;
 use crate::load::{FileSourceLoader, SourceLoader, Sources};
 use crate::query::{Build, BuildEntry, Query};
 #[cfg(compiler_v2)]
@@ -6,6 +7,7 @@ use crate::shared::ResultExt as _;
 use crate::shared::{Consts, Gen};
 use crate::worker::{LoadFileKind, Task, Worker};
 use crate::{Diagnostics, Options, Spanned as _, Storage};
+use runestick::debug::DebugArgs;
 use runestick::{Context, Location, Source, Span};
 use std::rc::Rc;
 use std::sync::Arc;
@@ -79,6 +81,26 @@ pub fn compile_with_options<'a>(
         gen,
     );
 
+    // NB: the context doesn't know how sources are lexed, so custom operators
+    // can only be checked against builtin syntax here.
+    if let Some(source_id) = worker.sources.source_ids().next() {
+        for op in context.iter_operators() {
+            if !ast::is_valid_custom_operator(op) {
+                worker.diagnostics.error(
+                    source_id,
+                    CompileError::new(
+                        Span::empty(),
+                        CompileErrorKind::InvalidOperator { op: op.into() },
+                    ),
+                );
+            }
+        }
+
+        if worker.diagnostics.has_error() {
+            return Err(());
+        }
+    }
+
     // Queue up the initial sources to be loaded.
     for source_id in worker.sources.source_ids() {
         let mod_item = match worker.query.insert_root_mod(source_id, Span::empty()) {
@@ -135,6 +157,14 @@ pub fn compile_with_options<'a>(
         return Err(());
     }
 
+    if options.embed_sources {
+        for source_id in worker.sources.source_ids() {
+            if let Some(source) = worker.sources.get(source_id) {
+                unit.embed_source(source_id, source.as_str());
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -243,7 +273,7 @@ impl CompileBuildEntry<'_> {
                         count,
                         asm,
                         f.call,
-                        args,
+                        DebugArgs::Named(args),
                     )?;
                 }
             }
@@ -280,6 +310,28 @@ impl CompileBuildEntry<'_> {
                     )?;
                 }
             }
+            Build::Derive(derive) => {
+                use self::v1::AssembleFn as _;
+
+                let protocol = derive.kind.protocol();
+
+                let args = match derive.kind {
+                    DeriveKind::Debug => vec![String::from("self"), String::from("f")],
+                    DeriveKind::PartialEq => vec![String::from("self"), String::from("other")],
+                };
+
+                let mut c = self.compiler1(location, &source, derive.span, &mut asm);
+                derive.assemble_fn(&mut c, true)?;
+
+                self.unit.new_derived_function(
+                    location,
+                    item.item.extended(protocol.name),
+                    derive.type_hash,
+                    protocol,
+                    asm,
+                    args,
+                )?;
+            }
             Build::Closure(closure) => {
                 use self::v1::AssembleClosure as _;
 
@@ -300,7 +352,7 @@ impl CompileBuildEntry<'_> {
                         closure.ast.args.len(),
                         asm,
                         closure.call,
-                        args,
+                        DebugArgs::Closure(args),
                     )?;
                 }
             }
@@ -323,7 +375,7 @@ impl CompileBuildEntry<'_> {
                         args,
                         asm,
                         b.call,
-                        Vec::new(),
+                        DebugArgs::Named(Vec::new()),
                     )?;
                 }
             }
@@ -54,6 +54,7 @@ impl UnitBuilder {
         this.prelude("is_readable", &["is_readable"]);
         this.prelude("is_writable", &["is_writable"]);
         this.prelude("line", &["macros", "builtin", "line"]);
+        this.prelude("matches", &["matches"]);
         this.prelude("None", &["option", "Option", "None"]);
         this.prelude("Object", &["object", "Object"]);
         this.prelude("Ok", &["result", "Result", "Ok"]);
@@ -342,7 +343,7 @@ impl UnitBuilder {
                     .insert(tuple.hash, signature);
             }
             CompileMetaKind::Struct { .. } => {
-                let hash = Hash::type_hash(&meta.item.item);
+                let hash = Hash::of_type_name(&meta.item.item);
 
                 let rtti = Arc::new(Rtti {
                     hash,
@@ -361,7 +362,7 @@ impl UnitBuilder {
             CompileMetaKind::UnitVariant {
                 enum_item, empty, ..
             } => {
-                let enum_hash = Hash::type_hash(enum_item);
+                let enum_hash = Hash::of_type_name(enum_item);
 
                 let rtti = Arc::new(VariantRtti {
                     enum_hash,
@@ -394,7 +395,7 @@ impl UnitBuilder {
             CompileMetaKind::TupleVariant {
                 enum_item, tuple, ..
             } => {
-                let enum_hash = Hash::type_hash(enum_item);
+                let enum_hash = Hash::of_type_name(enum_item);
 
                 let rtti = Arc::new(VariantRtti {
                     enum_hash,
@@ -428,8 +429,8 @@ impl UnitBuilder {
                     .insert(tuple.hash, signature);
             }
             CompileMetaKind::StructVariant { enum_item, .. } => {
-                let hash = Hash::type_hash(&meta.item.item);
-                let enum_hash = Hash::type_hash(enum_item);
+                let hash = Hash::of_type_name(&meta.item.item);
+                let enum_hash = Hash::of_type_name(enum_item);
 
                 let rtti = Arc::new(VariantRtti {
                     enum_hash,
@@ -472,16 +473,19 @@ impl UnitBuilder {
         args: usize,
         assembly: Assembly,
         call: Call,
-        debug_args: Vec<String>,
+        debug_args: DebugArgs,
     ) -> Result<(), CompileError> {
         let mut inner = self.inner.borrow_mut();
 
         let offset = inner.instructions.len();
-        let hash = Hash::type_hash(&path);
+        let hash = Hash::of_type_name(&path);
 
         inner.functions_rev.insert(offset, hash);
         let info = UnitFn::Offset { offset, call, args };
-        let signature = DebugSignature::new(path, debug_args);
+        let signature = DebugSignature {
+            path,
+            args: debug_args,
+        };
 
         if inner.functions.insert(hash, info).is_some() {
             return Err(CompileError::new(
@@ -511,8 +515,8 @@ impl UnitBuilder {
         target: &Item,
     ) -> Result<(), CompileError> {
         let mut inner = self.inner.borrow_mut();
-        let hash = Hash::type_hash(item);
-        let target = Hash::type_hash(target);
+        let hash = Hash::of_type_name(item);
+        let target = Hash::of_type_name(target);
 
         if inner.reexports.insert(hash, target).is_some() {
             return Err(CompileError::new(
@@ -542,7 +546,7 @@ impl UnitBuilder {
 
         let offset = inner.instructions.len();
         let instance_fn = Hash::instance_function(type_hash, name);
-        let hash = Hash::type_hash(&path);
+        let hash = Hash::of_type_name(&path);
 
         let info = UnitFn::Offset { offset, call, args };
         let signature = DebugSignature::new(path, debug_args);
@@ -579,6 +583,59 @@ impl UnitBuilder {
         Ok(())
     }
 
+    /// Declare a new protocol function derived for a type at the current
+    /// instruction pointer.
+    ///
+    /// Unlike other instance functions, it can only be called through the
+    /// protocol and not by its path.
+    pub(crate) fn new_derived_function(
+        &self,
+        location: Location,
+        path: Item,
+        type_hash: Hash,
+        protocol: Protocol,
+        assembly: Assembly,
+        debug_args: Vec<String>,
+    ) -> Result<(), CompileError> {
+        log::trace!("derived fn: {}", path);
+
+        let mut inner = self.inner.borrow_mut();
+
+        let offset = inner.instructions.len();
+        let hash = Hash::instance_function(type_hash, protocol);
+
+        let info = UnitFn::Offset {
+            offset,
+            call: Call::Immediate,
+            args: debug_args.len(),
+        };
+
+        let signature = DebugSignature::new(path, debug_args);
+
+        if inner.functions.insert(hash, info).is_some() {
+            return Err(CompileError::new(
+                location.span,
+                CompileErrorKind::FunctionConflict {
+                    existing: signature,
+                },
+            ));
+        }
+
+        inner.debug_info_mut().functions.insert(hash, signature);
+        inner.functions_rev.insert(offset, hash);
+        inner.add_assembly(location, assembly)?;
+        Ok(())
+    }
+
+    /// Embed the text of the source with the given id in the unit.
+    pub(crate) fn embed_source(&self, source_id: usize, source: &str) {
+        self.inner
+            .borrow_mut()
+            .debug_info_mut()
+            .sources
+            .insert(source_id, source.to_owned());
+    }
+
     /// Try to link the unit with the context, checking that all necessary
     /// functions are provided.
     ///
@@ -679,7 +736,21 @@ impl Inner {
 
         self.required_functions.extend(assembly.required_functions);
 
+        // NB: the span of the last instruction which had one, used for
+        // instructions which were generated without a span.
+        let mut last_span = None;
+
         for (pos, (inst, span)) in assembly.instructions.into_iter().enumerate() {
+            let span = match last_span {
+                Some(last_span) if span.is_empty() => last_span,
+                _ => span,
+            };
+
+            if !span.is_empty() {
+                last_span = Some(span);
+            }
+
+            let ip = self.instructions.len();
             let mut comment = None;
             let label = assembly.labels_rev.get(&pos).copied();
 
@@ -738,6 +809,10 @@ impl Inner {
 
             let debug = self.debug.get_or_insert_with(Default::default);
 
+            if let Some(variables) = assembly.variables.get(&pos) {
+                debug.variables.insert(ip, variables.clone());
+            }
+
             debug.instructions.push(DebugInst {
                 source_id: location.source_id,
                 span,
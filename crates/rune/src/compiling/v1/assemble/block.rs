@@ -13,7 +13,7 @@ impl AssembleClosure for ast::Block {
         let guard = c.scopes.push_child(span)?;
 
         for capture in captures {
-            c.scopes.new_var(&capture.ident, span)?;
+            c.scopes.new_var(&capture.ident, c.asm, span)?;
         }
 
         self.assemble(c, Needs::Value)?.apply(c)?;
@@ -0,0 +1,272 @@
+use crate::attrs::DeriveKind;
+use crate::compiling::v1::assemble::prelude::*;
+use crate::query::{Derive, DeriveFields, DeriveVariant};
+use runestick::{format, InstAddress};
+
+/// Offset of `self`, which the protocol function is called on.
+const SELF: usize = 0;
+/// Offset of the second argument, which is the value compared against or the
+/// string being formatted into.
+const OTHER: usize = 1;
+
+/// Assemble a derived protocol function.
+impl AssembleFn for Derive {
+    fn assemble_fn(&self, c: &mut Compiler<'_>, _: bool) -> CompileResult<()> {
+        log::trace!("Derive => {:?}", c.source.source(self.span));
+
+        match self.kind {
+            DeriveKind::Debug => assemble_debug(self, c),
+            DeriveKind::PartialEq => assemble_partial_eq(self, c),
+        }
+    }
+}
+
+/// Assemble a debug implementation, which formats structs and variants like
+/// `Point { x: 1, y: 2 }`, with fields in the order they're declared.
+fn assemble_debug(derive: &Derive, c: &mut Compiler<'_>) -> CompileResult<()> {
+    let span = derive.span;
+    let end_label = c.asm.new_label("derive_debug_end");
+
+    for variant in &derive.variants {
+        let next_label = c.asm.new_label("derive_debug_next");
+
+        c.asm.push(Inst::LoadLocal { slot: SELF }, span);
+        match_variant(c, variant, span)?;
+        c.asm.pop_and_jump_if_not(0, next_label, span);
+
+        let mut parts = Parts::default();
+
+        match &variant.fields {
+            DeriveFields::Tuple(len) if *len > 0 => {
+                parts.literal(&variant.name);
+                parts.literal("(");
+
+                for index in 0..*len {
+                    if index > 0 {
+                        parts.literal(", ");
+                    }
+
+                    parts.field(c, variant, index, span)?;
+                }
+
+                parts.literal(")");
+            }
+            DeriveFields::Struct(names) if !names.is_empty() => {
+                parts.literal(&variant.name);
+                parts.literal(" { ");
+
+                for (index, name) in names.iter().enumerate() {
+                    if index > 0 {
+                        parts.literal(", ");
+                    }
+
+                    parts.literal(name);
+                    parts.literal(": ");
+                    parts.field(c, variant, index, span)?;
+                }
+
+                parts.literal(" }");
+            }
+            _ => {
+                parts.literal(&variant.name);
+            }
+        }
+
+        parts.finish(c, span)?;
+
+        c.asm.push(
+            Inst::Assign {
+                target: InstTarget::Offset(OTHER),
+                op: InstAssignOp::Add,
+            },
+            span,
+        );
+
+        c.asm.jump(end_label, span);
+        c.asm.label(next_label)?;
+    }
+
+    c.asm.label(end_label)?;
+    c.asm.push(Inst::unit(), span);
+    c.asm.push(
+        Inst::Variant {
+            variant: InstVariant::Ok,
+        },
+        span,
+    );
+    c.asm.push(Inst::Clean { count: 2 }, span);
+    c.asm.push(Inst::Return, span);
+    Ok(())
+}
+
+/// Assemble an equality implementation, which compares the fields of two
+/// values of the same struct or variant with `==`.
+fn assemble_partial_eq(derive: &Derive, c: &mut Compiler<'_>) -> CompileResult<()> {
+    let span = derive.span;
+    let eq_label = c.asm.new_label("derive_eq");
+    let ne_label = c.asm.new_label("derive_ne");
+
+    for variant in &derive.variants {
+        let next_label = c.asm.new_label("derive_eq_next");
+
+        c.asm.push(Inst::LoadLocal { slot: SELF }, span);
+        match_variant(c, variant, span)?;
+        c.asm.pop_and_jump_if_not(0, next_label, span);
+
+        c.asm.push(Inst::LoadLocal { slot: OTHER }, span);
+        match_variant(c, variant, span)?;
+        c.asm.pop_and_jump_if_not(0, ne_label, span);
+
+        for index in 0..variant.fields.len() {
+            push_field(c, SELF, variant, index, span)?;
+            push_field(c, OTHER, variant, index, span)?;
+
+            c.asm.push(
+                Inst::Op {
+                    op: InstOp::Eq,
+                    a: InstAddress::Top,
+                    b: InstAddress::Top,
+                },
+                span,
+            );
+
+            c.asm.pop_and_jump_if_not(0, ne_label, span);
+        }
+
+        c.asm.jump(eq_label, span);
+        c.asm.label(next_label)?;
+    }
+
+    for (label, value) in [(ne_label, false), (eq_label, true)].iter().copied() {
+        c.asm.label(label)?;
+        c.asm.push(Inst::bool(value), span);
+        c.asm.push(Inst::Clean { count: 2 }, span);
+        c.asm.push(Inst::Return, span);
+    }
+
+    Ok(())
+}
+
+/// Replace the value on the top of the stack with whether it is the given
+/// struct or variant.
+fn match_variant(c: &mut Compiler<'_>, variant: &DeriveVariant, span: Span) -> CompileResult<()> {
+    let type_check = variant.type_check;
+
+    let inst = match &variant.fields {
+        DeriveFields::Unit => Inst::MatchSequence {
+            type_check,
+            len: 0,
+            exact: true,
+        },
+        DeriveFields::Tuple(len) => Inst::MatchSequence {
+            type_check,
+            len: *len,
+            exact: true,
+        },
+        DeriveFields::Struct(names) => {
+            let slot = c.unit.new_static_object_keys_iter(span, names.iter())?;
+
+            Inst::MatchObject {
+                type_check,
+                slot,
+                exact: true,
+            }
+        }
+    };
+
+    c.asm.push(inst, span);
+    Ok(())
+}
+
+/// Push the field with the given index of the value at `offset`.
+fn push_field(
+    c: &mut Compiler<'_>,
+    offset: usize,
+    variant: &DeriveVariant,
+    index: usize,
+    span: Span,
+) -> CompileResult<()> {
+    let inst = match &variant.fields {
+        DeriveFields::Struct(names) => {
+            let slot = c.unit.new_static_string(span, &names[index])?;
+            Inst::ObjectIndexGetAt { offset, slot }
+        }
+        _ => Inst::TupleIndexGetAt { offset, index },
+    };
+
+    c.asm.push(inst, span);
+    Ok(())
+}
+
+/// The parts of a debug formatted struct or variant, which are concatenated
+/// into a string.
+#[derive(Default)]
+struct Parts {
+    /// Literal text which hasn't been pushed yet.
+    literal: String,
+    /// The number of parts pushed.
+    len: usize,
+    /// The combined length of the literal parts.
+    size_hint: usize,
+}
+
+impl Parts {
+    /// Add literal text.
+    fn literal(&mut self, s: &str) {
+        self.literal.push_str(s);
+    }
+
+    /// Push the debug formatted field with the given index of `self`.
+    fn field(
+        &mut self,
+        c: &mut Compiler<'_>,
+        variant: &DeriveVariant,
+        index: usize,
+        span: Span,
+    ) -> CompileResult<()> {
+        self.flush(c, span)?;
+
+        let spec = format::FormatSpec::new(
+            format::Flags::default(),
+            ' ',
+            format::Alignment::default(),
+            None,
+            None,
+            format::Type::Debug,
+        );
+
+        push_field(c, SELF, variant, index, span)?;
+        c.asm.push(Inst::Format { spec }, span);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Push all parts and concatenate them.
+    fn finish(mut self, c: &mut Compiler<'_>, span: Span) -> CompileResult<()> {
+        self.flush(c, span)?;
+
+        c.asm.push(
+            Inst::StringConcat {
+                len: self.len,
+                size_hint: self.size_hint,
+            },
+            span,
+        );
+
+        Ok(())
+    }
+
+    /// Push the pending literal text, if there is any.
+    fn flush(&mut self, c: &mut Compiler<'_>, span: Span) -> CompileResult<()> {
+        if self.literal.is_empty() {
+            return Ok(());
+        }
+
+        let slot = c.unit.new_static_string(span, &self.literal)?;
+        c.asm.push(Inst::String { slot }, span);
+        self.size_hint += self.literal.len();
+        self.len += 1;
+        self.literal.clear();
+        Ok(())
+    }
+}
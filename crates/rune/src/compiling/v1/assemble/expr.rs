@@ -35,6 +35,7 @@ impl Assemble for ast::Expr {
             ast::Expr::ForceSemi(force_semi) => force_semi.expr.assemble(c, needs)?,
             ast::Expr::Tuple(expr_tuple) => expr_tuple.assemble(c, needs)?,
             ast::Expr::Vec(expr_vec) => expr_vec.assemble(c, needs)?,
+            ast::Expr::VecRepeat(expr_vec_repeat) => expr_vec_repeat.assemble(c, needs)?,
             ast::Expr::Object(expr_object) => expr_object.assemble(c, needs)?,
             ast::Expr::Range(expr_range) => expr_range.assemble(c, needs)?,
             ast::Expr::MacroCall(expr_call_macro) => {
@@ -15,9 +15,9 @@ impl Assemble for ast::ExprAssign {
                     .first
                     .try_as_ident()
                     .ok_or_else(|| CompileError::msg(path, "unsupported path"))?;
-                let ident = segment.resolve(c.storage, &*c.source)?;
+                let ident = segment.resolve_local(c.storage, &*c.source)?;
                 let var = c.scopes.get_var(&*ident, c.source_id, span)?;
-                c.asm.push(Inst::Replace { offset: var.offset }, span);
+                c.asm.push(Inst::StoreLocal { slot: var.offset }, span);
                 true
             }
             // <expr>.<field> = <value>
@@ -26,6 +26,11 @@ impl Assemble for ast::ExprBinary {
             return Ok(Asm::top(span));
         }
 
+        if let ast::BinOp::Custom { hash, .. } = self.op {
+            compile_custom_binop(c, &self.lhs, &self.rhs, hash, needs)?;
+            return Ok(Asm::top(span));
+        }
+
         let guard = c.scopes.push_child(span)?;
 
         // NB: need to declare these as anonymous local variables so that they
@@ -45,13 +50,17 @@ impl Assemble for ast::ExprBinary {
             ast::BinOp::Gte => InstOp::Gte,
             ast::BinOp::Is => InstOp::Is,
             ast::BinOp::IsNot => InstOp::IsNot,
+            ast::BinOp::As => InstOp::As,
             ast::BinOp::And => InstOp::And,
             ast::BinOp::Or => InstOp::Or,
             ast::BinOp::Add => InstOp::Add,
             ast::BinOp::Sub => InstOp::Sub,
+            ast::BinOp::Div if c.options.panic_on_divide_by_zero => InstOp::DivPanic,
             ast::BinOp::Div => InstOp::Div,
             ast::BinOp::Mul => InstOp::Mul,
+            ast::BinOp::Rem if c.options.panic_on_divide_by_zero => InstOp::RemPanic,
             ast::BinOp::Rem => InstOp::Rem,
+            ast::BinOp::Pow => InstOp::Pow,
             ast::BinOp::BitAnd => InstOp::BitAnd,
             ast::BinOp::BitXor => InstOp::BitXor,
             ast::BinOp::BitOr => InstOp::BitOr,
@@ -83,11 +92,37 @@ impl Assemble for ast::ExprBinary {
 /// operator.
 fn rhs_needs_of(op: ast::BinOp) -> Needs {
     match op {
-        ast::BinOp::Is | ast::BinOp::IsNot => Needs::Type,
+        ast::BinOp::Is | ast::BinOp::IsNot | ast::BinOp::As => Needs::Type,
         _ => Needs::Value,
     }
 }
 
+/// Compile a custom operator as a call to the function implementing it.
+fn compile_custom_binop(
+    c: &mut Compiler<'_>,
+    lhs: &ast::Expr,
+    rhs: &ast::Expr,
+    hash: Hash,
+    needs: Needs,
+) -> CompileResult<()> {
+    let span = lhs.span().join(rhs.span());
+
+    lhs.assemble(c, Needs::Value)?.apply(c)?;
+    c.scopes.decl_anon(span)?;
+
+    rhs.assemble(c, Needs::Value)?.apply(c)?;
+    c.scopes.decl_anon(span)?;
+
+    c.asm.push(Inst::Call { hash, args: 2 }, span);
+    c.scopes.undecl_anon(span, 2)?;
+
+    if !needs.value() {
+        c.asm.push(Inst::Pop, span);
+    }
+
+    Ok(())
+}
+
 fn compile_conditional_binop(
     c: &mut Compiler<'_>,
     lhs: &ast::Expr,
@@ -146,7 +181,7 @@ fn compile_assign_binop(
                 .try_as_ident()
                 .ok_or_else(|| CompileError::msg(path, "unsupported path segment"))?;
 
-            let ident = segment.resolve(c.storage, &*c.source)?;
+            let ident = segment.resolve_local(c.storage, &*c.source)?;
             let var = c.scopes.get_var(&*ident, c.source_id, span)?;
 
             Some(InstTarget::Offset(var.offset))
@@ -180,6 +215,16 @@ fn compile_assign_binop(
                 }
             }
         }
+        // <expr>[<index>] <op> <value>
+        ast::Expr::Index(expr_index) => {
+            expr_index.target.assemble(c, Needs::Value)?.apply(c)?;
+            c.scopes.decl_anon(span)?;
+            expr_index.index.assemble(c, Needs::Value)?.apply(c)?;
+            c.scopes.decl_anon(span)?;
+            rhs.assemble(c, Needs::Value)?.apply(c)?;
+            c.scopes.undecl_anon(span, 2)?;
+            Some(InstTarget::Index)
+        }
         _ => None,
     };
 
@@ -197,8 +242,11 @@ fn compile_assign_binop(
         ast::BinOp::AddAssign => InstAssignOp::Add,
         ast::BinOp::SubAssign => InstAssignOp::Sub,
         ast::BinOp::MulAssign => InstAssignOp::Mul,
+        ast::BinOp::DivAssign if c.options.panic_on_divide_by_zero => InstAssignOp::DivPanic,
         ast::BinOp::DivAssign => InstAssignOp::Div,
+        ast::BinOp::RemAssign if c.options.panic_on_divide_by_zero => InstAssignOp::RemPanic,
         ast::BinOp::RemAssign => InstAssignOp::Rem,
+        ast::BinOp::PowAssign => InstAssignOp::Pow,
         ast::BinOp::BitAndAssign => InstAssignOp::BitAnd,
         ast::BinOp::BitXorAssign => InstAssignOp::BitXor,
         ast::BinOp::BitOrAssign => InstAssignOp::BitOr,
@@ -29,7 +29,7 @@ impl AssembleClosure for ast::ExprClosure {
                 c.asm.push(Inst::PushTuple, span);
 
                 for capture in captures {
-                    c.scopes.new_var(&capture.ident, span)?;
+                    c.scopes.new_var(&capture.ident, c.asm, span)?;
                 }
             }
 
@@ -68,7 +68,7 @@ fn try_immediate_field_access_optimization(
         None => return Ok(false),
     };
 
-    let ident = ident.resolve(this.storage, &*this.source)?;
+    let ident = ident.resolve_local(this.storage, &*this.source)?;
 
     let index = match n.resolve(this.storage, &*this.source)? {
         ast::Number::Integer(n) => n,
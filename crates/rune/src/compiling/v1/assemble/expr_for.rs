@@ -6,6 +6,23 @@ impl Assemble for ast::ExprFor {
         let span = self.span();
         log::trace!("ExprFor => {:?}", c.source.source(span));
 
+        // NB: ranges without a start, like `..10` or `(..10)`, can never be
+        // iterated over.
+        let mut iter = &self.iter;
+
+        while let ast::Expr::Group(group) = iter {
+            iter = &group.expr;
+        }
+
+        if let ast::Expr::Range(range) = iter {
+            if range.from.is_none() {
+                return Err(CompileError::new(
+                    range,
+                    CompileErrorKind::UnsupportedRangeIteration,
+                ));
+            }
+        }
+
         let continue_label = c.asm.new_label("for_continue");
         let end_label = c.asm.new_label("for_end");
         let break_label = c.asm.new_label("for_break");
@@ -45,9 +62,7 @@ impl Assemble for ast::ExprFor {
 
             // Declare the named loop variable and put it in the scope.
             c.asm.push_with_comment(
-                Inst::Copy {
-                    offset: iter_offset,
-                },
+                Inst::LoadLocal { slot: iter_offset },
                 span,
                 "copy iterator (memoize)",
             );
@@ -81,17 +96,13 @@ impl Assemble for ast::ExprFor {
         // Use the memoized loop variable.
         if let Some(next_offset) = next_offset {
             c.asm.push_with_comment(
-                Inst::Copy {
-                    offset: iter_offset,
-                },
+                Inst::LoadLocal { slot: iter_offset },
                 self.iter.span(),
                 "copy iterator",
             );
 
             c.asm.push_with_comment(
-                Inst::Copy {
-                    offset: next_offset,
-                },
+                Inst::LoadLocal { slot: next_offset },
                 self.iter.span(),
                 "copy next",
             );
@@ -99,20 +110,16 @@ impl Assemble for ast::ExprFor {
             c.asm.push(Inst::CallFn { args: 1 }, span);
 
             c.asm.push(
-                Inst::Replace {
-                    offset: binding_offset,
+                Inst::StoreLocal {
+                    slot: binding_offset,
                 },
                 binding_span,
             );
         } else {
             // call the `next` function to get the next level of iteration, bind the
             // result to the loop variable in the loop.
-            c.asm.push(
-                Inst::Copy {
-                    offset: iter_offset,
-                },
-                self.iter.span(),
-            );
+            c.asm
+                .push(Inst::LoadLocal { slot: iter_offset }, self.iter.span());
 
             c.asm.push_with_comment(
                 Inst::CallInstance {
@@ -123,8 +130,8 @@ impl Assemble for ast::ExprFor {
                 "next",
             );
             c.asm.push(
-                Inst::Replace {
-                    offset: binding_offset,
+                Inst::StoreLocal {
+                    slot: binding_offset,
                 },
                 binding_span,
             );
@@ -25,7 +25,7 @@ impl Assemble for ast::ExprMatch {
 
             let load = move |this: &mut Compiler, needs: Needs| {
                 if needs.value() {
-                    this.asm.push(Inst::Copy { offset }, span);
+                    this.asm.push(Inst::LoadLocal { slot: offset }, span);
                 }
 
                 Ok(())
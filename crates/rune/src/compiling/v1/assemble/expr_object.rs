@@ -15,7 +15,7 @@ impl Assemble for ast::ExprObject {
 
         for (assign, _) in &self.assignments {
             let span = assign.span();
-            let key = assign.key.resolve(&c.storage, &*c.source)?;
+            let key = assign.key.resolve(c.storage, &c.source)?;
             keys.push(key.as_ref().into());
             check_keys.push((key.as_ref().into(), assign.key.span()));
 
@@ -30,15 +30,27 @@ impl Assemble for ast::ExprObject {
             }
         }
 
+        // NB: values are evaluated left-to-right in source order, which is
+        // also the order of the keys in the static object keys slot. This is a
+        // guarantee since the evaluation of fields might have side effects.
         for (assign, _) in &self.assignments {
             let span = assign.span();
 
             if let Some((_, expr)) = &assign.assign {
                 expr.assemble(c, Needs::Value)?.apply(c)?;
             } else {
-                let key = assign.key.resolve(&c.storage, &*c.source)?;
-                let var = c.scopes.get_var(&*key, c.source_id, span)?;
-                var.copy(&mut c.asm, span, format!("name `{}`", key));
+                let key = assign.key.resolve(c.storage, &c.source)?;
+
+                let local = match &assign.key {
+                    ast::ObjectKey::Path(path) => match path.try_as_ident() {
+                        Some(ident) => ident.resolve_local(c.storage, &c.source)?,
+                        None => key.clone(),
+                    },
+                    ast::ObjectKey::LitStr(..) => key.clone(),
+                };
+
+                let var = c.scopes.get_var(&local, c.source_id, span)?;
+                var.copy(c.asm, span, format!("name `{}`", key));
             }
             c.scopes.decl_anon(span)?;
         }
@@ -18,9 +18,15 @@ impl Assemble for ast::Path {
 
         let named = c.convert_path_to_named(self)?;
 
-        if let Needs::Value = needs {
+        // NB: locals are not types, but they still need to be resolved if
+        // their value is unused, like in `n;`.
+        if !matches!(needs, Needs::Type) {
             if let Some(local) = named.as_local() {
                 if let Some(var) = c.scopes.try_get_var(local, c.source_id, span)? {
+                    if let Needs::None = needs {
+                        return Ok(Asm::top(span));
+                    }
+
                     return Ok(Asm::var(span, *var, local.into()));
                 }
             }
@@ -42,9 +42,11 @@ impl Assemble for ast::ExprUnary {
         self.expr.assemble(c, Needs::Value)?.apply(c)?;
 
         match self.op {
-            ast::UnOp::Not { .. } => {
-                c.asm.push(Inst::Not, span);
-            }
+            ast::UnOp::Not { .. } => match static_type(c, &self.expr)? {
+                Some(StaticType::Bool) => c.asm.push(Inst::BoolNot, span),
+                Some(StaticType::Integer) => c.asm.push(Inst::BitNot, span),
+                None => c.asm.push(Inst::Not, span),
+            },
             ast::UnOp::Neg { .. } => {
                 c.asm.push(Inst::Neg, span);
             }
@@ -65,3 +67,35 @@ impl Assemble for ast::ExprUnary {
         Ok(Asm::top(span))
     }
 }
+
+/// A type which an expression is statically known to evaluate to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StaticType {
+    Bool,
+    Integer,
+}
+
+/// Get the type the given expression is statically known to evaluate to, if
+/// any, so that negating it doesn't have to dispatch on its type at runtime.
+fn static_type(c: &Compiler<'_>, expr: &ast::Expr) -> CompileResult<Option<StaticType>> {
+    Ok(match expr {
+        ast::Expr::Lit(expr_lit) => match &expr_lit.lit {
+            ast::Lit::Bool(..) => Some(StaticType::Bool),
+            ast::Lit::Number(n) => match n.resolve(c.storage, &c.source)? {
+                ast::Number::Integer(..) => Some(StaticType::Integer),
+                ast::Number::Float(..) => None,
+            },
+            _ => None,
+        },
+        ast::Expr::Group(expr_group) => static_type(c, &expr_group.expr)?,
+        ast::Expr::Unary(expr_unary) => match expr_unary.op {
+            ast::UnOp::Not => static_type(c, &expr_unary.expr)?,
+            ast::UnOp::Neg => match static_type(c, &expr_unary.expr)? {
+                Some(StaticType::Integer) => Some(StaticType::Integer),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    })
+}
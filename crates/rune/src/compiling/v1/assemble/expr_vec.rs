@@ -1,20 +1,33 @@
 use crate::compiling::v1::assemble::prelude::*;
 
 /// Compile a literal vector.
+///
+/// Items are guaranteed to be evaluated left-to-right in source order.
 impl Assemble for ast::ExprVec {
     fn assemble(&self, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<Asm> {
         let span = self.span();
         log::trace!("ExprVec => {:?}", c.source.source(span));
 
-        let count = self.items.len();
+        let has_spread = self
+            .items
+            .iter()
+            .any(|(item, _)| matches!(item, ast::ExprVecItem::Spread(..)));
 
-        for (expr, _) in &self.items {
-            expr.assemble(c, Needs::Value)?.apply(c)?;
-            c.scopes.decl_anon(expr.span())?;
-        }
+        if has_spread {
+            compile_spread(c, self)?;
+        } else {
+            let count = self.items.len();
+
+            for (item, _) in &self.items {
+                if let ast::ExprVecItem::Expr(expr) = item {
+                    expr.assemble(c, Needs::Value)?.apply(c)?;
+                    c.scopes.decl_anon(expr.span())?;
+                }
+            }
 
-        c.asm.push(Inst::Vec { count }, span);
-        c.scopes.undecl_anon(span, self.items.len())?;
+            c.asm.push(Inst::Vec { count }, span);
+            c.scopes.undecl_anon(span, count)?;
+        }
 
         // Evaluate the expressions one by one, then pop them to cause any
         // side effects (without creating an object).
@@ -26,3 +39,61 @@ impl Assemble for ast::ExprVec {
         Ok(Asm::top(span))
     }
 }
+
+/// Compile a vector with spread items by building it up in order. Runs of
+/// single elements are collected into vectors which are appended to it.
+fn compile_spread(c: &mut Compiler<'_>, expr_vec: &ast::ExprVec) -> CompileResult<()> {
+    let span = expr_vec.span();
+
+    // Number of elements on the stack which haven't been added to the vector.
+    let mut count = 0;
+    // If the vector being built is on the stack.
+    let mut started = false;
+
+    for (item, _) in &expr_vec.items {
+        match item {
+            ast::ExprVecItem::Expr(expr) => {
+                expr.assemble(c, Needs::Value)?.apply(c)?;
+                c.scopes.decl_anon(expr.span())?;
+                count += 1;
+            }
+            ast::ExprVecItem::Spread(_, expr) => {
+                if !started || count > 0 {
+                    flush(c, &mut count, &mut started, span)?;
+                }
+
+                expr.assemble(c, Needs::Value)?.apply(c)?;
+                c.asm.push(Inst::VecExtend, expr.span());
+            }
+        }
+    }
+
+    if count > 0 {
+        flush(c, &mut count, &mut started, span)?;
+    }
+
+    c.scopes.undecl_anon(span, 1)?;
+    return Ok(());
+
+    /// Collect the `count` elements on top of the stack into a vector, which
+    /// is either appended to the vector being built or starts it.
+    fn flush(
+        c: &mut Compiler<'_>,
+        count: &mut usize,
+        started: &mut bool,
+        span: Span,
+    ) -> CompileResult<()> {
+        c.asm.push(Inst::Vec { count: *count }, span);
+        c.scopes.undecl_anon(span, *count)?;
+
+        if *started {
+            c.asm.push(Inst::VecExtend, span);
+        } else {
+            c.scopes.decl_anon(span)?;
+            *started = true;
+        }
+
+        *count = 0;
+        Ok(())
+    }
+}
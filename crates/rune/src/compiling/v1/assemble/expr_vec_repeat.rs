@@ -0,0 +1,25 @@
+use crate::compiling::v1::assemble::prelude::*;
+
+/// Compile a vector with a repeated value.
+impl Assemble for ast::ExprVecRepeat {
+    fn assemble(&self, c: &mut Compiler<'_>, needs: Needs) -> CompileResult<Asm> {
+        let span = self.span();
+        log::trace!("ExprVecRepeat => {:?}", c.source.source(span));
+
+        self.value.assemble(c, Needs::Value)?.apply(c)?;
+        c.scopes.decl_anon(self.value.span())?;
+
+        self.count.assemble(c, Needs::Value)?.apply(c)?;
+        c.scopes.decl_anon(self.count.span())?;
+
+        c.asm.push(Inst::VecRepeat, span);
+        c.scopes.undecl_anon(span, 2)?;
+
+        if !needs.value() {
+            c.diagnostics.not_used(c.source_id, span, c.context());
+            c.asm.push(Inst::Pop, span);
+        }
+
+        Ok(Asm::top(span))
+    }
+}
@@ -18,7 +18,7 @@ impl AssembleFn for ast::ItemFn {
                     }
 
                     let span = s.span();
-                    c.scopes.new_var("self", span)?;
+                    c.scopes.new_var("self", c.asm, span)?;
                 }
                 ast::FnArg::Pat(pat) => {
                     let offset = c.scopes.decl_anon(pat.span())?;
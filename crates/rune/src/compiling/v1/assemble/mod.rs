@@ -2,6 +2,7 @@ mod block;
 mod builtin_format;
 mod builtin_template;
 mod const_value;
+mod derive;
 mod expr;
 mod expr_assign;
 mod expr_await;
@@ -27,6 +28,7 @@ mod expr_try;
 mod expr_tuple;
 mod expr_unary;
 mod expr_vec;
+mod expr_vec_repeat;
 mod expr_while;
 mod expr_yield;
 mod item_fn;
@@ -10,8 +10,8 @@ use crate::{
     UnitBuilder,
 };
 use runestick::{
-    CompileItem, CompileMeta, CompileMetaKind, ConstValue, Context, Inst, InstValue, Item, Label,
-    Source, Span, TypeCheck,
+    CompileItem, CompileMeta, CompileMetaKind, ConstValue, Context, Inst, InstAddress, InstOp,
+    InstValue, Item, Label, Source, Span, TypeCheck,
 };
 use std::rc::Rc;
 use std::sync::Arc;
@@ -84,6 +84,16 @@ impl<'a> Compiler<'a> {
         if let Some(meta) = self.query.query_meta(spanned, &item, Default::default())? {
             log::trace!("found in query: {:?}", meta);
             self.visitor.visit_meta(self.source_id, &meta, spanned);
+
+            if let Some(message) = self.query.deprecation(&meta.item.item) {
+                self.diagnostics.deprecated(
+                    self.source_id,
+                    spanned,
+                    meta.item.item.clone(),
+                    message,
+                );
+            }
+
             return Ok(Some(meta));
         }
 
@@ -259,6 +269,15 @@ impl<'a> Compiler<'a> {
             ast::Condition::Expr(expr) => {
                 let span = expr.span();
 
+                if let Some(value) = self.constant_condition(expr)? {
+                    self.diagnostics.constant_condition(
+                        self.source_id,
+                        span,
+                        value,
+                        self.context(),
+                    );
+                }
+
                 expr.assemble(self, Needs::Value)?.apply(self)?;
                 self.asm.jump_if(then_label, span);
 
@@ -307,7 +326,7 @@ impl<'a> Compiler<'a> {
 
         // Copy the temporary and check that its length matches the pattern and
         // that it is indeed a vector.
-        self.asm.push(Inst::Copy { offset }, span);
+        self.asm.push(Inst::LoadLocal { slot: offset }, span);
 
         let (is_open, count) = pat_items_count(&pat_vec.items)?;
 
@@ -421,7 +440,7 @@ impl<'a> Compiler<'a> {
 
         let (is_open, count) = pat_items_count(&pat_tuple.items)?;
 
-        self.asm.push(Inst::Copy { offset }, span);
+        self.asm.push(Inst::LoadLocal { slot: offset }, span);
         self.asm.push(
             Inst::MatchSequence {
                 type_check,
@@ -499,8 +518,13 @@ impl<'a> Compiler<'a> {
                     };
 
                     let key = ident.resolve(&self.storage, &*self.source)?;
+                    let local = ident.resolve_local(&self.storage, &*self.source)?;
 
-                    bindings.push(Binding::Ident(path.span(), key.as_ref().into()));
+                    bindings.push(Binding::Ident(
+                        path.span(),
+                        key.as_ref().into(),
+                        local.as_ref().into(),
+                    ));
                     key
                 }
                 _ => {
@@ -579,7 +603,7 @@ impl<'a> Compiler<'a> {
 
         // Copy the temporary and check that its length matches the pattern and
         // that it is indeed a vector.
-        self.asm.push(Inst::Copy { offset }, span);
+        self.asm.push(Inst::LoadLocal { slot: offset }, span);
         self.asm.push(
             Inst::MatchObject {
                 type_check,
@@ -607,9 +631,9 @@ impl<'a> Compiler<'a> {
 
                     self.compile_pat(&*pat, false_label, &load)?;
                 }
-                Binding::Ident(_, key) => {
+                Binding::Ident(_, _, local) => {
                     self.asm.push(Inst::ObjectIndexGetAt { offset, slot }, span);
-                    self.scopes.decl_var(key, span)?;
+                    self.scopes.decl_var(local, self.asm, span)?;
                 }
             }
         }
@@ -618,21 +642,21 @@ impl<'a> Compiler<'a> {
 
         enum Binding<'a> {
             Binding(Span, Box<str>, &'a ast::Pat),
-            Ident(Span, Box<str>),
+            Ident(Span, Box<str>, Box<str>),
         }
 
         impl Binding<'_> {
             fn span(&self) -> Span {
                 match self {
                     Self::Binding(span, _, _) => *span,
-                    Self::Ident(span, _) => *span,
+                    Self::Ident(span, _, _) => *span,
                 }
             }
 
             fn key(&self) -> &str {
                 match self {
                     Self::Binding(_, key, _) => key.as_ref(),
-                    Self::Ident(_, key) => key.as_ref(),
+                    Self::Ident(_, key, _) => key.as_ref(),
                 }
             }
         }
@@ -689,7 +713,7 @@ impl<'a> Compiler<'a> {
 
         let load = |c: &mut Compiler, needs: Needs| {
             if needs.value() {
-                c.asm.push(Inst::Copy { offset }, span);
+                c.asm.push(Inst::LoadLocal { slot: offset }, span);
             }
 
             Ok(())
@@ -738,15 +762,23 @@ impl<'a> Compiler<'a> {
 
                 let named = self.convert_path_to_named(&path.path)?;
 
-                if let Some(meta) = self.try_lookup_meta(span, &named.item)? {
-                    if self.compile_pat_meta_binding(span, &meta, false_label, load)? {
+                let meta = self.try_lookup_meta(span, &named.item)?;
+
+                if let Some(meta) = &meta {
+                    if self.compile_pat_meta_binding(span, meta, false_label, load)? {
                         return Ok(true);
                     }
                 }
 
                 if let Some(ident) = named.as_local() {
+                    if let Some(meta) = &meta {
+                        if let CompileMetaKind::Const { .. } = &meta.kind {
+                            self.shadowed_const(span, meta.item.item.clone())?;
+                        }
+                    }
+
                     load(self, Needs::Value)?;
-                    self.scopes.decl_var(ident, span)?;
+                    self.scopes.decl_var(ident, self.asm, span)?;
                     return Ok(false);
                 }
 
@@ -774,6 +806,23 @@ impl<'a> Compiler<'a> {
                 self.compile_pat_object(object, false_label, &load)?;
                 Ok(true)
             }
+            ast::Pat::PatAt(pat_at) => {
+                let span = pat_at.span();
+
+                load(self, Needs::Value)?;
+                let ident = pat_at.ident.resolve_local(&self.storage, &*self.source)?;
+                let offset = self.scopes.decl_var(ident.as_ref(), self.asm, span)?;
+
+                let load = move |c: &mut Self, needs: Needs| {
+                    if needs.value() {
+                        c.asm.push(Inst::LoadLocal { slot: offset }, span);
+                    }
+
+                    Ok(())
+                };
+
+                self.compile_pat(&pat_at.pat, false_label, &load)
+            }
             pat => Err(CompileError::new(
                 pat,
                 CompileErrorKind::UnsupportedPatternExpr,
@@ -781,6 +830,20 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    /// Report that a binding shadows the constant `item`, which is an error
+    /// if it's been configured to be.
+    fn shadowed_const(&mut self, span: Span, item: Item) -> CompileResult<()> {
+        if self.options.shadowed_const_error {
+            return Err(CompileError::new(
+                span,
+                CompileErrorKind::ShadowedConstError { item },
+            ));
+        }
+
+        self.diagnostics.shadowed_const(self.source_id, span, item);
+        Ok(())
+    }
+
     pub(crate) fn compile_pat_lit(
         &mut self,
         pat_lit: &ast::PatLit,
@@ -789,23 +852,24 @@ impl<'a> Compiler<'a> {
     ) -> CompileResult<bool> {
         loop {
             match &pat_lit.expr {
-                ast::Expr::Unary(expr_unary) => {
+                ast::Expr::Unary(expr_unary) if expr_unary.op == ast::UnOp::Neg => {
                     if let ast::Expr::Lit(expr_lit) = &expr_unary.expr {
                         if let ast::ExprLit {
                             lit: ast::Lit::Number(lit_number),
                             ..
                         } = &**expr_lit
                         {
-                            let span = lit_number.span();
-                            let integer = lit_number
-                                .resolve(&self.storage, &*self.source)?
-                                .as_i64(pat_lit.span(), true)?;
+                            let inst = self.pat_lit_number_inst(pat_lit, lit_number, true)?;
                             load(self, Needs::Value)?;
-                            self.asm.push(Inst::EqInteger { integer }, span);
+                            self.asm.push(inst, lit_number.span());
                             break;
                         }
                     }
                 }
+                ast::Expr::Range(expr_range) => {
+                    self.compile_pat_range(pat_lit, expr_range, false_label, load)?;
+                    return Ok(true);
+                }
                 ast::Expr::Lit(expr_lit) => match &expr_lit.lit {
                     ast::Lit::Byte(lit_byte) => {
                         let byte = lit_byte.resolve(&self.storage, &*self.source)?;
@@ -829,12 +893,9 @@ impl<'a> Compiler<'a> {
                         break;
                     }
                     ast::Lit::Number(lit_number) => {
-                        let span = lit_number.span();
-                        let integer = lit_number
-                            .resolve(&self.storage, &*self.source)?
-                            .as_i64(pat_lit.span(), false)?;
+                        let inst = self.pat_lit_number_inst(pat_lit, lit_number, false)?;
                         load(self, Needs::Value)?;
-                        self.asm.push(Inst::EqInteger { integer }, span);
+                        self.asm.push(inst, lit_number.span());
                         break;
                     }
                     ast::Lit::Bool(lit_bool) => {
@@ -844,7 +905,14 @@ impl<'a> Compiler<'a> {
                         self.asm.push(Inst::EqBool { boolean }, span);
                         break;
                     }
-                    ast::Lit::ByteStr(_) => {}
+                    ast::Lit::ByteStr(lit_byte_str) => {
+                        let span = lit_byte_str.span();
+                        let bytes = lit_byte_str.resolve(&self.storage, &*self.source)?;
+                        let slot = self.unit.new_static_bytes(span, &*bytes)?;
+                        load(self, Needs::Value)?;
+                        self.asm.push(Inst::EqStaticBytes { slot }, span);
+                        break;
+                    }
                 },
                 _ => (),
             }
@@ -861,6 +929,142 @@ impl<'a> Compiler<'a> {
         Ok(true)
     }
 
+    /// Compile a range pattern like `1..=5`, which matches values of the same
+    /// type as its bounds which fall within them.
+    fn compile_pat_range(
+        &mut self,
+        pat_lit: &ast::PatLit,
+        expr_range: &ast::ExprRange,
+        false_label: Label,
+        load: &dyn Fn(&mut Self, Needs) -> CompileResult<()>,
+    ) -> CompileResult<()> {
+        let span = expr_range.span();
+
+        let from = match &expr_range.from {
+            Some(from) => self.pat_range_bound(pat_lit, from)?,
+            None => {
+                return Err(CompileError::new(
+                    pat_lit,
+                    CompileErrorKind::UnsupportedPatternExpr,
+                ))
+            }
+        };
+
+        let to = match &expr_range.to {
+            Some(to) => Some(self.pat_range_bound(pat_lit, to)?),
+            None => None,
+        };
+
+        let type_hash = match (from, to) {
+            (InstValue::Integer(..), None) => runestick::INTEGER_TYPE.hash,
+            (InstValue::Integer(..), Some(InstValue::Integer(..))) => runestick::INTEGER_TYPE.hash,
+            (InstValue::Float(..), None) => runestick::FLOAT_TYPE.hash,
+            (InstValue::Float(..), Some(InstValue::Float(..))) => runestick::FLOAT_TYPE.hash,
+            _ => {
+                return Err(CompileError::new(
+                    pat_lit,
+                    CompileErrorKind::UnsupportedPatternExpr,
+                ))
+            }
+        };
+
+        load(self, Needs::Value)?;
+        let offset = self.scopes.decl_anon(span)?;
+
+        self.pat_range_test(
+            offset,
+            InstOp::Is,
+            InstValue::Type(type_hash),
+            false_label,
+            span,
+        )?;
+        self.pat_range_test(offset, InstOp::Gte, from, false_label, span)?;
+
+        if let Some(to) = to {
+            let op = match &expr_range.limits {
+                ast::ExprRangeLimits::HalfOpen(..) => InstOp::Lt,
+                ast::ExprRangeLimits::Closed(..) => InstOp::Lte,
+            };
+
+            self.pat_range_test(offset, op, to, false_label, span)?;
+        }
+
+        Ok(())
+    }
+
+    /// Test the value at `offset` against `value` using `op`, jumping to
+    /// `false_label` if the test fails.
+    fn pat_range_test(
+        &mut self,
+        offset: usize,
+        op: InstOp,
+        value: InstValue,
+        false_label: Label,
+        span: Span,
+    ) -> CompileResult<()> {
+        self.asm.push(Inst::Push { value }, span);
+        self.asm.push(
+            Inst::Op {
+                op,
+                a: InstAddress::Offset(offset),
+                b: InstAddress::Top,
+            },
+            span,
+        );
+        self.asm
+            .pop_and_jump_if_not(self.scopes.local_var_count(span)?, false_label, span);
+        Ok(())
+    }
+
+    /// Resolve the bound of a range pattern, which must be a possibly negated
+    /// number.
+    fn pat_range_bound(&self, pat_lit: &ast::PatLit, expr: &ast::Expr) -> CompileResult<InstValue> {
+        let (expr, neg) = match expr {
+            ast::Expr::Unary(expr_unary) if expr_unary.op == ast::UnOp::Neg => {
+                (&expr_unary.expr, true)
+            }
+            expr => (expr, false),
+        };
+
+        if let ast::Expr::Lit(expr_lit) = expr {
+            if let ast::Lit::Number(lit_number) = &expr_lit.lit {
+                let number = lit_number.resolve(&self.storage, &*self.source)?;
+
+                return Ok(match number {
+                    ast::Number::Float(float) => InstValue::Float(if neg { -float } else { float }),
+                    ast::Number::Integer(..) => {
+                        InstValue::Integer(number.as_i64(pat_lit.span(), neg)?)
+                    }
+                });
+            }
+        }
+
+        Err(CompileError::new(
+            pat_lit,
+            CompileErrorKind::UnsupportedPatternExpr,
+        ))
+    }
+
+    /// Construct the instruction used to test against a number in a pattern,
+    /// which is negated if `neg` is set.
+    fn pat_lit_number_inst(
+        &self,
+        pat_lit: &ast::PatLit,
+        lit_number: &ast::LitNumber,
+        neg: bool,
+    ) -> CompileResult<Inst> {
+        let number = lit_number.resolve(&self.storage, &*self.source)?;
+
+        Ok(match number {
+            ast::Number::Float(float) => Inst::EqFloat {
+                float: if neg { -float } else { float },
+            },
+            ast::Number::Integer(..) => Inst::EqInteger {
+                integer: number.as_i64(pat_lit.span(), neg)?,
+            },
+        })
+    }
+
     /// Clean the last scope.
     pub(crate) fn clean_last_scope(
         &mut self,
@@ -884,6 +1088,94 @@ impl<'a> Compiler<'a> {
         self.contexts.last().copied()
     }
 
+    /// Test if the given condition is a comparison whose result is statically
+    /// known, like `1 > 2` or `x < x`.
+    fn constant_condition(&self, expr: &ast::Expr) -> CompileResult<Option<bool>> {
+        use std::cmp::Ordering;
+
+        let expr_binary = match expr {
+            ast::Expr::Binary(expr_binary) => expr_binary,
+            _ => return Ok(None),
+        };
+
+        let op = expr_binary.op;
+
+        let ordering = match (&expr_binary.lhs, &expr_binary.rhs) {
+            (ast::Expr::Lit(a), ast::Expr::Lit(b)) => self.compare_lits(&a.lit, &b.lit)?,
+            (ast::Expr::Path(a), ast::Expr::Path(b))
+                if matches!(op, ast::BinOp::Lt | ast::BinOp::Gt) =>
+            {
+                let (a, b) = match (a.try_as_ident(), b.try_as_ident()) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => return Ok(None),
+                };
+
+                let a = a.resolve(self.storage, &self.source)?;
+                let b = b.resolve(self.storage, &self.source)?;
+
+                if a != b {
+                    return Ok(None);
+                }
+
+                Some(Ordering::Equal)
+            }
+            _ => None,
+        };
+
+        let ordering = match ordering {
+            Some(ordering) => ordering,
+            None => return Ok(None),
+        };
+
+        Ok(Some(match op {
+            ast::BinOp::Eq => ordering == Ordering::Equal,
+            ast::BinOp::Neq => ordering != Ordering::Equal,
+            ast::BinOp::Lt => ordering == Ordering::Less,
+            ast::BinOp::Gt => ordering == Ordering::Greater,
+            ast::BinOp::Lte => ordering != Ordering::Greater,
+            ast::BinOp::Gte => ordering != Ordering::Less,
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Compare two literals, returning `None` if they aren't comparable.
+    fn compare_lits(
+        &self,
+        a: &ast::Lit,
+        b: &ast::Lit,
+    ) -> CompileResult<Option<std::cmp::Ordering>> {
+        let storage = self.storage;
+        let source = &self.source;
+
+        Ok(match (a, b) {
+            (ast::Lit::Bool(a), ast::Lit::Bool(b)) => Some(a.value.cmp(&b.value)),
+            (ast::Lit::Byte(a), ast::Lit::Byte(b)) => Some(
+                a.resolve(storage, source)?
+                    .cmp(&b.resolve(storage, source)?),
+            ),
+            (ast::Lit::Char(a), ast::Lit::Char(b)) => Some(
+                a.resolve(storage, source)?
+                    .cmp(&b.resolve(storage, source)?),
+            ),
+            (ast::Lit::Str(a), ast::Lit::Str(b)) => Some(
+                a.resolve(storage, source)?
+                    .cmp(&b.resolve(storage, source)?),
+            ),
+            (ast::Lit::ByteStr(a), ast::Lit::ByteStr(b)) => Some(
+                a.resolve(storage, source)?
+                    .cmp(&b.resolve(storage, source)?),
+            ),
+            (ast::Lit::Number(a), ast::Lit::Number(b)) => {
+                match (a.resolve(storage, source)?, b.resolve(storage, source)?) {
+                    (ast::Number::Integer(a), ast::Number::Integer(b)) => Some(a.cmp(&b)),
+                    (ast::Number::Float(a), ast::Number::Float(b)) => a.partial_cmp(&b),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+
     /// Calling a constant function by id and return the resuling value.
     pub(crate) fn call_const_fn<S>(
         &mut self,
@@ -22,13 +22,7 @@ impl Var {
     where
         C: AsRef<str>,
     {
-        asm.push_with_comment(
-            Inst::Copy {
-                offset: self.offset,
-            },
-            span,
-            comment,
-        );
+        asm.push_with_comment(Inst::LoadLocal { slot: self.offset }, span, comment);
     }
 
     /// Move the declared variable.
@@ -300,13 +294,31 @@ impl Scopes {
     }
 
     /// Construct a new variable.
-    pub(crate) fn new_var(&mut self, name: &str, span: Span) -> CompileResult<usize> {
-        self.last_mut(span)?.new_var(name, span)
+    ///
+    /// The variable is recorded in the debug info of the assembly.
+    pub(crate) fn new_var(
+        &mut self,
+        name: &str,
+        asm: &mut Assembly,
+        span: Span,
+    ) -> CompileResult<usize> {
+        let offset = self.last_mut(span)?.new_var(name, span)?;
+        asm.declare(offset, name);
+        Ok(offset)
     }
 
     /// Declare the given variable.
-    pub(crate) fn decl_var(&mut self, name: &str, span: Span) -> CompileResult<usize> {
-        Ok(self.last_mut(span)?.decl_var(name, span))
+    ///
+    /// The variable is recorded in the debug info of the assembly.
+    pub(crate) fn decl_var(
+        &mut self,
+        name: &str,
+        asm: &mut Assembly,
+        span: Span,
+    ) -> CompileResult<usize> {
+        let offset = self.last_mut(span)?.decl_var(name, span);
+        asm.declare(offset, name);
+        Ok(offset)
     }
 
     /// Declare an anonymous variable.
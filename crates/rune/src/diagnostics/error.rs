@@ -1,5 +1,5 @@
 use crate::compiling::LinkerError;
-use crate::{BuildError, CompileError, ParseError, QueryError};
+use crate::{BuildError, CompileError, ParseError, QueryError, Warning};
 use runestick::SourceId;
 use std::error;
 use std::fmt;
@@ -78,6 +78,13 @@ pub enum ErrorKind {
         #[source]
         BuildError,
     ),
+    /// A warning which has been promoted into an error.
+    #[error("warning treated as an error")]
+    Warning(
+        #[from]
+        #[source]
+        Warning,
+    ),
     /// An internal error.
     #[error("internal error: {0}")]
     Internal(&'static str),
@@ -1,4 +1,4 @@
-use runestick::{SourceId, Span};
+use runestick::{Item, SourceId, Span};
 
 mod error;
 mod warning;
@@ -211,6 +211,60 @@ impl Diagnostics {
         self.warning(source_id, WarningKind::UnecessarySemiColon { span });
     }
 
+    /// Add a warning about the use of a deprecated item.
+    pub fn deprecated(&mut self, source_id: usize, span: Span, item: Item, message: Box<str>) {
+        self.warning(
+            source_id,
+            WarningKind::Deprecated {
+                item,
+                message,
+                span,
+            },
+        );
+    }
+
+    /// Add a warning about a variable binding shadowing a constant.
+    pub fn shadowed_const(&mut self, source_id: usize, span: Span, item: Item) {
+        self.warning(source_id, WarningKind::ShadowedConst { item, span });
+    }
+
+    /// Add a warning about a condition whose result is statically known.
+    ///
+    /// Like `if 1 > 2 { }`.
+    pub fn constant_condition(
+        &mut self,
+        source_id: usize,
+        span: Span,
+        value: bool,
+        context: Option<Span>,
+    ) {
+        self.warning(
+            source_id,
+            WarningKind::ConstantCondition {
+                span,
+                value,
+                context,
+            },
+        );
+    }
+
+    /// Promote all warnings collected so far into errors.
+    ///
+    /// Note that no warnings are collected if the diagnostics were constructed
+    /// with [Diagnostics::without_warnings].
+    pub fn warnings_as_errors(&mut self) {
+        let diagnostics = std::mem::take(&mut self.diagnostics);
+        self.last_error = None;
+        self.last_warning = None;
+
+        for diagnostic in diagnostics {
+            match diagnostic {
+                Diagnostic::Error(error) => self.error(error.source_id, *error.kind),
+                Diagnostic::Warning(warning) => self.error(warning.source_id, warning),
+            }
+        }
+    }
+
     /// Push a warning to the collection of diagnostics.
     pub fn warning<T>(&mut self, source_id: SourceId, kind: T)
     where
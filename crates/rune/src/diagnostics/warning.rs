@@ -1,10 +1,10 @@
-use runestick::{SourceId, Span};
+use runestick::{Item, SourceId, Span};
 use std::error;
 use std::fmt;
 use thiserror::Error;
 
 /// Compilation warning.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Warning {
     /// The last warning reported in the chain.
     pub(super) last: Option<usize>,
@@ -38,6 +38,9 @@ impl Warning {
             WarningKind::TemplateWithoutExpansions { span, .. } => *span,
             WarningKind::RemoveTupleCallParams { span, .. } => *span,
             WarningKind::UnecessarySemiColon { span, .. } => *span,
+            WarningKind::Deprecated { span, .. } => *span,
+            WarningKind::ShadowedConst { span, .. } => *span,
+            WarningKind::ConstantCondition { span, .. } => *span,
         }
     }
 }
@@ -55,7 +58,7 @@ impl error::Error for Warning {
 }
 
 /// Compilation warning kind.
-#[derive(Debug, Clone, Copy, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum WarningKind {
     /// Item identified by the span is not used.
     #[error("not used")]
@@ -98,4 +101,32 @@ pub enum WarningKind {
         /// Span where the semi-colon is.
         span: Span,
     },
+    /// A deprecated item is used.
+    #[error("use of deprecated item `{item}`: {message}")]
+    Deprecated {
+        /// The deprecated item.
+        item: Item,
+        /// The message of the deprecation.
+        message: Box<str>,
+        /// Span where the item is used.
+        span: Span,
+    },
+    /// A variable binding shadows a constant with the same name.
+    #[error("binding shadows constant `{item}`")]
+    ShadowedConst {
+        /// The constant being shadowed.
+        item: Item,
+        /// Span of the binding.
+        span: Span,
+    },
+    /// A condition is a comparison whose result is statically known.
+    #[error("condition is always `{value}`")]
+    ConstantCondition {
+        /// The span of the condition.
+        span: Span,
+        /// The value the condition always has.
+        value: bool,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
 }
@@ -4,7 +4,9 @@ use crate::{
     CompileErrorKind, Diagnostics, Error, ErrorKind, IrErrorKind, LinkerError, QueryErrorKind,
     ResolveErrorKind, Sources, Spanned as _, Warning, WarningKind,
 };
-use runestick::{Location, Source, SourceId, Span, Unit, VmError, VmErrorKind};
+use runestick::{
+    Bytes, Inst, InstTarget, Location, Source, SourceId, Span, Unit, VmError, VmErrorKind,
+};
 use std::error::Error as _;
 use std::fmt;
 use std::fmt::Write as _;
@@ -299,6 +301,32 @@ where
 
             None
         }
+        WarningKind::Deprecated { span, message, .. } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range()).with_message(message.as_ref()),
+            );
+            None
+        }
+        WarningKind::ShadowedConst { span, item } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message(format!("binding shadows constant `{}`", item)),
+            );
+
+            None
+        }
+        WarningKind::ConstantCondition {
+            span,
+            value,
+            context,
+        } => {
+            labels.push(
+                Label::primary(this.source_id(), span.range())
+                    .with_message(format!("condition is always `{}`", value)),
+            );
+
+            *context
+        }
     };
 
     if let Some(context) = context {
@@ -364,6 +392,7 @@ where
             return Ok(());
         }
         ErrorKind::ParseError(error) => error.span(),
+        ErrorKind::Warning(warning) => warning.span(),
         ErrorKind::CompileError(error) => {
             format_compile_error(
                 this,
@@ -639,6 +668,22 @@ pub trait DumpInstructions {
     ) -> io::Result<()>
     where
         O: WriteColor;
+
+    /// Emit the instruction at the given instruction pointer to the given
+    /// writer.
+    ///
+    /// Operands which refer to data stored in the unit, like static strings,
+    /// object keys and functions, are resolved into a comment next to the
+    /// instruction.
+    fn emit_instruction<O>(
+        &self,
+        out: &mut O,
+        sources: &Sources,
+        ip: usize,
+        with_source: bool,
+    ) -> io::Result<()>
+    where
+        O: WriteColor;
 }
 
 impl DumpInstructions for Unit {
@@ -653,9 +698,7 @@ impl DumpInstructions for Unit {
     {
         let mut first_function = true;
 
-        for (n, inst) in self.iter_instructions().enumerate() {
-            let debug = self.debug_info().and_then(|d| d.instruction_at(n));
-
+        for (n, _) in self.iter_instructions().enumerate() {
             if let Some((hash, signature)) = self.debug_info().and_then(|d| d.function_at(n)) {
                 if !std::mem::take(&mut first_function) {
                     writeln!(out)?;
@@ -664,31 +707,137 @@ impl DumpInstructions for Unit {
                 writeln!(out, "fn {} ({}):", signature, hash)?;
             }
 
-            if with_source {
-                if let Some((source, span)) =
-                    debug.and_then(|d| sources.get(d.source_id).map(|s| (s, d.span)))
-                {
-                    source.emit_source_line(out, span)?;
-                }
-            }
+            self.emit_instruction(out, sources, n, with_source)?;
+        }
+
+        Ok(())
+    }
+
+    fn emit_instruction<O>(
+        &self,
+        out: &mut O,
+        sources: &Sources,
+        ip: usize,
+        with_source: bool,
+    ) -> io::Result<()>
+    where
+        O: WriteColor,
+    {
+        let debug = self.debug_info().and_then(|d| d.instruction_at(ip));
 
-            if let Some(label) = debug.and_then(|d| d.label.as_ref()) {
-                writeln!(out, "{}:", label)?;
+        if with_source {
+            if let Some((source, span)) =
+                debug.and_then(|d| sources.get(d.source_id).map(|s| (s, d.span)))
+            {
+                source.emit_source_line(out, span)?;
             }
+        }
 
-            write!(out, "  {:04} = {}", n, inst)?;
+        if let Some(label) = debug.and_then(|d| d.label.as_ref()) {
+            writeln!(out, "{}:", label)?;
+        }
 
-            if let Some(comment) = debug.and_then(|d| d.comment.as_ref()) {
-                write!(out, " // {}", comment)?;
+        let inst = match self.instruction_at(ip) {
+            Some(inst) => inst,
+            None => {
+                writeln!(out, "  {:04} = *out of bounds*", ip)?;
+                return Ok(());
             }
+        };
 
-            writeln!(out)?;
+        write!(out, "  {:04} = {}", ip, inst)?;
+
+        let comment = debug.and_then(|d| d.comment.as_ref());
+
+        // NB: the compiler might already have described the operand in the
+        // comment, in which case we don't repeat it.
+        let comments = comment
+            .cloned()
+            .into_iter()
+            .chain(
+                resolve_operands(self, inst)
+                    .into_iter()
+                    .filter(|resolved| comment != Some(resolved)),
+            )
+            .collect::<Vec<_>>();
+
+        if !comments.is_empty() {
+            write!(out, " // {}", comments.join("; "))?;
         }
 
+        writeln!(out)?;
         Ok(())
     }
 }
 
+/// Resolve the operands of an instruction which refer to data stored in the
+/// unit into human-readable descriptions.
+fn resolve_operands(unit: &Unit, inst: &Inst) -> Vec<String> {
+    let mut out = Vec::new();
+
+    match *inst {
+        Inst::String { slot }
+        | Inst::EqStaticString { slot }
+        | Inst::ObjectIndexGet { slot }
+        | Inst::ObjectIndexSet { slot }
+        | Inst::ObjectIndexGetAt { slot, .. }
+        | Inst::Assign {
+            target: InstTarget::Field(slot),
+            ..
+        } => {
+            if let Ok(string) = unit.lookup_string(slot) {
+                out.push(format!("{:?}", string.as_str()));
+            }
+        }
+        Inst::Bytes { slot } | Inst::EqStaticBytes { slot } => {
+            if let Ok(bytes) = unit.lookup_bytes(slot) {
+                out.push(format!("{:?}", Bytes::from_vec(bytes.to_vec())));
+            }
+        }
+        Inst::Object { slot } | Inst::MatchObject { slot, .. } => {
+            if let Some(keys) = unit.lookup_object_keys(slot) {
+                out.push(format!("{{{}}}", keys.join(", ")));
+            }
+        }
+        Inst::Call { hash, .. } | Inst::LoadFn { hash } | Inst::Closure { hash, .. } => {
+            if let Some(signature) = unit.lookup_signature(hash) {
+                out.push(format!("fn {}", signature.path));
+            }
+        }
+        Inst::UnitStruct { hash } => {
+            if let Some(rtti) = unit.lookup_rtti(hash) {
+                out.push(rtti.item.to_string());
+            }
+        }
+        Inst::Struct { hash, slot } => {
+            if let Some(rtti) = unit.lookup_rtti(hash) {
+                out.push(rtti.item.to_string());
+            }
+
+            if let Some(keys) = unit.lookup_object_keys(slot) {
+                out.push(format!("{{{}}}", keys.join(", ")));
+            }
+        }
+        Inst::UnitVariant { hash } => {
+            if let Some(rtti) = unit.lookup_variant_rtti(hash) {
+                out.push(rtti.item.to_string());
+            }
+        }
+        Inst::StructVariant { hash, slot } => {
+            if let Some(rtti) = unit.lookup_variant_rtti(hash) {
+                out.push(rtti.item.to_string());
+            }
+
+            if let Some(keys) = unit.lookup_object_keys(slot) {
+                out.push(format!("{{{}}}", keys.join(", ")));
+            }
+        }
+        _ => (),
+    }
+
+    out
+}
+
 /// Helper trait to emit source code locations.
 ///
 /// These are implemented for [Source], so that you can print diagnostics about
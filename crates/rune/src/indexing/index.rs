@@ -7,7 +7,8 @@ use crate::macros::MacroCompiler;
 use crate::parsing::{Parse, Parser};
 use crate::query::{
     Build, BuildEntry, BuiltInFile, BuiltInFormat, BuiltInLine, BuiltInMacro, BuiltInTemplate,
-    Function, Indexed, IndexedEntry, InstanceFunction, Query, Used,
+    Derive, DeriveFields, DeriveVariant, Function, Indexed, IndexedEntry, InstanceFunction, Query,
+    Used,
 };
 use crate::shared::{Consts, Items};
 use crate::worker::{Import, ImportKind, LoadFileKind, Task};
@@ -17,8 +18,8 @@ use crate::{
 };
 use runestick::format;
 use runestick::{
-    Call, CompileMeta, CompileMetaKind, CompileMod, CompileSource, Context, Hash, Item, Location,
-    Source, SourceId, Span, Visibility,
+    Call, CompileItem, CompileMeta, CompileMetaKind, CompileMod, CompileSource, Context, Hash,
+    Item, Location, Source, SourceId, Span, TypeCheck, Visibility,
 };
 use std::collections::VecDeque;
 use std::num::NonZeroUsize;
@@ -372,6 +373,47 @@ impl<'a> Indexer<'a> {
         Ok(expanded)
     }
 
+    /// Queue up the protocol functions derived for a script type.
+    ///
+    /// NB: like instance functions, these are always built since we don't
+    /// know statically if they are used.
+    fn derive(
+        &mut self,
+        item: &Arc<CompileItem>,
+        kinds: Vec<(Span, attrs::DeriveKind)>,
+        variants: Vec<DeriveVariant>,
+    ) {
+        for (span, kind) in kinds {
+            self.query.push_build_entry(BuildEntry {
+                location: Location::new(self.source_id, span),
+                item: item.clone(),
+                build: Build::Derive(Derive {
+                    span,
+                    kind,
+                    type_hash: Hash::type_hash(&item.item),
+                    variants: variants.clone(),
+                }),
+                source: self.source.clone(),
+                used: Used::Used,
+            });
+        }
+    }
+
+    /// Resolve the names of the given fields, for deriving protocol functions
+    /// of a struct or variant.
+    fn derive_fields<'f, I>(&self, fields: I) -> CompileResult<DeriveFields>
+    where
+        I: IntoIterator<Item = &'f (ast::Field, Option<T![,]>)>,
+    {
+        let mut names = Vec::new();
+
+        for (field, _) in fields {
+            names.push(field.name.resolve(&self.storage, &*self.source)?.into());
+        }
+
+        Ok(DeriveFields::Struct(names))
+    }
+
     /// pre-process uses and expand item macros.
     ///
     /// Uses are processed first in a file, and once processed any potential
@@ -526,7 +568,9 @@ impl<'a> Indexer<'a> {
             None => {
                 return Err(CompileError::new(
                     span,
-                    CompileErrorKind::UnsupportedModuleSource,
+                    CompileErrorKind::UnsupportedModuleSource {
+                        name: self.source.name().to_owned(),
+                    },
                 ));
             }
         };
@@ -711,6 +755,12 @@ impl Index for ast::ItemFn {
             _ => false,
         };
 
+        if let Some((_, deprecated)) = attributes.try_parse::<attrs::Deprecated>()? {
+            let message = deprecated.message(&idx.storage, &idx.source)?;
+            idx.query
+                .insert_deprecation(&item.item, message.as_ref().into());
+        }
+
         if let Some(attrs) = attributes.remaining() {
             return Err(CompileError::msg(attrs, "unrecognized function attribute"));
         }
@@ -971,7 +1021,7 @@ impl Index for ast::Ident {
         let span = self.span();
         log::trace!("Ident => {:?}", idx.source.source(span));
 
-        let ident = self.resolve(&idx.storage, &*idx.source)?;
+        let ident = self.resolve_local(&idx.storage, &*idx.source)?;
         idx.scopes.declare(ident.as_ref(), span)?;
         Ok(())
     }
@@ -1002,6 +1052,9 @@ impl Index for ast::Pat {
             ast::Pat::PatBinding(pat_binding) => {
                 pat_binding.index(idx)?;
             }
+            ast::Pat::PatAt(pat_at) => {
+                pat_at.index(idx)?;
+            }
             ast::Pat::PatIgnore(..) => (),
             ast::Pat::PatLit(..) => (),
             ast::Pat::PatRest(..) => (),
@@ -1037,6 +1090,16 @@ impl Index for ast::PatBinding {
     }
 }
 
+impl Index for ast::PatAt {
+    fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("PatAt => {:?}", idx.source.source(span));
+        self.ident.index(idx)?;
+        self.pat.index(idx)?;
+        Ok(())
+    }
+}
+
 impl Index for ast::PatObject {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
@@ -1167,6 +1230,9 @@ impl Index for ast::Expr {
             ast::Expr::Vec(expr_vec) => {
                 expr_vec.index(idx)?;
             }
+            ast::Expr::VecRepeat(expr_vec_repeat) => {
+                expr_vec_repeat.index(idx)?;
+            }
             ast::Expr::Object(expr_object) => {
                 expr_object.index(idx)?;
             }
@@ -1290,7 +1356,20 @@ impl Index for ast::ItemEnum {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
 
-        if let Some(first) = self.attributes.first() {
+        let mut attributes = attrs::Attributes::new(
+            self.attributes.clone(),
+            idx.storage.clone(),
+            idx.source.clone(),
+        );
+
+        let deprecated = attributes.try_parse::<attrs::Deprecated>()?;
+
+        let derives = match attributes.try_parse::<attrs::Derive>()? {
+            Some((_, derive)) => derive.kinds(&idx.storage, &idx.source)?,
+            None => Vec::new(),
+        };
+
+        if let Some(first) = attributes.remaining() {
             return Err(CompileError::msg(
                 first,
                 "enum attributes are not supported",
@@ -1309,8 +1388,16 @@ impl Index for ast::ItemEnum {
             visibility,
         )?;
 
+        if let Some((_, deprecated)) = deprecated {
+            let message = deprecated.message(&idx.storage, &idx.source)?;
+            idx.query
+                .insert_deprecation(&enum_item.item, message.as_ref().into());
+        }
+
         idx.query.index_enum(&enum_item, &idx.source)?;
 
+        let mut derived = Vec::new();
+
         for (variant, _) in &mut self.variants {
             if let Some(first) = variant.attributes.first() {
                 return Err(CompileError::msg(
@@ -1341,10 +1428,25 @@ impl Index for ast::ItemEnum {
             )?;
             variant.id = Some(item.id);
 
+            if !derives.is_empty() {
+                let fields = match &variant.body {
+                    ast::ItemVariantBody::UnitBody => DeriveFields::Unit,
+                    ast::ItemVariantBody::TupleBody(body) => DeriveFields::Tuple(body.len()),
+                    ast::ItemVariantBody::StructBody(body) => idx.derive_fields(body)?,
+                };
+
+                derived.push(DeriveVariant {
+                    name: name.as_ref().into(),
+                    type_check: TypeCheck::Variant(Hash::type_hash(&item.item)),
+                    fields,
+                });
+            }
+
             idx.query
                 .index_variant(&item, &idx.source, enum_item.id, variant.clone())?;
         }
 
+        idx.derive(&enum_item, derives, derived);
         Ok(())
     }
 }
@@ -1353,7 +1455,20 @@ impl Index for Box<ast::ItemStruct> {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
 
-        if let Some(first) = self.attributes.first() {
+        let mut attributes = attrs::Attributes::new(
+            self.attributes.clone(),
+            idx.storage.clone(),
+            idx.source.clone(),
+        );
+
+        let deprecated = attributes.try_parse::<attrs::Deprecated>()?;
+
+        let derives = match attributes.try_parse::<attrs::Derive>()? {
+            Some((_, derive)) => derive.kinds(&idx.storage, &idx.source)?,
+            None => Vec::new(),
+        };
+
+        if let Some(first) = attributes.remaining() {
             return Err(CompileError::msg(
                 first,
                 "struct attributes are not supported",
@@ -1387,6 +1502,28 @@ impl Index for Box<ast::ItemStruct> {
         )?;
         self.id = Some(item.id);
 
+        if let Some((_, deprecated)) = deprecated {
+            let message = deprecated.message(&idx.storage, &idx.source)?;
+            idx.query
+                .insert_deprecation(&item.item, message.as_ref().into());
+        }
+
+        if !derives.is_empty() {
+            let fields = match &self.body {
+                ast::ItemStructBody::UnitBody => DeriveFields::Unit,
+                ast::ItemStructBody::TupleBody(body) => DeriveFields::Tuple(body.len()),
+                ast::ItemStructBody::StructBody(body) => idx.derive_fields(body)?,
+            };
+
+            let variant = DeriveVariant {
+                name: ident.as_ref().into(),
+                type_check: TypeCheck::Type(Hash::type_hash(&item.item)),
+                fields,
+            };
+
+            idx.derive(&item, derives, vec![variant]);
+        }
+
         idx.query.index_struct(&item, &idx.source, self.clone())?;
         Ok(())
     }
@@ -1516,9 +1653,11 @@ impl Index for ast::Item {
         match self {
             ast::Item::Enum(item_enum) => {
                 item_enum.index(idx)?;
+                attributes.drain();
             }
             ast::Item::Struct(item_struct) => {
                 item_struct.index(idx)?;
+                attributes.drain();
             }
             ast::Item::Fn(item_fn) => {
                 item_fn.index(idx)?;
@@ -1573,7 +1712,7 @@ impl Index for ast::Path {
                 idx.scopes.mark_use("self");
             }
             Some(ast::PathKind::Ident(ident)) => {
-                let ident = ident.resolve(&idx.storage, &*idx.source)?;
+                let ident = ident.resolve_local(&idx.storage, &*idx.source)?;
                 idx.scopes.mark_use(ident.as_ref());
             }
             None => (),
@@ -1877,14 +2016,27 @@ impl Index for ast::ExprVec {
         let span = self.span();
         log::trace!("ExprVec => {:?}", idx.source.source(span));
 
-        for (expr, _) in &mut self.items {
-            expr.index(idx)?;
+        for (item, _) in &mut self.items {
+            match item {
+                ast::ExprVecItem::Expr(expr) => expr.index(idx)?,
+                ast::ExprVecItem::Spread(_, expr) => expr.index(idx)?,
+            }
         }
 
         Ok(())
     }
 }
 
+impl Index for ast::ExprVecRepeat {
+    fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("ExprVecRepeat => {:?}", idx.source.source(span));
+        self.value.index(idx)?;
+        self.count.index(idx)?;
+        Ok(())
+    }
+}
+
 impl Index for ast::ExprObject {
     fn index(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
         let span = self.span();
@@ -2,7 +2,6 @@
 use crate::ast;
 use crate::compiling::CompileResult;
 use crate::indexing::Indexer;
-use crate::parsing::Resolve as _;
 use crate::Spanned as _;
 
 pub(crate) trait IndexLocal {
@@ -31,6 +30,9 @@ impl IndexLocal for ast::Pat {
             ast::Pat::PatBinding(pat_binding) => {
                 pat_binding.index_local(idx)?;
             }
+            ast::Pat::PatAt(pat_at) => {
+                pat_at.index_local(idx)?;
+            }
             ast::Pat::PatIgnore(..) => (),
             ast::Pat::PatLit(..) => (),
             ast::Pat::PatRest(..) => (),
@@ -73,7 +75,7 @@ impl IndexLocal for ast::Ident {
         log::trace!("Ident => {:?}", idx.source.source(span));
 
         let span = self.span();
-        let ident = self.resolve(&idx.storage, &*idx.source)?;
+        let ident = self.resolve_local(&idx.storage, &*idx.source)?;
         idx.scopes.declare(ident.as_ref(), span)?;
         Ok(())
     }
@@ -137,3 +139,12 @@ impl IndexLocal for ast::PatBinding {
         Ok(())
     }
 }
+
+impl IndexLocal for ast::PatAt {
+    fn index_local(&mut self, idx: &mut Indexer<'_>) -> CompileResult<()> {
+        let span = self.span();
+        log::trace!("PatAt => {:?}", idx.source.source(span));
+        self.pat.index_local(idx)?;
+        Ok(())
+    }
+}
@@ -363,8 +363,13 @@ impl IrCompile for ast::ExprVec {
     fn compile(&self, c: &mut IrCompiler<'_>) -> Result<Self::Output, IrError> {
         let mut items = Vec::new();
 
-        for (expr, _) in &self.items {
-            items.push(expr.compile(c)?);
+        for (item, _) in &self.items {
+            match item {
+                ast::ExprVecItem::Expr(expr) => items.push(expr.compile(c)?),
+                ast::ExprVecItem::Spread(..) => {
+                    return Err(IrError::msg(item, "spread is not supported yet"));
+                }
+            }
         }
 
         Ok(ir::IrVec {
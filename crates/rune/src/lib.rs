@@ -236,3 +236,33 @@ where
     parser.eof()?;
     Ok(ast)
 }
+
+/// Parse the given input as the given type that implements
+/// [Parse][crate::parsing::Parse], without requiring that all input is
+/// consumed.
+///
+/// Returns the parsed node together with the byte offset where parsing
+/// stopped, which is the end of the last token that is part of the node. Any
+/// whitespace following it is *not* consumed, so the remaining input starts
+/// at the returned offset and can be parsed again by the caller.
+///
+/// # Examples
+///
+/// ```rust
+/// use rune::ast;
+///
+/// let source = "foo(1) bar(2)";
+/// let (_, offset) = rune::parse_partial::<ast::Expr>(source)?;
+/// assert_eq!(offset, 6);
+/// assert_eq!(&source[offset..], " bar(2)");
+/// # Ok::<_, rune::ParseError>(())
+/// ```
+pub fn parse_partial<T>(source: &str) -> Result<(T, usize), ParseError>
+where
+    T: crate::parsing::Parse + Spanned,
+{
+    let mut parser = Parser::new(source);
+    let ast = parser.parse::<T>()?;
+    let offset = ast.span().end.into_usize();
+    Ok((ast, offset))
+}
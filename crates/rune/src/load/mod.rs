@@ -117,6 +117,11 @@ pub fn load_sources_with_visitor<'a>(
         }
     }
 
+    if options.warnings_as_errors && diagnostics.has_warning() {
+        diagnostics.warnings_as_errors();
+        return Err(LoadSourcesError);
+    }
+
     match unit.build() {
         Ok(unit) => Ok(unit),
         Err(error) => {
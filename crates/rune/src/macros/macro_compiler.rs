@@ -66,6 +66,7 @@ impl MacroCompiler<'_> {
             item: self.item.clone(),
             query: self.query.clone(),
             consts: self.consts.clone(),
+            hygiene: Some(self.storage.next_hygiene()),
         };
 
         let result = crate::macros::with_context(macro_context, || handler(input_stream));
@@ -127,7 +128,7 @@ impl MacroCompiler<'_> {
             }
         };
 
-        let mut parser = Parser::from_token_stream(&token_stream);
+        let mut parser = Parser::from_token_stream_with_context(&token_stream, self.context);
         let output = parser.parse::<T>()?;
         parser.eof()?;
 
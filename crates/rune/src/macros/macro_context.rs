@@ -110,6 +110,8 @@ pub struct MacroContext {
     pub(crate) item: Arc<CompileItem>,
     /// Constants storage.
     pub(crate) consts: Consts,
+    /// The hygiene marker of the macro expansion, if any.
+    pub(crate) hygiene: Option<usize>,
 }
 
 impl MacroContext {
@@ -123,6 +125,7 @@ impl MacroContext {
             query: Default::default(),
             item: Default::default(),
             consts: Default::default(),
+            hygiene: None,
         }
     }
 
@@ -1,5 +1,6 @@
 use crate::ast;
 use crate::collections::HashMap;
+use runestick::Span;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -131,6 +132,33 @@ impl Storage {
         let s = inner.numbers.get(id)?;
         Some(with(s))
     }
+
+    /// Generate a fresh synthetic identifier starting with `prefix`.
+    ///
+    /// The identifier resolves to a reserved name like `prefix$0` which can't
+    /// be written in source, so it will never clash with user code. Every
+    /// call produces a distinct name.
+    ///
+    /// The identifier has an empty span, which can be replaced by setting its
+    /// `token.span`.
+    pub fn gen_ident(&self, prefix: &str) -> ast::Ident {
+        let id = {
+            let mut inner = self.inner.borrow_mut();
+            let id = inner.synthetic_idents;
+            inner.synthetic_idents += 1;
+            id
+        };
+
+        ast::Ident::new_with(&format!("{}${}", prefix, id), Span::empty(), self)
+    }
+
+    /// Allocate a hygiene marker for a new macro expansion.
+    pub(crate) fn next_hygiene(&self) -> usize {
+        let mut inner = self.inner.borrow_mut();
+        let hygiene = inner.hygiene;
+        inner.hygiene += 1;
+        hygiene
+    }
 }
 
 #[derive(Default)]
@@ -145,4 +173,8 @@ struct Inner {
     byte_strings_rev: HashMap<Vec<u8>, usize>,
     /// Numbers stored.
     numbers: Vec<ast::Number>,
+    /// Number of synthetic identifiers generated.
+    synthetic_idents: usize,
+    /// Number of hygiene markers allocated.
+    hygiene: usize,
 }
@@ -24,6 +24,14 @@ pub struct Options {
     pub(crate) macros: bool,
     /// Support (experimental) bytecode caching.
     pub bytecode: bool,
+    /// Panic instead of raising an error on division by zero.
+    pub(crate) panic_on_divide_by_zero: bool,
+    /// Raise an error instead of a warning when a binding shadows a constant.
+    pub(crate) shadowed_const_error: bool,
+    /// Treat any warnings as errors.
+    pub(crate) warnings_as_errors: bool,
+    /// Embed the text of compiled sources in the unit.
+    pub(crate) embed_sources: bool,
 
     /// Compile for and enable test features
     pub cfg_test: bool,
@@ -60,6 +68,18 @@ impl Options {
             Some("test") => {
                 self.cfg_test = it.next() != Some("false");
             }
+            Some("panic-on-divide-by-zero") => {
+                self.panic_on_divide_by_zero = it.next() != Some("false");
+            }
+            Some("shadowed-const-error") => {
+                self.shadowed_const_error = it.next() != Some("false");
+            }
+            Some("warnings-as-errors") => {
+                self.warnings_as_errors = it.next() != Some("false");
+            }
+            Some("embed-sources") => {
+                self.embed_sources = it.next() != Some("false");
+            }
             Some("v2") => {
                 self.v2 = it.next() != Some("false");
             }
@@ -100,6 +120,36 @@ impl Options {
         self.bytecode = enabled;
     }
 
+    /// Set if division or remainder by zero should cause an unrecoverable panic
+    /// instead of raising a [VmErrorKind::DivideByZero] error. Defaults to
+    /// `false`.
+    ///
+    /// [VmErrorKind::DivideByZero]: runestick::VmErrorKind::DivideByZero
+    pub fn panic_on_divide_by_zero(&mut self, enabled: bool) {
+        self.panic_on_divide_by_zero = enabled;
+    }
+
+    /// Set if a variable binding which shadows a constant with the same name
+    /// should be an error instead of a warning. Defaults to `false`.
+    pub fn shadowed_const_error(&mut self, enabled: bool) {
+        self.shadowed_const_error = enabled;
+    }
+
+    /// Set if any warnings produced during compilation should be promoted into
+    /// errors, causing compilation to fail. Defaults to `false`.
+    pub fn warnings_as_errors(&mut self, enabled: bool) {
+        self.warnings_as_errors = enabled;
+    }
+
+    /// Set if the text of compiled sources should be embedded in the unit, so
+    /// that [Unit::source_snippet] can be used without access to the original
+    /// sources. This makes the unit larger. Defaults to `false`.
+    ///
+    /// [Unit::source_snippet]: runestick::Unit::source_snippet
+    pub fn embed_sources(&mut self, enabled: bool) {
+        self.embed_sources = enabled;
+    }
+
     /// Memoize the instance function in a loop. Defaults to `false`.
     pub fn memoize_instance_fn(&mut self, enabled: bool) {
         self.memoize_instance_fn = enabled;
@@ -114,6 +164,10 @@ impl Default for Options {
             debug_info: true,
             macros: true,
             bytecode: false,
+            panic_on_divide_by_zero: false,
+            shadowed_const_error: false,
+            warnings_as_errors: false,
+            embed_sources: false,
             cfg_test: false,
             v2: false,
         }
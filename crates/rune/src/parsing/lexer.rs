@@ -129,15 +129,43 @@ impl<'a> Lexer<'a> {
 
         let mut is_fractional = false;
         let mut has_exponent = false;
+        // Digit separators are only permitted directly after a digit.
+        let mut after_digit = base == ast::NumberBase::Decimal;
+        let mut after_separator = false;
 
         while let Some(c) = self.iter.peek() {
             match c {
-                'e' if !has_exponent => {
+                '_' => {
+                    self.iter.next();
+
+                    if !after_digit {
+                        return Err(ParseError::new(
+                            self.iter.span_from(start),
+                            ParseErrorKind::BadNumberSeparator,
+                        ));
+                    }
+
+                    after_digit = false;
+                    after_separator = true;
+                    continue;
+                }
+                'e' | 'E' if base == ast::NumberBase::Decimal && !has_exponent => {
                     self.iter.next();
                     has_exponent = true;
-                    is_fractional = true;
+                    after_digit = false;
+
+                    if let Some('+') | Some('-') = self.iter.peek() {
+                        self.iter.next();
+                    }
+
+                    if !matches!(self.iter.peek(), Some('0'..='9')) {
+                        return Err(ParseError::new(
+                            self.iter.span_from(start),
+                            ParseErrorKind::BadNumber,
+                        ));
+                    }
                 }
-                '.' if !is_fractional => {
+                '.' if !is_fractional && !has_exponent => {
                     if let Some(p2) = self.iter.peek2() {
                         // NB: only skip if the next peek matches:
                         // * the beginning of an ident.
@@ -155,17 +183,29 @@ impl<'a> Lexer<'a> {
 
                     self.iter.next();
                     is_fractional = true;
+                    after_digit = false;
                 }
                 c if c.is_alphanumeric() => {
                     self.iter.next();
+                    after_digit = true;
                 }
                 _ => break,
             }
+
+            after_separator = false;
+        }
+
+        if after_separator {
+            return Err(ParseError::new(
+                self.iter.span_from(start),
+                ParseErrorKind::BadNumberSeparator,
+            ));
         }
 
         Ok(Some(ast::Token {
             kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
                 is_fractional,
+                has_exponent,
                 base,
             })),
             span: self.iter.span_from(start),
@@ -316,6 +356,64 @@ impl<'a> Lexer<'a> {
             kind: kind(ast::StrSource::Text(ast::StrText {
                 escaped,
                 wrapped: true,
+                raw: None,
+            })),
+            span: self.iter.span_from(start),
+        }))
+    }
+
+    /// Count the number of `#` hashes of a raw string, if we're positioned
+    /// right after the `r` prefix of one.
+    fn raw_str_hashes(&self) -> Option<usize> {
+        let mut it = self.iter.clone();
+        let mut hashes = 0;
+
+        loop {
+            match it.next()? {
+                '#' => hashes += 1,
+                '"' => return Some(hashes),
+                _ => return None,
+            }
+        }
+    }
+
+    /// Consume a raw string literal, which has no escape sequences and is
+    /// terminated by a `"` followed by the given number of `#` hashes.
+    fn next_raw_str(
+        &mut self,
+        start: usize,
+        hashes: usize,
+    ) -> Result<Option<ast::Token>, ParseError> {
+        // NB: skip over the opening hashes and quote.
+        for _ in 0..=hashes {
+            self.iter.next();
+        }
+
+        loop {
+            match self.iter.next() {
+                Some('"') => {
+                    let mut it = self.iter.clone();
+
+                    if (0..hashes).all(|_| it.next() == Some('#')) {
+                        self.iter = it;
+                        break;
+                    }
+                }
+                Some(_) => (),
+                None => {
+                    return Err(ParseError::new(
+                        self.iter.span_from(start),
+                        ParseErrorKind::UnterminatedStrLit,
+                    ));
+                }
+            }
+        }
+
+        Ok(Some(ast::Token {
+            kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                escaped: false,
+                wrapped: true,
+                raw: Some(hashes),
             })),
             span: self.iter.span_from(start),
         }))
@@ -370,6 +468,7 @@ impl<'a> Lexer<'a> {
                             kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
                                 escaped: take(&mut escaped),
                                 wrapped: false,
+                                raw: None,
                             })),
                             span,
                         });
@@ -419,6 +518,7 @@ impl<'a> Lexer<'a> {
                             kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
                                 escaped: take(&mut escaped),
                                 wrapped: false,
+                                raw: None,
                             })),
                             span,
                         });
@@ -496,6 +596,16 @@ impl<'a> Lexer<'a> {
                             self.iter.next();
                             break ast::Kind::StarEq;
                         }
+                        ('*', '*') => {
+                            self.iter.next();
+
+                            break if matches!(self.iter.peek(), Some('=')) {
+                                self.iter.next();
+                                ast::Kind::StarStarEq
+                            } else {
+                                ast::Kind::StarStar
+                            };
+                        }
                         ('/', '=') => {
                             self.iter.next();
                             break ast::Kind::SlashEq;
@@ -591,6 +701,11 @@ impl<'a> Lexer<'a> {
                             self.iter.next();
                             return self.next_lit_byte(start);
                         }
+                        ('r', '"') | ('r', '#') => {
+                            if let Some(hashes) = self.raw_str_hashes() {
+                                return self.next_raw_str(start, hashes);
+                            }
+                        }
                         ('b', '"') => {
                             self.iter.next();
                             return self.next_str(
@@ -877,6 +992,7 @@ impl fmt::Display for LexerMode {
 mod tests {
     use super::Lexer;
     use crate::ast;
+    use crate::ParseErrorKind;
     use runestick::span;
 
     macro_rules! test_lexer {
@@ -917,6 +1033,7 @@ mod tests {
                 span: span!(1, 3),
                 kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
                     is_fractional: false,
+                    has_exponent: false,
                     base: ast::NumberBase::Decimal,
                 })),
             },
@@ -933,6 +1050,7 @@ mod tests {
                 span: span!(1, 4),
                 kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
                     is_fractional: true,
+                    has_exponent: false,
                     base: ast::NumberBase::Decimal,
                 })),
             },
@@ -940,6 +1058,122 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_number_literal_separators() {
+        test_lexer! {
+            "1_0",
+            ast::Token {
+                span: span!(0, 3),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: false,
+                    has_exponent: false,
+                    base: ast::NumberBase::Decimal,
+                })),
+            },
+        };
+
+        test_lexer! {
+            "0b1010_1010",
+            ast::Token {
+                span: span!(0, 11),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: false,
+                    has_exponent: false,
+                    base: ast::NumberBase::Binary,
+                })),
+            },
+        };
+
+        // NB: a leading underscore is never part of a number literal.
+        test_lexer! {
+            "_1",
+            ast::Token {
+                span: span!(0, 1),
+                kind: ast::Kind::Underscore,
+            },
+            ast::Token {
+                span: span!(1, 2),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: false,
+                    has_exponent: false,
+                    base: ast::NumberBase::Decimal,
+                })),
+            },
+        };
+
+        for source in &["0x_1", "1__0", "1_"] {
+            let error = Lexer::new(source).next().unwrap_err();
+            assert!(
+                matches!(error.kind(), ParseErrorKind::BadNumberSeparator),
+                "{}: {:?}",
+                source,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_literal_exponents() {
+        test_lexer! {
+            "1e5",
+            ast::Token {
+                span: span!(0, 3),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: false,
+                    has_exponent: true,
+                    base: ast::NumberBase::Decimal,
+                })),
+            },
+        };
+
+        test_lexer! {
+            "2.5E+2",
+            ast::Token {
+                span: span!(0, 6),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: true,
+                    has_exponent: true,
+                    base: ast::NumberBase::Decimal,
+                })),
+            },
+        };
+
+        test_lexer! {
+            "1e-10",
+            ast::Token {
+                span: span!(0, 5),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: false,
+                    has_exponent: true,
+                    base: ast::NumberBase::Decimal,
+                })),
+            },
+        };
+
+        // NB: `e` is a hex digit.
+        test_lexer! {
+            "0x1e",
+            ast::Token {
+                span: span!(0, 4),
+                kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
+                    is_fractional: false,
+                    has_exponent: false,
+                    base: ast::NumberBase::Hex,
+                })),
+            },
+        };
+
+        for source in &["1e", "1e+", "1e-x"] {
+            let error = Lexer::new(source).next().unwrap_err();
+            assert!(
+                matches!(error.kind(), ParseErrorKind::BadNumber),
+                "{}: {:?}",
+                source,
+                error
+            );
+        }
+    }
+
     #[test]
     fn test_char_literal() {
         test_lexer! {
@@ -973,11 +1207,52 @@ mod tests {
             },
             ast::Token {
                 span: span!(10, 19),
-                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText { escaped: false, wrapped: true })),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                    escaped: false,
+                    wrapped: true,
+                    raw: None,
+                })),
             }
         };
     }
 
+    #[test]
+    fn test_raw_str() {
+        test_lexer! {
+            "r\"C:\\foo\"",
+            ast::Token {
+                span: span!(0, 9),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                    escaped: false,
+                    wrapped: true,
+                    raw: Some(0),
+                })),
+            }
+        };
+
+        test_lexer! {
+            "r##\"say \"#hi\"\"##",
+            ast::Token {
+                span: span!(0, 16),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                    escaped: false,
+                    wrapped: true,
+                    raw: Some(2),
+                })),
+            }
+        };
+
+        for source in &["r\"foo", "r#\"foo\""] {
+            let error = Lexer::new(source).next().unwrap_err();
+            assert!(
+                matches!(error.kind(), ParseErrorKind::UnterminatedStrLit),
+                "{}: {:?}",
+                source,
+                error
+            );
+        }
+    }
+
     #[test]
     fn test_operators() {
         test_lexer! {
@@ -1041,6 +1316,7 @@ mod tests {
                 span: span!(14, 16),
                 kind: ast::Kind::Number(ast::NumberSource::Text(ast::NumberText {
                     is_fractional: false,
+                    has_exponent: false,
                     base: ast::NumberBase::Decimal,
                 })),
             },
@@ -1099,6 +1375,7 @@ mod tests {
                 kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(1, 5),
             },
@@ -1118,6 +1395,7 @@ mod tests {
                 kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
                     escaped: true,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(11, 18),
             },
@@ -1176,6 +1454,7 @@ mod tests {
                 kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(1, 5),
             },
@@ -1195,6 +1474,7 @@ mod tests {
                 kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(11, 12),
             },
@@ -1222,6 +1502,7 @@ mod tests {
                 kind: ast::Kind::ByteStr(ast::StrSource::Text(ast::StrText {
                     escaped: false,
                     wrapped: true,
+                    raw: None,
                 })),
             },
         };
@@ -1233,6 +1514,7 @@ mod tests {
                 kind: ast::Kind::ByteStr(ast::StrSource::Text(ast::StrText {
                     escaped: false,
                     wrapped: true,
+                    raw: None,
                 })),
             },
         };
@@ -32,6 +32,32 @@ impl ParseError {
         )
     }
 
+    /// Construct an expectation error listing everything which would have been
+    /// valid.
+    ///
+    /// The expected set is sorted and deduplicated so that messages are
+    /// stable.
+    pub(crate) fn expected_one_of<A>(actual: A, expected: &[&'static str]) -> Self
+    where
+        A: Description + Spanned,
+    {
+        let mut expected = expected.to_vec();
+        expected.sort_unstable();
+        expected.dedup();
+
+        if let [expected] = &expected[..] {
+            return Self::expected(actual, *expected);
+        }
+
+        Self::new(
+            actual.span(),
+            ParseErrorKind::ExpectedOneOf {
+                actual: actual.description(),
+                expected: expected.into(),
+            },
+        )
+    }
+
     /// Construct an unsupported error.
     pub(crate) fn unsupported<T, E>(actual: T, what: E) -> Self
     where
@@ -54,7 +80,7 @@ impl From<ParseError> for SpannedError {
 }
 
 /// Error when parsing.
-#[derive(Debug, Clone, Copy, Error)]
+#[derive(Debug, Clone, Error)]
 #[allow(missing_docs)]
 pub enum ParseErrorKind {
     #[error("{message}")]
@@ -75,6 +101,11 @@ pub enum ParseErrorKind {
         actual: &'static str,
         expected: &'static str,
     },
+    #[error("expected one of: [{}], but got `{actual}`", expected.join(", "))]
+    ExpectedOneOf {
+        actual: &'static str,
+        expected: Box<[&'static str]>,
+    },
     #[error("{what} is not supported")]
     Unsupported { what: &'static str },
     #[error("expected escape sequence")]
@@ -108,6 +139,8 @@ pub enum ParseErrorKind {
     },
     #[error("bad number literal")]
     BadNumber,
+    #[error("digit separator `_` must be placed between digits in number literal")]
+    BadNumberSeparator,
     #[error("can only specify one attribute named `{name}`")]
     MultipleMatchingAttributes { name: &'static str },
 }
@@ -1,8 +1,9 @@
 use crate::ast::{Kind, Token};
 use crate::macros::{TokenStream, TokenStreamIter};
 use crate::parsing::{Lexer, Parse, ParseError, ParseErrorKind, Peek};
+use crate::shared::Description;
 use crate::OptionSpanned as _;
-use runestick::Span;
+use runestick::{Context, ContextOperator, Span};
 use std::collections::VecDeque;
 use std::fmt;
 use std::ops;
@@ -22,6 +23,8 @@ pub struct Parser<'a> {
     peeker: Peeker<'a>,
     /// The default span to use in case no better one is available.
     span: Option<Span>,
+    /// Things which would have been valid at the current position.
+    expected: Vec<&'static str>,
 }
 
 impl<'a> Parser<'a> {
@@ -32,6 +35,14 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Construct a new parser around the given source, which recognizes the
+    /// custom operators registered in the given context.
+    pub fn with_context(source: &'a str, context: &'a Context) -> Self {
+        let mut parser = Self::new(source);
+        parser.peeker.context = Some(context);
+        parser
+    }
+
     /// Try to consume a single thing matching `T`, returns `true` if any tokens
     /// were consumed.
     pub fn try_consume<T>(&mut self) -> Result<bool, ParseError>
@@ -63,12 +74,27 @@ impl<'a> Parser<'a> {
     }
 
     /// Construct a parser from a token stream.
+    ///
+    /// Custom operators aren't recognized by this parser, so an operator like
+    /// `<>` is parsed as the two tokens it's made up of. Use
+    /// [Parser::from_token_stream_with_context] to recognize them.
     pub fn from_token_stream(token_stream: &'a TokenStream) -> Self {
         Self::with_source(Source {
             inner: SourceInner::TokenStream(token_stream.iter()),
         })
     }
 
+    /// Construct a parser from a token stream, which recognizes the custom
+    /// operators registered in the given context.
+    pub fn from_token_stream_with_context(
+        token_stream: &'a TokenStream,
+        context: &'a Context,
+    ) -> Self {
+        let mut parser = Self::from_token_stream(token_stream);
+        parser.peeker.context = Some(context);
+        parser
+    }
+
     /// Construct a new parser with a source.
     fn with_source(source: Source<'a>) -> Self {
         let span = source.span().or_else(crate::macros::current_stream_span);
@@ -79,8 +105,10 @@ impl<'a> Parser<'a> {
                 buf: VecDeque::new(),
                 error: None,
                 last: None,
+                context: None,
             },
             span,
+            expected: Vec::new(),
         }
     }
 
@@ -138,11 +166,15 @@ impl<'a> Parser<'a> {
         }
 
         if let Some(t) = self.peeker.buf.pop_front() {
+            self.expected.clear();
             return Ok(t);
         }
 
         match self.peeker.source.next()? {
-            Some(t) => Ok(t),
+            Some(t) => {
+                self.expected.clear();
+                Ok(t)
+            }
             None => Err(ParseError::new(
                 self.last_span().end(),
                 ParseErrorKind::UnexpectedEof,
@@ -150,6 +182,35 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Record that the given thing would have been valid at the current
+    /// position.
+    ///
+    /// The set is reset as soon as a token is consumed, and is reported by
+    /// [Parser::expected_error].
+    pub(crate) fn expected<E>(&mut self, expected: E)
+    where
+        E: Description,
+    {
+        self.expected.push(expected.description());
+    }
+
+    /// Record that any of the given token kinds would have been valid at the
+    /// current position.
+    pub(crate) fn expected_kinds(&mut self, kinds: &[Kind]) {
+        for kind in kinds {
+            self.expected(kind);
+        }
+    }
+
+    /// Construct an error for the current token, listing everything which
+    /// would have been valid at this position.
+    pub(crate) fn expected_error(&mut self) -> ParseError {
+        match self.tok_at(0) {
+            Ok(token) => ParseError::expected_one_of(&token, &self.expected),
+            Err(error) => error,
+        }
+    }
+
     /// Test if the parser is at end-of-file, after which there is no more input
     /// to parse.
     pub fn is_eof(&mut self) -> Result<bool, ParseError> {
@@ -177,6 +238,14 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Peek the kinds of the next three tokens.
+    ///
+    /// This is the lookahead needed to tell apart grammar which only differs
+    /// further in, like `Foo { a: 1 }` from a `Foo` followed by a block.
+    pub fn peek3(&mut self) -> Result<(Kind, Kind, Kind), ParseError> {
+        Ok((self.nth(0)?, self.nth(1)?, self.nth(2)?))
+    }
+
     /// Get the span for the given offset.
     pub fn span_at(&mut self, n: usize) -> Span {
         if let Ok(Some(t)) = self.peeker.at(n) {
@@ -205,7 +274,6 @@ impl<'a> Parser<'a> {
 }
 
 /// Construct used to peek a parser.
-#[derive(Debug)]
 pub struct Peeker<'a> {
     pub(crate) source: Source<'a>,
     buf: VecDeque<Token>,
@@ -213,10 +281,15 @@ pub struct Peeker<'a> {
     error: Option<ParseError>,
     /// The last span we encountered. Used to provide better EOF diagnostics.
     last: Option<Span>,
+    /// Context used to look up custom operators.
+    context: Option<&'a Context>,
 }
 
 impl<'a> Peeker<'a> {
     /// Peek the token kind at the given position.
+    ///
+    /// Tokens are buffered as needed, so this can look arbitrarily far ahead
+    /// without consuming anything.
     pub fn nth(&mut self, n: usize) -> Kind {
         // Error tripped already, this peeker returns nothing but errors from
         // here on out.
@@ -236,6 +309,11 @@ impl<'a> Peeker<'a> {
         }
     }
 
+    /// Peek the kinds of the next three tokens.
+    pub fn peek3(&mut self) -> (Kind, Kind, Kind) {
+        (self.nth(0), self.nth(1), self.nth(2))
+    }
+
     /// Make sure there are at least `n` items in the buffer, and return the
     /// item at that point.
     fn at(&mut self, n: usize) -> Result<Option<Token>, ParseError> {
@@ -266,6 +344,58 @@ impl<'a> Peeker<'a> {
             }
         }
     }
+
+    /// Look up the custom operator made up of the next two tokens, if they are
+    /// written without whitespace between them.
+    pub(crate) fn custom_operator(&mut self) -> Option<ContextOperator> {
+        let context = self.context?;
+
+        if self.error.is_some() {
+            return None;
+        }
+
+        let (first, second) = match self.at(1) {
+            Ok(Some(second)) => (self.buf[0], second),
+            Ok(None) => return None,
+            Err(error) => {
+                self.error = Some(error);
+                return None;
+            }
+        };
+
+        if first.span.end != second.span.start {
+            return None;
+        }
+
+        // NB: operators are at most three characters long, so the lookup key
+        // is built on the stack instead of allocating on every peek.
+        let first = (&first.kind).description();
+        let second = (&second.kind).description();
+        let len = first.len() + second.len();
+
+        let mut buf = [0u8; 3];
+
+        if len > buf.len() {
+            return None;
+        }
+
+        buf[..first.len()].copy_from_slice(first.as_bytes());
+        buf[first.len()..len].copy_from_slice(second.as_bytes());
+
+        let op = std::str::from_utf8(&buf[..len]).ok()?;
+        context.lookup_operator(op).copied()
+    }
+}
+
+impl fmt::Debug for Peeker<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Peeker")
+            .field("source", &self.source)
+            .field("buf", &self.buf)
+            .field("error", &self.error)
+            .field("last", &self.last)
+            .finish()
+    }
 }
 
 /// A source adapter.
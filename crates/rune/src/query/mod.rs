@@ -1,6 +1,7 @@
 //! Lazy query system, used to compile and build items on demand.
 
 use crate::ast;
+use crate::attrs;
 use crate::collections::{HashMap, HashSet};
 use crate::ir;
 use crate::ir::{IrBudget, IrCompile, IrCompiler, IrInterpreter, IrQuery};
@@ -14,7 +15,8 @@ use runestick::format;
 use runestick::{
     Call, CompileItem, CompileMeta, CompileMetaCapture, CompileMetaEmpty, CompileMetaKind,
     CompileMetaStruct, CompileMetaTuple, CompileMod, CompileSource, Component, ComponentRef,
-    Context, Hash, IntoComponent, Item, Location, Names, Source, SourceId, Span, Visibility,
+    Context, Hash, IntoComponent, Item, Location, Names, Source, SourceId, Span, TypeCheck,
+    Visibility,
 };
 use std::cell::{RefCell, RefMut};
 use std::collections::VecDeque;
@@ -134,6 +136,7 @@ impl Query {
                 items: HashMap::new(),
                 names: Names::default(),
                 modules: HashMap::new(),
+                deprecations: HashMap::new(),
             })),
         }
     }
@@ -291,6 +294,19 @@ impl Query {
         self.inner.borrow().item_for(ast.span(), ast.id())
     }
 
+    /// Mark the given item as deprecated with the given message.
+    pub(crate) fn insert_deprecation(&self, item: &Item, message: Box<str>) {
+        self.inner
+            .borrow_mut()
+            .deprecations
+            .insert(item.clone(), message);
+    }
+
+    /// Get the deprecation message of the given item, if it's deprecated.
+    pub(crate) fn deprecation(&self, item: &Item) -> Option<Box<str>> {
+        self.inner.borrow().deprecations.get(item).cloned()
+    }
+
     /// Get the expanded internal macro for the given identifier.
     pub(crate) fn builtin_macro_for<T>(&self, ast: T) -> Result<Arc<BuiltInMacro>, QueryError>
     where
@@ -652,6 +668,8 @@ struct QueryInner {
     names: Names,
     /// Modules and associated metadata.
     modules: HashMap<Item, Arc<CompileMod>>,
+    /// Deprecation messages of deprecated items.
+    deprecations: HashMap<Item, Box<str>>,
 }
 
 impl Default for QueryInner {
@@ -672,6 +690,7 @@ impl Default for QueryInner {
             items: Default::default(),
             names: Default::default(),
             modules: Default::default(),
+            deprecations: Default::default(),
         }
     }
 }
@@ -1114,11 +1133,12 @@ impl QueryInner {
                 ));
             }
             (None, segment) => match segment {
-                ast::PathSegment::Ident(ident) => {
-                    let ident = ident.resolve(storage, source)?;
+                ast::PathSegment::Ident(segment) => {
+                    let ident = segment.resolve(storage, source)?;
 
                     if path.rest.is_empty() {
-                        local = Some(<Box<str>>::from(ident.as_ref()));
+                        let name = segment.resolve_local(storage, source)?;
+                        local = Some(<Box<str>>::from(name.as_ref()));
                     }
 
                     self.lookup_initial(context, &qp.module, &qp.item, &*ident)?
@@ -1527,6 +1547,55 @@ pub(crate) struct InstanceFunction {
     pub(crate) call: Call,
 }
 
+/// A protocol function derived for a script type, like through
+/// `#[derive(PartialEq)]`.
+#[derive(Debug, Clone)]
+pub(crate) struct Derive {
+    /// The span of the derived trait.
+    pub(crate) span: Span,
+    /// The trait being derived.
+    pub(crate) kind: attrs::DeriveKind,
+    /// The type hash the protocol function is implemented for.
+    pub(crate) type_hash: Hash,
+    /// The structs or variants making up the type. Structs only have one.
+    pub(crate) variants: Vec<DeriveVariant>,
+}
+
+/// A struct or variant which a protocol function is derived for.
+#[derive(Debug, Clone)]
+pub(crate) struct DeriveVariant {
+    /// The name of the struct or variant.
+    pub(crate) name: Box<str>,
+    /// The type check matching the struct or variant.
+    pub(crate) type_check: TypeCheck,
+    /// The fields of the struct or variant.
+    pub(crate) fields: DeriveFields,
+}
+
+/// The fields of a struct or variant which a protocol function is derived
+/// for.
+#[derive(Debug, Clone)]
+pub(crate) enum DeriveFields {
+    /// A unit struct or variant.
+    Unit,
+    /// A tuple struct or variant with the given number of fields.
+    Tuple(usize),
+    /// A struct or struct variant with the given named fields, in the order
+    /// they are declared.
+    Struct(Vec<Box<str>>),
+}
+
+impl DeriveFields {
+    /// The number of fields.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Unit => 0,
+            Self::Tuple(len) => *len,
+            Self::Struct(names) => names.len(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Closure {
     /// Ast for closure.
@@ -1570,6 +1639,7 @@ pub(crate) struct ConstFn {
 pub(crate) enum Build {
     Function(Function),
     InstanceFunction(InstanceFunction),
+    Derive(Derive),
     Closure(Closure),
     AsyncBlock(AsyncBlock),
     Unused,
@@ -5,7 +5,9 @@ use crate::collections::HashMap;
 use crate::indexing::{Index as _, IndexScopes, Indexer};
 use crate::query::Query;
 use crate::shared::{Consts, Gen, Items};
-use crate::{CompileVisitor, Diagnostics, Options, SourceLoader, Sources, Storage, UnitBuilder};
+use crate::{
+    CompileVisitor, Diagnostics, Options, Parser, SourceLoader, Sources, Storage, UnitBuilder,
+};
 use runestick::{Context, Item, SourceId, Span};
 use std::collections::VecDeque;
 use std::rc::Rc;
@@ -93,7 +95,9 @@ impl<'a> Worker<'a> {
                         }
                     };
 
-                    let mut file = match crate::parse_all::<ast::File>(source.as_str()) {
+                    let mut parser = Parser::with_context(source.as_str(), self.context);
+
+                    let mut file = match parser.parse_all::<ast::File>() {
                         Ok(file) => file,
                         Err(error) => {
                             self.diagnostics.error(source_id, error);
@@ -101,6 +105,9 @@ impl<'a> Worker<'a> {
                         }
                     };
 
+                    // NB: a root source without a path can't load modules, which
+                    // is reported using the name of the source when a module is
+                    // encountered.
                     let root = match kind {
                         LoadFileKind::Root => source.path().map(ToOwned::to_owned),
                         LoadFileKind::Module { root } => root,
@@ -2,6 +2,7 @@ use crate::internals::*;
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
 use syn::spanned::Spanned as _;
 use syn::Lit;
 use syn::Meta::*;
@@ -33,6 +34,9 @@ pub(crate) struct FieldAttrs {
     /// `#[rune(copy)]` to indicate that a field is copy and does not need to be
     /// cloned.
     pub(crate) copy: bool,
+    /// `#[rune(name = "..")]` to override the name of the field used by
+    /// protocol generators.
+    pub(crate) name: Option<syn::LitStr>,
 }
 
 /// Parsed field attributes.
@@ -204,6 +208,14 @@ impl Context {
                     Meta(Path(path)) if path == COPY => {
                         output.copy = true;
                     }
+                    // Parse `#[rune(name = "..")]`.
+                    Meta(NameValue(syn::MetaNameValue {
+                        path,
+                        lit: Lit::Str(name),
+                        ..
+                    })) if path == NAME => {
+                        output.name = Some(name);
+                    }
                     Meta(meta) if meta.path() == GET => {
                         output.protocols.push(FieldProtocol {
                             custom: self.parse_field_custom(meta)?,
@@ -427,6 +439,8 @@ impl Context {
 
         let ident = &input.ident;
         let (_, ty_generics, _) = generics.split_for_impl();
+        let mut names = HashSet::new();
+
         match &input.data {
             syn::Data::Struct(st) => {
                 for field in &st.fields {
@@ -448,7 +462,19 @@ impl Context {
                     };
 
                     let ty = &field.ty;
-                    let name = &syn::LitStr::new(&field_ident.to_string(), field_ident.span());
+
+                    let name = &match &attrs.name {
+                        Some(name) => name.clone(),
+                        None => syn::LitStr::new(&field_ident.to_string(), field_ident.span()),
+                    };
+
+                    if !attrs.protocols.is_empty() && !names.insert(name.value()) {
+                        self.errors.push(syn::Error::new_spanned(
+                            name,
+                            format!("field name `{}` is used by another field", name.value()),
+                        ));
+                        return None;
+                    }
 
                     for protocol in &attrs.protocols {
                         installers.push((protocol.generate)(Generate {
@@ -465,12 +491,20 @@ impl Context {
                     }
                 }
             }
-            syn::Data::Enum(..) => {
-                self.errors.push(syn::Error::new_spanned(
-                    input,
-                    "`Any` not supported on enums",
-                ));
-                return None;
+            syn::Data::Enum(en) => {
+                for variant in &en.variants {
+                    for field in &variant.fields {
+                        let attrs = self.parse_field_attrs(&field.attrs)?;
+
+                        if !attrs.protocols.is_empty() {
+                            self.errors.push(syn::Error::new_spanned(
+                                field,
+                                "fields of enum variants can't be used with protocol generators like `#[rune(get)]`",
+                            ));
+                            return None;
+                        }
+                    }
+                }
             }
             syn::Data::Union(..) => {
                 self.errors.push(syn::Error::new_spanned(
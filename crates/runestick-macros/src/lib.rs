@@ -79,6 +79,26 @@ pub fn to_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// This is required to support the external type as a type argument in a
 /// registered function.
 ///
+/// Enums, tuple structs and unit structs can also derive `Any`, which exposes
+/// them as opaque types. Protocol generators like `#[rune(get)]` can only be
+/// used on named fields of structs.
+///
+/// ```rust
+/// use runestick::Any;
+///
+/// #[derive(Any)]
+/// enum Shape {
+///     Circle(f64),
+///     Square { side: f64 },
+/// }
+///
+/// fn install() -> Result<runestick::Module, runestick::ContextError> {
+///     let mut module = runestick::Module::new();
+///     module.ty::<Shape>()?;
+///     Ok(module)
+/// }
+/// ```
+///
 /// ## `#[rune(name = "..")]` attribute
 ///
 /// The name of a type defaults to its identifiers, so `struct Foo {}` would be
@@ -100,6 +120,19 @@ pub fn to_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///     Ok(module)
 /// }
 /// ```
+///
+/// The same attribute can be used on a field to change the name that
+/// protocol generators like `#[rune(get)]` and `#[rune(set)]` expose it as:
+///
+/// ```rust
+/// use runestick::Any;
+///
+/// #[derive(Any)]
+/// struct Response {
+///     #[rune(get, name = "status")]
+///     http_status: u16,
+/// }
+/// ```
 #[proc_macro_derive(Any, attributes(rune))]
 pub fn any(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive = syn::parse_macro_input!(input as any::Derive);
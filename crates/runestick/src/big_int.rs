@@ -0,0 +1,105 @@
+//! Support for integers which have been promoted to arbitrary precision.
+//!
+//! Integers are only promoted if enabled through
+//! [RuntimeContext::set_promote_integers][crate::RuntimeContext::set_promote_integers].
+//! Results which fit into an `i64` are always demoted back into a regular
+//! integer, so a big integer is never equal to a regular integer.
+
+use crate::{AnyObj, Value, VmError};
+use num_bigint::BigInt;
+use std::cmp::Ordering;
+use std::convert::TryFrom as _;
+
+crate::__internal_impl_any!(num_bigint::BigInt);
+
+/// Convert a value into a big integer, if it is an integer.
+pub(crate) fn from_value(value: &Value) -> Result<Option<BigInt>, VmError> {
+    Ok(match value {
+        Value::Integer(value) => Some(BigInt::from(*value)),
+        Value::Any(any) => any.borrow_ref()?.downcast_borrow_ref::<BigInt>().cloned(),
+        _ => None,
+    })
+}
+
+/// Convert a big integer into a value, demoting it into a regular integer if
+/// it fits.
+pub(crate) fn into_value(value: BigInt) -> Value {
+    match i64::try_from(&value) {
+        Ok(value) => Value::Integer(value),
+        Err(..) => Value::from(AnyObj::new(value)),
+    }
+}
+
+/// Perform a binary operation where at least one side is a big integer.
+///
+/// Returns `None` if the operands are not both integers.
+pub(crate) fn binary<T>(
+    lhs: &Value,
+    rhs: &Value,
+    op: fn(&BigInt, &BigInt) -> T,
+) -> Result<Option<T>, VmError> {
+    if !is_big_int(lhs)? && !is_big_int(rhs)? {
+        return Ok(None);
+    }
+
+    let (lhs, rhs) = match (from_value(lhs)?, from_value(rhs)?) {
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(op(&lhs, &rhs)))
+}
+
+/// Get the sign of an integer value, or [Ordering::Equal] if it isn't an
+/// integer.
+pub(crate) fn sign(value: &Value) -> Result<Ordering, VmError> {
+    Ok(match from_value(value)? {
+        Some(value) => value.cmp(&BigInt::from(0)),
+        None => Ordering::Equal,
+    })
+}
+
+/// Test if two values are equal, where at least one of them is a big integer.
+pub(crate) fn eq(lhs: &Value, rhs: &Value) -> Result<Option<bool>, VmError> {
+    binary(lhs, rhs, |a, b| a.cmp(b) == Ordering::Equal)
+}
+
+pub(crate) fn add(a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    Some(a + b)
+}
+
+pub(crate) fn sub(a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    Some(a - b)
+}
+
+pub(crate) fn mul(a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    Some(a * b)
+}
+
+pub(crate) fn div(a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    if b.sign() == num_bigint::Sign::NoSign {
+        return None;
+    }
+
+    Some(a / b)
+}
+
+pub(crate) fn rem(a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    if b.sign() == num_bigint::Sign::NoSign {
+        return None;
+    }
+
+    Some(a % b)
+}
+
+pub(crate) fn pow(a: &BigInt, b: &BigInt) -> Option<BigInt> {
+    Some(a.pow(u32::try_from(b).ok()?))
+}
+
+/// Test if the given value is a big integer.
+fn is_big_int(value: &Value) -> Result<bool, VmError> {
+    Ok(match value {
+        Value::Any(any) => any.borrow_ref()?.is::<BigInt>(),
+        _ => false,
+    })
+}
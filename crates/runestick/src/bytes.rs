@@ -3,8 +3,8 @@
 //! [Value::Bytes]: crate::Value::Bytes.
 
 use crate::{
-    FromValue, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, UnsafeFromValue, Value,
-    VmError,
+    FromValue, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue,
+    UnsafeFromValue, Value, VmError,
 };
 
 use std::cmp;
@@ -130,6 +130,29 @@ impl FromValue for Bytes {
     }
 }
 
+/// Clones the bytes out of the script.
+impl FromValue for Box<[u8]> {
+    fn from_value(value: Value) -> Result<Self, VmError> {
+        let bytes = value.into_bytes()?;
+        let bytes = bytes.borrow_ref()?;
+        Ok(bytes.bytes.as_slice().into())
+    }
+}
+
+/// Converts into [Value::Bytes] without copying.
+impl ToValue for Box<[u8]> {
+    fn to_value(self) -> Result<Value, VmError> {
+        Ok(Value::from(Shared::new(Bytes::from_vec(self.into_vec()))))
+    }
+}
+
+/// Copies the slice into [Value::Bytes].
+impl ToValue for &[u8] {
+    fn to_value(self) -> Result<Value, VmError> {
+        Ok(Value::from(Shared::new(Bytes::from_vec(self.to_vec()))))
+    }
+}
+
 impl<'a> UnsafeFromValue for &'a Bytes {
     type Output = *const Bytes;
     type Guard = RawRef;
@@ -191,6 +191,10 @@ impl<'de> de::Deserialize<'de> for ConstValue {
     where
         D: de::Deserializer<'de>,
     {
+        if !deserializer.is_human_readable() {
+            return Ok(Tagged::deserialize(deserializer)?.into());
+        }
+
         deserializer.deserialize_any(ConstValueVisitor)
     }
 }
@@ -204,6 +208,10 @@ impl ser::Serialize for ConstValue {
         use serde::ser::SerializeMap as _;
         use serde::ser::SerializeSeq as _;
 
+        if !serializer.is_human_readable() {
+            return TaggedRef::from(self).serialize(serializer);
+        }
+
         match self {
             Self::Unit => serializer.serialize_unit(),
             Self::Bool(b) => serializer.serialize_bool(*b),
@@ -246,6 +254,84 @@ impl ser::Serialize for ConstValue {
     }
 }
 
+/// The representation of a constant value used by formats which aren't human
+/// readable, like the one used to store units.
+///
+/// These formats aren't self-describing, so the kind of the value has to be
+/// stored with it. Serialized through [TaggedRef], which must have the same
+/// variants in the same order.
+#[derive(serde::Deserialize)]
+enum Tagged {
+    Unit,
+    Byte(u8),
+    Char(char),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Bytes(vec::Vec<u8>),
+    Vec(vec::Vec<ConstValue>),
+    Tuple(vec::Vec<ConstValue>),
+    Object(HashMap<String, ConstValue>),
+    Option(Option<Box<ConstValue>>),
+}
+
+impl From<Tagged> for ConstValue {
+    fn from(value: Tagged) -> Self {
+        match value {
+            Tagged::Unit => Self::Unit,
+            Tagged::Byte(b) => Self::Byte(b),
+            Tagged::Char(c) => Self::Char(c),
+            Tagged::Bool(b) => Self::Bool(b),
+            Tagged::Integer(n) => Self::Integer(n),
+            Tagged::Float(n) => Self::Float(n),
+            Tagged::String(s) => Self::String(s),
+            Tagged::Bytes(b) => Self::Bytes(Bytes::from_vec(b)),
+            Tagged::Vec(vec) => Self::Vec(vec),
+            Tagged::Tuple(tuple) => Self::Tuple(tuple.into_boxed_slice()),
+            Tagged::Object(object) => Self::Object(object),
+            Tagged::Option(option) => Self::Option(option),
+        }
+    }
+}
+
+/// A borrowed [Tagged] value.
+#[derive(serde::Serialize)]
+enum TaggedRef<'a> {
+    Unit,
+    Byte(u8),
+    Char(char),
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(&'a str),
+    Bytes(&'a [u8]),
+    Vec(&'a [ConstValue]),
+    Tuple(&'a [ConstValue]),
+    Object(&'a HashMap<String, ConstValue>),
+    Option(&'a Option<Box<ConstValue>>),
+}
+
+impl<'a> From<&'a ConstValue> for TaggedRef<'a> {
+    fn from(value: &'a ConstValue) -> Self {
+        match value {
+            ConstValue::Unit => Self::Unit,
+            ConstValue::Byte(b) => Self::Byte(*b),
+            ConstValue::Char(c) => Self::Char(*c),
+            ConstValue::Bool(b) => Self::Bool(*b),
+            ConstValue::Integer(n) => Self::Integer(*n),
+            ConstValue::Float(n) => Self::Float(*n),
+            ConstValue::String(s) => Self::String(s),
+            ConstValue::StaticString(s) => Self::String(s.as_ref()),
+            ConstValue::Bytes(b) => Self::Bytes(b),
+            ConstValue::Vec(vec) => Self::Vec(vec),
+            ConstValue::Tuple(tuple) => Self::Tuple(tuple),
+            ConstValue::Object(object) => Self::Object(object),
+            ConstValue::Option(option) => Self::Option(option),
+        }
+    }
+}
+
 struct ConstValueVisitor;
 
 impl<'de> de::Visitor<'de> for ConstValueVisitor {
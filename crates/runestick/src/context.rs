@@ -1,8 +1,10 @@
 use crate::{
     collections::{HashMap, HashSet},
     module::{
-        ModuleAssociatedFn, ModuleFn, ModuleInternalEnum, ModuleMacro, ModuleType, ModuleUnitType,
+        ModuleAssociatedFn, ModuleConversion, ModuleFn, ModuleInternalEnum, ModuleMacro,
+        ModuleOperator, ModuleType, ModuleUnitType,
     },
+    runtime_context::FunctionHandler,
     CompileMeta, CompileMetaKind, CompileMetaStruct, CompileMetaTuple, ComponentRef, ConstValue,
     Hash, IntoComponent, Item, Module, Names, Protocol, RuntimeContext, Stack, StaticType,
     TypeCheck, TypeInfo, TypeOf, VmError,
@@ -45,6 +47,45 @@ pub enum ContextError {
         /// The name of the conflicting function.
         name: Item,
     },
+    /// Error raised when attempting to register a conflicting operator.
+    #[error("operator `{op}` already exists")]
+    ConflictingOperator {
+        /// The conflicting operator.
+        op: Box<str>,
+    },
+    /// Error raised when installing a conversion to or from a type which
+    /// hasn't been installed.
+    #[error("type `{type_info}` must be installed before conversions using it")]
+    MissingConversionType {
+        /// The type which is missing.
+        type_info: TypeInfo,
+    },
+    /// Error raised when attempting to register a conflicting conversion.
+    #[error("conversion from `{from}` to `{to}` already exists")]
+    ConflictingConversion {
+        /// The type converted from.
+        from: TypeInfo,
+        /// The type converted to.
+        to: TypeInfo,
+    },
+    /// Error raised when attempting to modify a function which has not been
+    /// registered.
+    #[error("function with name `{name}` does not exist")]
+    MissingFunction {
+        /// The name of the missing function.
+        name: Item,
+    },
+    /// Error raised when the number of argument names doesn't match the number
+    /// of arguments of a function.
+    #[error("function `{name}` expects {expected} arguments, but {actual} names were given")]
+    ArgNamesMismatch {
+        /// The name of the function.
+        name: Item,
+        /// The number of arguments the function expects.
+        expected: usize,
+        /// The number of argument names given.
+        actual: usize,
+    },
     /// Error raised when attempting to register a conflicting constant.
     #[error("constant with name `{name}` already exists")]
     ConflictingConstantName {
@@ -133,6 +174,15 @@ impl fmt::Display for ContextTypeInfo {
     }
 }
 
+/// A custom binary operator registered in the context.
+#[derive(Debug, Clone, Copy)]
+pub struct ContextOperator {
+    /// The precedence of the operator.
+    pub precedence: usize,
+    /// The hash of the function implementing the operator.
+    pub hash: Hash,
+}
+
 /// A description of a function signature.
 #[derive(Debug, Clone)]
 pub enum ContextSignature {
@@ -142,8 +192,10 @@ pub enum ContextSignature {
         type_hash: Hash,
         /// Path to the function.
         item: Item,
-        /// Arguments.
+        /// Arguments, or `None` if the function is variadic.
         args: Option<usize>,
+        /// Names of the arguments, if available.
+        arg_names: Option<Vec<String>>,
     },
     /// An instance function or method
     Instance {
@@ -163,10 +215,34 @@ pub enum ContextSignature {
 impl fmt::Display for ContextSignature {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Function { item, args, .. } => {
+            Self::Function {
+                item,
+                args,
+                arg_names,
+                ..
+            } => {
                 write!(fmt, "{}(", item)?;
 
-                if let Some(args) = args {
+                if let Some(names) = arg_names {
+                    let mut it = names.iter();
+                    let last = it.next_back();
+
+                    for name in it {
+                        write!(fmt, "{}, ", name)?;
+                    }
+
+                    if let Some(name) = last {
+                        write!(fmt, "{}", name)?;
+                    }
+
+                    if args.is_none() {
+                        if names.is_empty() {
+                            write!(fmt, "...")?;
+                        } else {
+                            write!(fmt, ", ...")?;
+                        }
+                    }
+                } else if let Some(args) = args {
                     let mut it = 0..*args;
                     let last = it.next_back();
 
@@ -222,6 +298,10 @@ pub struct Context {
     meta: HashMap<Item, CompileMeta>,
     /// Registered native function handlers.
     functions: HashMap<Hash, Arc<Handler>>,
+    /// Native functions which are allowed to yield back to the virtual machine.
+    resumable: HashSet<Hash>,
+    /// Native functions which are async.
+    async_functions: HashSet<Hash>,
     /// Registered native macro handlers.
     macros: HashMap<Hash, Arc<Macro>>,
     /// Information on functions.
@@ -240,6 +320,11 @@ pub struct Context {
     crates: HashSet<Box<str>>,
     /// Constants visible in this context
     constants: HashMap<Hash, ConstValue>,
+    /// Custom binary operators.
+    operators: HashMap<Box<str>, ContextOperator>,
+    /// Conversions between types, keyed by the hashes of the types converted
+    /// from and to.
+    conversions: HashMap<(Hash, Hash), Arc<Handler>>,
 }
 
 impl Context {
@@ -296,9 +381,28 @@ impl Context {
     /// ```
     pub fn runtime(&self) -> RuntimeContext {
         RuntimeContext {
-            functions: self.functions.clone(),
+            functions: self
+                .functions
+                .iter()
+                .map(|(hash, handler)| {
+                    let function = FunctionHandler {
+                        handler: handler.clone(),
+                        resumable: self.resumable.contains(hash),
+                    };
+
+                    (*hash, function)
+                })
+                .collect(),
+            conversions: self.conversions.clone(),
             types: self.types.iter().map(|(k, t)| (*k, t.type_check)).collect(),
+            type_infos: self
+                .types
+                .values()
+                .map(|t| (t.type_hash, t.type_info.clone()))
+                .collect(),
             constants: self.constants.clone(),
+            catch_native_panics: false,
+            promote_integers: false,
         }
     }
 
@@ -368,6 +472,21 @@ impl Context {
         self.meta.get(name).cloned()
     }
 
+    /// Iterate over the names of all custom binary operators.
+    pub fn iter_operators(&self) -> impl Iterator<Item = &str> + '_ {
+        self.operators.keys().map(|op| op.as_ref())
+    }
+
+    /// Lookup the custom binary operator with the given name.
+    pub fn lookup_operator(&self, op: &str) -> Option<&ContextOperator> {
+        self.operators.get(op)
+    }
+
+    /// Lookup the signature of the given native function.
+    pub fn lookup_signature(&self, hash: Hash) -> Option<&ContextSignature> {
+        self.functions_info.get(&hash)
+    }
+
     /// Iterate over all available functions
     pub fn iter_functions(&self) -> impl Iterator<Item = (Hash, &ContextSignature)> {
         let mut it = self.functions_info.iter();
@@ -378,6 +497,27 @@ impl Context {
         })
     }
 
+    /// Iterate over the item, hash, number of arguments and whether it's async
+    /// for every available function and instance function.
+    ///
+    /// The number of arguments is `None` if the function is variadic. For
+    /// instance functions it includes the instance itself, and the item is
+    /// the item of the instance type extended with the name of the function.
+    pub fn iter_function_info(
+        &self,
+    ) -> impl Iterator<Item = (Item, Hash, Option<usize>, bool)> + '_ {
+        self.functions_info.iter().map(move |(hash, signature)| {
+            let (item, args) = match signature {
+                ContextSignature::Function { item, args, .. } => (item.clone(), *args),
+                ContextSignature::Instance {
+                    item, name, args, ..
+                } => (item.extended(name), *args),
+            };
+
+            (item, *hash, args, self.async_functions.contains(hash))
+        })
+    }
+
     /// Iterate over all available types.
     pub fn iter_types(&self) -> impl Iterator<Item = (Hash, &ContextTypeInfo)> {
         let mut it = self.types.iter();
@@ -410,6 +550,10 @@ impl Context {
             self.install_constant(&module, name, m)?;
         }
 
+        for (op, o) in &module.operators {
+            self.install_operator(&module, op, o)?;
+        }
+
         if let Some(unit_type) = &module.unit_type {
             self.install_unit_type(&module, unit_type)?;
         }
@@ -418,6 +562,10 @@ impl Context {
             self.install_internal_enum(module, internal_enum)?;
         }
 
+        for (key, conversion) in &module.conversions {
+            self.install_conversion(*key, conversion)?;
+        }
+
         for (key, inst) in &module.associated_functions {
             self.install_associated_function(
                 key.type_hash,
@@ -499,6 +647,30 @@ impl Context {
         Ok(())
     }
 
+    /// Install a custom binary operator and check for duplicates.
+    fn install_operator(
+        &mut self,
+        module: &Module,
+        op: &str,
+        o: &ModuleOperator,
+    ) -> Result<(), ContextError> {
+        if self.operators.contains_key(op) {
+            return Err(ContextError::ConflictingOperator { op: op.into() });
+        }
+
+        let hash = Hash::type_hash(&module.item.join(&[op]));
+
+        self.operators.insert(
+            op.into(),
+            ContextOperator {
+                precedence: o.precedence,
+                hash,
+            },
+        );
+
+        Ok(())
+    }
+
     /// Install a function and check for duplicates.
     fn install_function(
         &mut self,
@@ -511,10 +683,15 @@ impl Context {
 
         let hash = Hash::type_hash(&item);
 
+        if self.constants.contains_key(&hash) {
+            return Err(ContextError::ConflictingFunctionName { name: item });
+        }
+
         let signature = ContextSignature::Function {
             type_hash: hash,
             item: item.clone(),
             args: f.args,
+            arg_names: f.arg_names.clone(),
         };
 
         if let Some(old) = self.functions_info.insert(hash, signature) {
@@ -530,6 +707,15 @@ impl Context {
         );
 
         self.functions.insert(hash, f.handler.clone());
+
+        if f.resumable {
+            self.resumable.insert(hash);
+        }
+
+        if f.is_async {
+            self.async_functions.insert(hash);
+        }
+
         self.meta.insert(
             item.clone(),
             CompileMeta {
@@ -575,6 +761,10 @@ impl Context {
 
         let hash = Hash::type_hash(&item);
 
+        if self.functions.contains_key(&hash) || self.constants.contains_key(&hash) {
+            return Err(ContextError::ConflictingConstantName { name: item });
+        }
+
         self.constants.insert(hash, v.clone());
 
         self.meta.insert(
@@ -645,6 +835,37 @@ impl Context {
         );
 
         self.functions.insert(hash, assoc.handler.clone());
+
+        if assoc.is_async {
+            self.async_functions.insert(hash);
+        }
+        Ok(())
+    }
+
+    /// Install a conversion between two types.
+    fn install_conversion(
+        &mut self,
+        key: (Hash, Hash),
+        conversion: &ModuleConversion,
+    ) -> Result<(), ContextError> {
+        let (from, to) = key;
+
+        for (type_hash, type_info) in &[(from, &conversion.from), (to, &conversion.to)] {
+            if !self.types_rev.contains_key(type_hash) {
+                return Err(ContextError::MissingConversionType {
+                    type_info: (*type_info).clone(),
+                });
+            }
+        }
+
+        if self.conversions.contains_key(&key) {
+            return Err(ContextError::ConflictingConversion {
+                from: conversion.from.clone(),
+                to: conversion.to.clone(),
+            });
+        }
+
+        self.conversions.insert(key, conversion.handler.clone());
         Ok(())
     }
 
@@ -740,6 +961,7 @@ impl Context {
                 type_hash: variant.type_hash,
                 item,
                 args: Some(variant.args),
+                arg_names: None,
             };
 
             if let Some(old) = self.functions_info.insert(hash, signature) {
@@ -802,6 +1024,7 @@ impl Context {
             type_hash,
             item,
             args: Some(args),
+            arg_names: None,
         };
 
         if let Some(old) = self.functions_info.insert(hash, signature) {
@@ -3,10 +3,11 @@
 use crate::collections::HashMap;
 use crate::{DebugLabel, Hash, Item, Span};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Debug information about a unit.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DebugInfo {
     /// Debug information on each instruction.
     pub instructions: Vec<DebugInst>,
@@ -14,6 +15,10 @@ pub struct DebugInfo {
     pub functions: HashMap<Hash, DebugSignature>,
     /// Reverse lookup of a function.
     pub functions_rev: HashMap<usize, Hash>,
+    /// Variables declared at a given instruction pointer.
+    pub variables: BTreeMap<usize, Vec<DebugVariable>>,
+    /// The text of sources by id, if they were embedded when compiling.
+    pub sources: BTreeMap<usize, String>,
 }
 
 impl DebugInfo {
@@ -22,16 +27,58 @@ impl DebugInfo {
         self.instructions.get(ip)
     }
 
+    /// Get the text of the source with the given id covered by the span, if
+    /// the source was embedded when compiling.
+    pub fn source_snippet(&self, source_id: usize, span: Span) -> Option<&str> {
+        self.sources.get(&source_id)?.get(span.range())
+    }
+
     /// Get the function corresponding to the given instruction pointer.
     pub fn function_at(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
         let hash = *self.functions_rev.get(&ip)?;
         let signature = self.functions.get(&hash)?;
         Some((hash, signature))
     }
+
+    /// Get the function which contains the given instruction pointer.
+    pub fn function_containing(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
+        let (_, hash) = self
+            .functions_rev
+            .iter()
+            .filter(|(offset, _)| **offset <= ip)
+            .max_by_key(|(offset, _)| **offset)?;
+
+        let signature = self.functions.get(hash)?;
+        Some((*hash, signature))
+    }
+
+    /// Get the names of the variables which are in scope at the given
+    /// instruction pointer, for the function starting at `entry`.
+    ///
+    /// The returned vector is indexed by stack offset relative to the bottom
+    /// of the function's stack frame, and contains `None` for slots which do
+    /// not correspond to a named variable.
+    pub fn variables_at(&self, entry: usize, ip: usize) -> Vec<Option<&str>> {
+        let mut names = Vec::new();
+
+        if ip < entry {
+            return names;
+        }
+
+        for variable in self.variables.range(entry..=ip).flat_map(|(_, v)| v) {
+            if names.len() <= variable.offset {
+                names.resize(variable.offset + 1, None);
+            }
+
+            names[variable.offset] = Some(variable.name.as_str());
+        }
+
+        names
+    }
 }
 
 /// Debug information for every instruction.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugInst {
     /// The file by id the instruction belongs to.
     pub source_id: usize,
@@ -43,8 +90,18 @@ pub struct DebugInst {
     pub label: Option<DebugLabel>,
 }
 
+/// Debug information on a declared variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebugVariable {
+    /// The name of the variable.
+    pub name: String,
+    /// The stack offset of the variable, relative to the bottom of the stack
+    /// frame of the function it belongs to.
+    pub offset: usize,
+}
+
 /// Debug information on function arguments.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DebugArgs {
     /// An empty, with not arguments.
     EmptyArgs,
@@ -52,10 +109,22 @@ pub enum DebugArgs {
     TupleArgs(usize),
     /// A collection of named arguments.
     Named(Vec<String>),
+    /// A closure, with the given named arguments.
+    Closure(Vec<String>),
+}
+
+impl DebugArgs {
+    /// Get the names of the arguments, if they are known.
+    pub fn names(&self) -> Option<&[String]> {
+        match self {
+            Self::Named(args) | Self::Closure(args) => Some(args),
+            _ => None,
+        }
+    }
 }
 
 /// A description of a function signature.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugSignature {
     /// The path of the function.
     pub path: Item,
@@ -95,7 +164,7 @@ impl fmt::Display for DebugSignature {
 
                 write!(fmt, ")")?;
             }
-            DebugArgs::Named(args) => {
+            DebugArgs::Named(args) | DebugArgs::Closure(args) => {
                 write!(fmt, "{}(", self.path)?;
 
                 let mut it = args.iter();
@@ -0,0 +1,176 @@
+//! Output limits for debug formatting values.
+//!
+//! Debug formatting a large nested value, like through `dbg` or `{:?}` in a
+//! format string, can produce a lot of output. This module allows for limiting
+//! how deeply nested collections are printed and how many elements are printed
+//! for each collection, by wrapping the call which formats values in [with].
+//!
+//! Elements which are left out are replaced with a marker indicating how many
+//! of them there were, like `[1, 2, ... 3 more]` or `Point { x: 1, ... 2 more
+//! }`. A collection which is nested too deeply is printed with all of its
+//! elements left out, like `[... 5 more]`.
+//!
+//! Scripts can set the limits through `std::io::set_debug_limits`. These apply
+//! for as long as the virtual machine which set them is executing, and are
+//! restored once it returns to the caller.
+//!
+//! By default nothing is left out.
+
+use std::cell::Cell;
+use std::fmt;
+
+/// Limits for debug formatting values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugLimits {
+    /// The maximum number of nested collections to print elements for.
+    pub max_depth: usize,
+    /// The maximum number of elements to print for each collection.
+    pub max_elements: usize,
+}
+
+impl DebugLimits {
+    /// Limits which don't leave anything out.
+    pub const fn unlimited() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_elements: usize::MAX,
+        }
+    }
+}
+
+impl Default for DebugLimits {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+thread_local! {
+    static LIMITS: Cell<DebugLimits> = const { Cell::new(DebugLimits::unlimited()) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Set the debug formatting limits of the current thread, returning the
+/// previous limits.
+///
+/// If called inside of [with], the limits are restored once it returns.
+pub fn set(limits: DebugLimits) -> DebugLimits {
+    LIMITS.with(|tls| tls.replace(limits))
+}
+
+/// Call the given function with the specified debug formatting limits.
+pub fn with<T>(limits: DebugLimits, f: impl FnOnce() -> T) -> T {
+    LIMITS.with(|tls| {
+        let _guard = LimitsGuard(tls.get());
+        tls.set(limits);
+        f()
+    })
+}
+
+/// Enter a collection with `len` elements which is about to be debug
+/// formatted, returning a guard which indicates how many of its elements to
+/// print and exits the collection when dropped.
+pub(crate) fn enter(len: usize) -> Entries {
+    let limits = LIMITS.with(Cell::get);
+
+    let shown = DEPTH.with(|tls| {
+        let depth = tls.get();
+        tls.set(depth + 1);
+
+        if depth >= limits.max_depth {
+            0
+        } else {
+            usize::min(len, limits.max_elements)
+        }
+    });
+
+    Entries {
+        shown,
+        omitted: len - shown,
+    }
+}
+
+/// Guard returned by [enter].
+pub(crate) struct Entries {
+    shown: usize,
+    omitted: usize,
+}
+
+impl Entries {
+    /// The number of elements to print.
+    pub(crate) fn shown(&self) -> usize {
+        self.shown
+    }
+
+    /// A marker to print in place of the elements which were left out, if
+    /// any.
+    pub(crate) fn omitted(&self) -> Option<Omitted> {
+        if self.omitted > 0 {
+            Some(Omitted(self.omitted))
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for Entries {
+    fn drop(&mut self) {
+        DEPTH.with(|tls| {
+            tls.set(tls.get() - 1);
+        });
+    }
+}
+
+/// Marker for elements which were left out when debug formatting a
+/// collection.
+pub(crate) struct Omitted(usize);
+
+impl fmt::Debug for Omitted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "... {} more", self.0)
+    }
+}
+
+/// Guard returned by [Scope::enter].
+pub(crate) struct Scope {
+    vm: Option<DebugLimits>,
+    /// Restores the limits of the caller when dropped.
+    outer: LimitsGuard,
+}
+
+impl Scope {
+    /// Apply the limits set by a virtual machine, if any, until [Scope::exit]
+    /// is called.
+    pub(crate) fn enter(vm: Option<DebugLimits>) -> Self {
+        LIMITS.with(|tls| {
+            let guard = LimitsGuard(tls.get());
+
+            if let Some(limits) = vm {
+                tls.set(limits);
+            }
+
+            Self { vm, outer: guard }
+        })
+    }
+
+    /// Restore the limits of the caller, returning the limits to apply the
+    /// next time the virtual machine is entered.
+    pub(crate) fn exit(self) -> Option<DebugLimits> {
+        let limits = LIMITS.with(Cell::get);
+
+        if self.vm.is_some() || limits != self.outer.0 {
+            Some(limits)
+        } else {
+            None
+        }
+    }
+}
+
+struct LimitsGuard(DebugLimits);
+
+impl Drop for LimitsGuard {
+    fn drop(&mut self) {
+        LIMITS.with(|tls| {
+            tls.set(self.0);
+        });
+    }
+}
@@ -0,0 +1,68 @@
+//! Nesting limits for Runestick.
+//!
+//! This module contains methods which allows for limiting how deeply nested
+//! values can be when they are converted using [FromValue][crate::FromValue],
+//! so that untrusted data can't overflow the native stack.
+//!
+//! By default the limit is [DEFAULT_MAX_DEPTH], but it can be changed by
+//! wrapping your function call in [with].
+
+use crate::{VmError, VmErrorKind};
+use std::cell::Cell;
+
+/// The default maximum nesting depth.
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+thread_local! {
+    static MAX_DEPTH: Cell<usize> = const { Cell::new(DEFAULT_MAX_DEPTH) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Call the given function with the specified maximum nesting depth.
+pub fn with<T>(max: usize, f: impl FnOnce() -> T) -> T {
+    MAX_DEPTH.with(|tls| {
+        let _guard = MaxDepthGuard(tls.get());
+        tls.set(max);
+        f()
+    })
+}
+
+/// Enter one level of nesting, returning a guard which exits it when dropped.
+///
+/// Errors with [VmErrorKind::TooDeep] if the maximum depth is exceeded.
+pub(crate) fn enter() -> Result<DepthGuard, VmError> {
+    let max = MAX_DEPTH.with(Cell::get);
+
+    DEPTH.with(|tls| {
+        let depth = tls.get();
+
+        if depth >= max {
+            return Err(VmError::from(VmErrorKind::TooDeep { max }));
+        }
+
+        tls.set(depth + 1);
+        Ok(DepthGuard(()))
+    })
+}
+
+/// Guard returned by [enter].
+pub(crate) struct DepthGuard(());
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|tls| {
+            tls.set(tls.get() - 1);
+        });
+    }
+}
+
+#[repr(transparent)]
+struct MaxDepthGuard(usize);
+
+impl Drop for MaxDepthGuard {
+    fn drop(&mut self) {
+        MAX_DEPTH.with(|tls| {
+            tls.set(self.0);
+        });
+    }
+}
@@ -5,9 +5,12 @@
 //! * [super::Value::string_debug]
 //! * [super::Value::into_type_name]
 //!
+//! It also tracks the location of the instruction which called the currently
+//! executing native function, see [call_site].
+//!
 //! See the corresponding function for documentation.
 
-use crate::{RuntimeContext, Unit, VmError, VmErrorKind};
+use crate::{Location, RuntimeContext, Unit, VmError, VmErrorKind};
 use std::cell::Cell;
 use std::ptr;
 use std::sync::Arc;
@@ -20,7 +23,7 @@ where
     F: FnOnce(&Arc<RuntimeContext>, &Arc<Unit>) -> Result<T, VmError>,
 {
     let env = ENV.with(|env| env.get());
-    let Env { context, unit } = env;
+    let Env { context, unit, .. } = env;
 
     if context.is_null() || unit.is_null() {
         return Err(VmError::from(VmErrorKind::MissingInterfaceEnvironment));
@@ -32,6 +35,73 @@ where
     c(unsafe { &*context }, unsafe { &*unit })
 }
 
+/// Get the location of the instruction which called the currently executing
+/// native function.
+///
+/// This returns `None` if called outside of a native function called from a
+/// virtual machine, or if the unit being executed doesn't have debug info.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::Module;
+///
+/// fn deprecated() {
+///     if let Some(location) = runestick::call_site() {
+///         println!("deprecated function called at {:?}", location);
+///     }
+/// }
+///
+/// # fn main() -> runestick::Result<()> {
+/// let mut module = Module::new();
+/// module.function(&["deprecated"], deprecated)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn call_site() -> Option<Location> {
+    let env = ENV.with(|env| env.get());
+    let ip = env.ip?;
+
+    if env.unit.is_null() {
+        return None;
+    }
+
+    // Safety: see [with].
+    let unit = unsafe { &*env.unit };
+    let inst = unit.debug_info()?.instruction_at(ip)?;
+    Some(Location::new(inst.source_id, inst.span))
+}
+
+/// Set the instruction pointer of the native function about to be called, so
+/// that it's available through [call_site] until the returned guard is
+/// dropped.
+#[inline]
+pub(crate) fn set_ip(ip: usize) -> IpGuard {
+    ENV.with(|env| {
+        let mut current = env.get();
+        let old = current.ip.replace(ip);
+        env.set(current);
+        IpGuard { old }
+    })
+}
+
+/// Guard returned by [set_ip], which restores the previous instruction
+/// pointer once the native function has returned.
+#[must_use]
+pub(crate) struct IpGuard {
+    old: Option<usize>,
+}
+
+impl Drop for IpGuard {
+    fn drop(&mut self) {
+        ENV.with(|env| {
+            let mut current = env.get();
+            current.ip = self.old;
+            env.set(current);
+        })
+    }
+}
+
 pub(crate) struct Guard {
     old: Env,
 }
@@ -43,7 +113,13 @@ impl Guard {
     ///
     /// The returned guard must be dropped before the pointed to elements are.
     pub(crate) fn new(context: *const Arc<RuntimeContext>, unit: *const Arc<Unit>) -> Guard {
-        let old = ENV.with(|e| e.replace(Env { context, unit }));
+        let old = ENV.with(|e| {
+            e.replace(Env {
+                context,
+                unit,
+                ip: None,
+            })
+        });
 
         Guard { old }
     }
@@ -59,6 +135,8 @@ impl Drop for Guard {
 struct Env {
     context: *const Arc<RuntimeContext>,
     unit: *const Arc<Unit>,
+    /// The instruction pointer of the current native function call.
+    ip: Option<usize>,
 }
 
 impl Env {
@@ -66,6 +144,7 @@ impl Env {
         Self {
             context: ptr::null(),
             unit: ptr::null(),
+            ip: None,
         }
     }
 }
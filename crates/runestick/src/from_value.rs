@@ -1,4 +1,6 @@
-use crate::{Any, AnyObj, Mut, RawMut, RawRef, Ref, Shared, StaticString, Value, VmError};
+use crate::{
+    Any, AnyObj, Mut, RawMut, RawRef, Ref, Shared, StaticString, Value, VmError, VmErrorKind,
+};
 use std::sync::Arc;
 
 /// Trait for converting from a value.
@@ -51,7 +53,7 @@ where
     T: Any,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        Ok(value.into_any()?.take_downcast()?)
+        Ok(into_any::<T>(value)?.take_downcast()?)
     }
 }
 
@@ -60,7 +62,7 @@ where
     T: Any,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        Ok(value.into_any()?.downcast_into_mut()?)
+        Ok(into_any::<T>(value)?.downcast_into_mut()?)
     }
 }
 
@@ -69,7 +71,19 @@ where
     T: Any,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        Ok(value.into_any()?.downcast_into_ref()?)
+        Ok(into_any::<T>(value)?.downcast_into_ref()?)
+    }
+}
+
+/// Coerce the value into an external value, reporting `T` as the expected
+/// type if it isn't one.
+fn into_any<T>(value: Value) -> Result<Shared<AnyObj>, VmError>
+where
+    T: Any,
+{
+    match value {
+        Value::Any(any) => Ok(any),
+        actual => Err(VmError::expected_external::<T>(actual.type_info()?)),
     }
 }
 
@@ -108,6 +122,8 @@ where
     T: FromValue,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
+        let _guard = crate::depth::enter()?;
+
         Ok(match value.into_option()?.take()? {
             Some(some) => Some(T::from_value(some)?),
             None => None,
@@ -303,6 +319,8 @@ where
     E: FromValue,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
+        let _guard = crate::depth::enter()?;
+
         Ok(match value.into_result()?.take()? {
             Ok(ok) => Ok(T::from_value(ok)?),
             Err(err) => Err(E::from_value(err)?),
@@ -395,9 +413,24 @@ impl FromValue for f64 {
     }
 }
 
+/// Floats are stored as `f64` in the virtual machine, so converting into an
+/// `f32` rounds to the nearest representable value.
+///
+/// Finite values which are out of range for an `f32` produce an error instead
+/// of silently turning into infinity. NaN and infinity are preserved as-is.
 impl FromValue for f32 {
     fn from_value(value: Value) -> Result<Self, VmError> {
-        Ok(value.into_float()? as f32)
+        let float = value.into_float()?;
+        let number = float as f32;
+
+        if float.is_finite() && !number.is_finite() {
+            return Err(VmError::from(VmErrorKind::ValueToFloatCoercionError {
+                from: float,
+                to: std::any::type_name::<Self>(),
+            }));
+        }
+
+        Ok(number)
     }
 }
 
@@ -410,6 +443,7 @@ macro_rules! impl_map {
             T: $crate::FromValue,
         {
             fn from_value(value: $crate::Value) -> Result<Self, $crate::VmError> {
+                let _guard = $crate::depth::enter()?;
                 let object = value.into_object()?;
                 let object = object.take()?;
 
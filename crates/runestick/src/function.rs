@@ -41,7 +41,9 @@ where
                 let arg_count = args.count();
                 let mut stack = Stack::with_capacity(arg_count);
                 args.into_stack(&mut stack)?;
-                (handler.handler)(&mut stack, arg_count)?;
+                handler
+                    .context
+                    .call_handler(&handler.handler, &mut stack, arg_count)?;
                 stack.pop()?
             }
             Inner::FnOffset(fn_offset) => fn_offset.call(args, ())?,
@@ -109,12 +111,14 @@ where
     pub(crate) fn call_with_vm(&self, vm: &mut Vm, args: usize) -> Result<Option<VmHalt>, VmError> {
         let reason = match &self.inner {
             Inner::FnHandler(handler) => {
-                (handler.handler)(&mut vm.stack, args)?;
+                let _ip = crate::env::set_ip(vm.ip());
+                vm.context
+                    .call_handler(&handler.handler, &mut vm.stack, args)?;
                 None
             }
             Inner::FnOffset(fn_offset) => {
                 if let Some(vm_call) = fn_offset.call_with_vm(vm, args, ())? {
-                    return Ok(Some(VmHalt::VmCall(vm_call)));
+                    return Ok(Some(VmHalt::VmCall(Box::new(vm_call))));
                 }
 
                 None
@@ -125,7 +129,7 @@ where
                     args,
                     (Tuple::from(closure.environment.clone()),),
                 )? {
-                    return Ok(Some(VmHalt::VmCall(vm_call)));
+                    return Ok(Some(VmHalt::VmCall(Box::new(vm_call))));
                 }
 
                 None
@@ -164,9 +168,17 @@ where
     }
 
     /// Create a function pointer from a handler.
-    pub(crate) fn from_handler(handler: Arc<Handler>, hash: Hash) -> Self {
+    pub(crate) fn from_handler(
+        context: Arc<RuntimeContext>,
+        handler: Arc<Handler>,
+        hash: Hash,
+    ) -> Self {
         Self {
-            inner: Inner::FnHandler(FnHandler { handler, hash }),
+            inner: Inner::FnHandler(FnHandler {
+                context,
+                handler,
+                hash,
+            }),
         }
     }
 
@@ -359,6 +371,9 @@ enum Inner<V> {
 }
 
 struct FnHandler {
+    /// The context the handler was looked up in, which decides how it's
+    /// called.
+    context: Arc<RuntimeContext>,
     /// The function handler.
     handler: Arc<Handler>,
     /// Hash for the function type
@@ -0,0 +1,259 @@
+use crate::{
+    Any, Hash, InstallWith, Named, RawRef, RawStr, TypeInfo, TypeOf, UnsafeFromValue, Value,
+    VmError, VmErrorKind,
+};
+use std::fmt;
+use std::marker;
+
+/// An opaque handle to a value of type `T` which is stored on the host side
+/// in a [Handles] collection.
+///
+/// A handle can be passed into a script, which can pass it back to native
+/// functions. Since no fields or constructors are registered for it, a script
+/// can neither inspect nor forge a handle.
+///
+/// Native functions should take a handle by reference, since taking it by
+/// value moves it out of the script.
+///
+/// # Examples
+///
+/// Native functions have to be `Copy`, so the collection of handles is shared
+/// with them through a `'static` reference.
+///
+/// ```rust
+/// use runestick::{Handle, Handles, Module, VmError};
+/// use std::sync::Mutex;
+///
+/// struct Connection {
+///     name: String,
+/// }
+///
+/// # fn main() -> runestick::Result<()> {
+/// let handles: &'static Mutex<Handles<Connection>> =
+///     Box::leak(Box::new(Mutex::new(Handles::new())));
+///
+/// let mut module = Module::new();
+/// module.ty::<Handle<Connection>>()?;
+///
+/// module.function(&["name"], move |handle: &Handle<Connection>| {
+///     let handles = handles.lock().unwrap();
+///     Ok::<_, VmError>(handles.get(handle)?.name.clone())
+/// })?;
+///
+/// let handle = handles.lock().unwrap().insert(Connection {
+///     name: String::from("primary"),
+/// });
+///
+/// assert_eq!(handles.lock().unwrap().get(&handle)?.name, "primary");
+/// # Ok(()) }
+/// ```
+pub struct Handle<T> {
+    index: usize,
+    generation: usize,
+    _marker: marker::PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle")
+            .field("index", &self.index)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+impl<T> Named for Handle<T> {
+    const BASE_NAME: RawStr = RawStr::from_str("Handle");
+}
+
+impl<T> InstallWith for Handle<T> {}
+
+impl<T> Any for Handle<T>
+where
+    T: 'static,
+{
+    fn type_hash() -> Hash {
+        Hash::from_type_id(std::any::TypeId::of::<Self>())
+    }
+}
+
+impl<T> TypeOf for Handle<T>
+where
+    T: 'static,
+{
+    fn type_hash() -> Hash {
+        <Self as Any>::type_hash()
+    }
+
+    fn type_info() -> TypeInfo {
+        TypeInfo::Any(RawStr::from_str(std::any::type_name::<Self>()))
+    }
+}
+
+impl<T> UnsafeFromValue for &Handle<T>
+where
+    T: 'static,
+{
+    type Output = *const Handle<T>;
+    type Guard = RawRef;
+
+    fn from_value(value: Value) -> Result<(Self::Output, Self::Guard), VmError> {
+        value.into_any_ptr()
+    }
+
+    unsafe fn unsafe_coerce(output: Self::Output) -> Self {
+        &*output
+    }
+}
+
+/// A slot in a [Handles] collection.
+struct Slot<T> {
+    /// Incremented every time the value in the slot is removed, so that old
+    /// handles to it become stale.
+    generation: usize,
+    value: Option<T>,
+}
+
+/// A host-side collection of values which are referenced through [Handle]s.
+///
+/// Removing a value makes all handles to it stale, and using a stale handle
+/// results in a [VmErrorKind::StaleHandle] error even if the slot it refers to
+/// has been reused.
+pub struct Handles<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T> Handles<T> {
+    /// Construct a new empty collection of handles.
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Get the number of live values in the collection.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if the collection has no live values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert a value and get a handle to it.
+    pub fn insert(&mut self, value: T) -> Handle<T> {
+        self.len += 1;
+
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.slots[index].value = Some(value);
+                index
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: Some(value),
+                });
+
+                self.slots.len() - 1
+            }
+        };
+
+        Handle {
+            index,
+            generation: self.slots[index].generation,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Get the value the given handle refers to.
+    pub fn get(&self, handle: &Handle<T>) -> Result<&T, VmError> {
+        match self.slots.get(handle.index) {
+            Some(Slot {
+                generation,
+                value: Some(value),
+            }) if *generation == handle.generation => Ok(value),
+            _ => Err(VmError::from(VmErrorKind::StaleHandle {
+                index: handle.index,
+            })),
+        }
+    }
+
+    /// Get the value the given handle refers to mutably.
+    pub fn get_mut(&mut self, handle: &Handle<T>) -> Result<&mut T, VmError> {
+        match self.slots.get_mut(handle.index) {
+            Some(Slot {
+                generation,
+                value: Some(value),
+            }) if *generation == handle.generation => Ok(value),
+            _ => Err(VmError::from(VmErrorKind::StaleHandle {
+                index: handle.index,
+            })),
+        }
+    }
+
+    /// Remove the value the given handle refers to, making the handle and any
+    /// copies of it stale.
+    pub fn remove(&mut self, handle: &Handle<T>) -> Result<T, VmError> {
+        let slot = match self.slots.get_mut(handle.index) {
+            Some(slot) if slot.generation == handle.generation => slot,
+            _ => {
+                return Err(VmError::from(VmErrorKind::StaleHandle {
+                    index: handle.index,
+                }))
+            }
+        };
+
+        let value = slot.value.take().ok_or_else(|| VmErrorKind::StaleHandle {
+            index: handle.index,
+        })?;
+
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(handle.index);
+        self.len -= 1;
+        Ok(value)
+    }
+}
+
+impl<T> Default for Handles<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Handles<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.slots
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, slot)| slot.value.as_ref().map(|value| (index, value))),
+            )
+            .finish()
+    }
+}
@@ -4,7 +4,6 @@ use std::any;
 use std::fmt;
 use std::hash;
 use std::hash::{BuildHasher as _, BuildHasherDefault, Hash as _, Hasher as _};
-use std::mem;
 use twox_hash::XxHash64;
 
 const SEP: usize = 0x7f;
@@ -39,6 +38,17 @@ impl Hash {
         path.into_type_hash()
     }
 
+    /// Construct a hash from the path of the given item.
+    ///
+    /// Unlike [Hash::from_type_id], which depends on the binary it's used in,
+    /// this only depends on the components of the item. So it's the same
+    /// across processes, which is what allows a compiled unit to be stored and
+    /// loaded again later. It is identical to [Hash::type_hash] for the same
+    /// item.
+    pub fn of_type_name(item: &Item) -> Self {
+        Self::path_hash(TYPE, item)
+    }
+
     /// Construct a hash from the given type id.
     pub fn from_any<T>() -> Self
     where
@@ -48,10 +58,11 @@ impl Hash {
     }
 
     /// Construct a hash from a type id.
+    ///
+    /// The type id is hashed with the same fixed-key hasher as other hashes,
+    /// since it isn't guaranteed to fit in a hash.
     pub fn from_type_id(type_id: any::TypeId) -> Self {
-        // Safety: a type id is exactly a 64-bit unsigned integer.
-        // And has an identical bit pattern to `Hash`.
-        unsafe { mem::transmute(type_id) }
+        Self::of(type_id)
     }
 
     /// Construct a hash to an instance function, where the instance is a
@@ -155,6 +166,39 @@ impl IntoTypeHash for Hash {
     }
 }
 
+/// A path to an item which is written as a string, like `"math::add"`, where
+/// components are separated by `::`.
+///
+/// # Examples
+///
+/// ```rust
+/// use runestick::{Hash, ItemPath};
+///
+/// assert_eq!(
+///     Hash::type_hash(ItemPath::new("math::add")),
+///     Hash::type_hash(&["math", "add"]),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ItemPath<'a>(&'a str);
+
+impl<'a> ItemPath<'a> {
+    /// Construct a new item path from a string.
+    pub fn new(path: &'a str) -> Self {
+        Self(path)
+    }
+}
+
+impl IntoTypeHash for ItemPath<'_> {
+    fn into_type_hash(self) -> Hash {
+        Hash::path_hash(TYPE, self.0.split("::"))
+    }
+
+    fn into_item(self) -> Item {
+        Item::with_item(self.0.split("::"))
+    }
+}
+
 impl<I> IntoTypeHash for I
 where
     I: Copy + IntoIterator,
@@ -0,0 +1,120 @@
+use crate::context::Handler;
+use crate::{Call, Hash};
+use std::fmt;
+use std::sync::Arc;
+use std::vec;
+
+/// Per call-site cache of resolved instance functions.
+///
+/// Each [CallInstance][crate::Inst::CallInstance] instruction gets a slot
+/// indexed by its instruction pointer, which stores the type hash of the last
+/// instance it was called on together with the function it resolved to. A
+/// repeated call on the same type can then skip looking up the function.
+///
+/// Slots are allocated lazily, so the cache only grows up to the highest call
+/// site which has been visited.
+#[derive(Clone)]
+pub struct InlineCache {
+    /// Cached entries, indexed by instruction pointer.
+    entries: vec::Vec<Option<Entry>>,
+    /// Number of calls resolved through the cache.
+    hits: usize,
+    /// Number of calls which had to fall back to a full lookup.
+    misses: usize,
+}
+
+impl InlineCache {
+    /// Construct a new empty cache.
+    pub const fn new() -> Self {
+        Self {
+            entries: vec::Vec::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Number of instance function calls which were resolved through the
+    /// cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of instance function calls which had to perform a full lookup.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Clear all cached entries and reset the counters.
+    ///
+    /// This must be done whenever the functions which entries resolved to may
+    /// have changed, like when a function in the unit is replaced.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Get the cached function for the call site at `ip`, if it was last
+    /// called on an instance with the type `type_hash`.
+    #[inline]
+    pub(crate) fn get(&mut self, ip: usize, type_hash: Hash) -> Option<&CachedFn> {
+        match self.entries.get(ip) {
+            Some(Some(entry)) if entry.type_hash == type_hash => {
+                self.hits += 1;
+                Some(&entry.target)
+            }
+            _ => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Store the resolved function for the call site at `ip`.
+    pub(crate) fn insert(&mut self, ip: usize, type_hash: Hash, target: CachedFn) {
+        if ip >= self.entries.len() {
+            self.entries.resize_with(ip + 1, || None);
+        }
+
+        self.entries[ip] = Some(Entry { type_hash, target });
+    }
+}
+
+impl Default for InlineCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for InlineCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InlineCache")
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+#[derive(Clone)]
+struct Entry {
+    /// The type hash of the instance the call site was last called on.
+    type_hash: Hash,
+    /// The resolved function.
+    target: CachedFn,
+}
+
+/// A function resolved from an instance function call.
+#[derive(Clone)]
+pub(crate) enum CachedFn {
+    /// A function in the unit.
+    Offset {
+        /// Offset of the function.
+        offset: usize,
+        /// The way the function is called.
+        call: Call,
+        /// The number of arguments the function takes.
+        args: usize,
+    },
+    /// A native function from the context.
+    Handler(Arc<Handler>),
+}
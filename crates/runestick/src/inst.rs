@@ -13,6 +13,8 @@ pub enum PanicReason {
     UnmatchedPattern,
     /// Tried to poll a future that has already been completed.
     FutureCompleted,
+    /// Tried to divide by zero.
+    DivideByZero,
 }
 
 impl PanicReason {
@@ -22,6 +24,7 @@ impl PanicReason {
             Self::NotImplemented => "not implemented",
             Self::UnmatchedPattern => "unmatched pattern",
             Self::FutureCompleted => "future completed",
+            Self::DivideByZero => "divide by zero",
         }
     }
 }
@@ -34,6 +37,7 @@ impl fmt::Display for PanicReason {
             Self::FutureCompleted => {
                 write!(fmt, "tried to poll future that has already been completed")?
             }
+            Self::DivideByZero => write!(fmt, "attempt to divide by zero")?,
         }
 
         Ok(())
@@ -80,11 +84,21 @@ impl fmt::Display for TypeCheck {
 }
 
 /// An operation in the stack-based virtual machine.
+///
+/// Jumps are encoded as offsets relative to the instruction performing them,
+/// and functions are always called by hash. Moving the instructions of a
+/// function to a different position therefore only requires updating the
+/// offset in its [UnitFn::Offset][crate::UnitFn::Offset] entry.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Inst {
     /// Not operator. Takes a boolean from the top of the stack  and inverts its
     /// logical value.
     ///
+    /// If the value is an integer, its bits are inverted instead like with
+    /// [Inst::BitNot]. This is used when the type of the operand isn't known
+    /// during compilation, otherwise [Inst::BoolNot] or [Inst::BitNot] is
+    /// used.
+    ///
     /// # Operation
     ///
     /// ```text
@@ -92,6 +106,26 @@ pub enum Inst {
     /// => <bool>
     /// ```
     Not,
+    /// Boolean not operator. Takes a boolean from the top of the stack and
+    /// inverts its logical value.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <bool>
+    /// => <bool>
+    /// ```
+    BoolNot,
+    /// Bitwise not operator. Takes an integer from the top of the stack and
+    /// inverts its bits.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <integer>
+    /// => <integer>
+    /// ```
+    BitNot,
     /// Negate the numerical value on the stack.
     ///
     /// # Operation
@@ -382,13 +416,19 @@ pub enum Inst {
         /// The number of entries in the stack to pop.
         count: usize,
     },
-    /// Copy a variable from a location `offset` relative to the current call
-    /// frame.
+    /// Load a copy of the local variable in the given `slot`, which is
+    /// relative to the current call frame.
     ///
     /// A copy is very cheap. It simply means pushing a reference to the stack.
-    Copy {
-        /// Offset to copy value from.
-        offset: usize,
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// => <value>
+    /// ```
+    LoadLocal {
+        /// Slot to load the value from.
+        slot: usize,
     },
     /// Move a variable from a location `offset` relative to the current call
     /// frame.
@@ -416,11 +456,18 @@ pub enum Inst {
     /// => <value>
     /// ```
     Dup,
-    /// Replace a value at the offset relative from the top of the stack, with
-    /// the top of the stack.
-    Replace {
-        /// Offset to swap value from.
-        offset: usize,
+    /// Store the top of the stack in the local variable in the given `slot`,
+    /// which is relative to the current call frame.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => *noop*
+    /// ```
+    StoreLocal {
+        /// Slot to store the value in.
+        slot: usize,
     },
     /// Pop the current stack frame and restore the instruction pointer from it.
     ///
@@ -502,6 +549,8 @@ pub enum Inst {
     /// Construct a push a vector value onto the stack. The number of elements
     /// in the vector are determined by `count` and are popped from the stack.
     ///
+    /// The vector is allocated once, up front, with room for `count` elements.
+    ///
     /// # Operation
     ///
     /// ```text
@@ -512,6 +561,34 @@ pub enum Inst {
         /// The size of the vector.
         count: usize,
     },
+    /// Construct a push a vector onto the stack which contains `count` clones
+    /// of `value`.
+    ///
+    /// Containers like vectors, objects and strings are cloned for each
+    /// element, so that modifying one element doesn't affect the others.
+    ///
+    /// Errors if `count` is larger than [Vec::MAX_REPEAT][crate::Vec::MAX_REPEAT].
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// <count>
+    /// => <vec>
+    /// ```
+    VecRepeat,
+    /// Extend the vector below the top of the stack with the elements of
+    /// `iterable`, which is converted into an iterator using the
+    /// [INTO_ITER][crate::Protocol::INTO_ITER] protocol.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <vec>
+    /// <iterable>
+    /// => <vec>
+    /// ```
+    VecExtend,
     /// Construct a push a one-tuple value onto the stack.
     ///
     /// # Operation
@@ -559,6 +636,8 @@ pub enum Inst {
     /// Construct a push a tuple value onto the stack. The number of elements
     /// in the tuple are determined by `count` and are popped from the stack.
     ///
+    /// The tuple is allocated once, up front, with room for `count` elements.
+    ///
     /// # Operation
     ///
     /// ```text
@@ -784,6 +863,18 @@ pub enum Inst {
         /// The integer to test against.
         integer: i64,
     },
+    /// Test if the top of the stack is a specific float.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean>
+    /// ```
+    EqFloat {
+        /// The float to test against.
+        float: f64,
+    },
 
     /// Test if the top of the stack is a specific boolean.
     ///
@@ -809,6 +900,18 @@ pub enum Inst {
         /// The slot to test against.
         slot: usize,
     },
+    /// Compare the top of the stack against a static byte string slot.
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <value>
+    /// => <boolean>
+    /// ```
+    EqStaticBytes {
+        /// The slot to test against.
+        slot: usize,
+    },
     /// Test that the top of the stack is a tuple with the given length
     /// requirements.
     ///
@@ -985,6 +1088,12 @@ impl fmt::Display for Inst {
             Self::Not => {
                 write!(fmt, "not")?;
             }
+            Self::BoolNot => {
+                write!(fmt, "bool-not")?;
+            }
+            Self::BitNot => {
+                write!(fmt, "bit-not")?;
+            }
             Self::Neg => {
                 write!(fmt, "neg")?;
             }
@@ -1051,8 +1160,8 @@ impl fmt::Display for Inst {
             Self::Clean { count } => {
                 write!(fmt, "clean {}", count)?;
             }
-            Self::Copy { offset } => {
-                write!(fmt, "copy {}", offset)?;
+            Self::LoadLocal { slot } => {
+                write!(fmt, "load-local {}", slot)?;
             }
             Self::Move { offset } => {
                 write!(fmt, "move {}", offset)?;
@@ -1060,8 +1169,8 @@ impl fmt::Display for Inst {
             Self::Dup => {
                 write!(fmt, "dup")?;
             }
-            Self::Replace { offset } => {
-                write!(fmt, "replace {}", offset)?;
+            Self::StoreLocal { slot } => {
+                write!(fmt, "store-local {}", slot)?;
             }
             Self::Return => {
                 write!(fmt, "return")?;
@@ -1087,6 +1196,12 @@ impl fmt::Display for Inst {
             Self::Vec { count } => {
                 write!(fmt, "vec {}", count)?;
             }
+            Self::VecRepeat => {
+                write!(fmt, "vec-repeat")?;
+            }
+            Self::VecExtend => {
+                write!(fmt, "vec-extend")?;
+            }
             Self::Tuple1 { args: [a] } => {
                 write!(fmt, "tuple-1 {}", a)?;
             }
@@ -1162,12 +1277,18 @@ impl fmt::Display for Inst {
             Self::EqInteger { integer } => {
                 write!(fmt, "eq-integer {}", integer)?;
             }
+            Self::EqFloat { float } => {
+                write!(fmt, "eq-float {}", float)?;
+            }
             Self::EqBool { boolean } => {
-                write!(fmt, "eq-integer {}", boolean)?;
+                write!(fmt, "eq-bool {}", boolean)?;
             }
             Self::EqStaticString { slot } => {
                 write!(fmt, "eq-static-string {}", slot)?;
             }
+            Self::EqStaticBytes { slot } => {
+                write!(fmt, "eq-static-bytes {}", slot)?;
+            }
             Self::MatchSequence {
                 type_check,
                 len,
@@ -1272,6 +1393,9 @@ pub enum InstTarget {
     Field(usize),
     /// Target a tuple field.
     TupleField(usize),
+    /// Target an index, where both the indexed value and the index are
+    /// popped from the stack.
+    Index,
 }
 
 impl fmt::Display for InstTarget {
@@ -1280,6 +1404,7 @@ impl fmt::Display for InstTarget {
             Self::Offset(offset) => write!(f, "offset({})", offset),
             Self::Field(slot) => write!(f, "field({})", slot),
             Self::TupleField(slot) => write!(f, "tuple-field({})", slot),
+            Self::Index => write!(f, "index"),
         }
     }
 }
@@ -1295,8 +1420,20 @@ pub enum InstAssignOp {
     Mul,
     /// The division operation. `a / b`.
     Div,
+    /// The division operation. `a / b`.
+    ///
+    /// Unlike [InstAssignOp::Div] this panics on division by zero, instead of
+    /// raising an error.
+    DivPanic,
     /// The remainder operation. `a % b`.
     Rem,
+    /// The remainder operation. `a % b`.
+    ///
+    /// Unlike [InstAssignOp::Rem] this panics on division by zero, instead of
+    /// raising an error.
+    RemPanic,
+    /// The exponent operation. `a ** b`.
+    Pow,
     /// The bitwise and operation. `a & b`.
     BitAnd,
     /// The bitwise xor operation. `a ^ b`.
@@ -1324,9 +1461,18 @@ impl fmt::Display for InstAssignOp {
             Self::Div => {
                 write!(f, "/")?;
             }
+            Self::DivPanic => {
+                write!(f, "/")?;
+            }
             Self::Rem => {
                 write!(f, "%")?;
             }
+            Self::RemPanic => {
+                write!(f, "%")?;
+            }
+            Self::Pow => {
+                write!(f, "**")?;
+            }
             Self::BitAnd => {
                 write!(f, "&")?;
             }
@@ -1359,8 +1505,20 @@ pub enum InstOp {
     Mul,
     /// The division operation. `a / b`.
     Div,
+    /// The division operation. `a / b`.
+    ///
+    /// Unlike [InstOp::Div] this panics on division by zero, instead of
+    /// raising an error.
+    DivPanic,
     /// The remainder operation. `a % b`.
     Rem,
+    /// The remainder operation. `a % b`.
+    ///
+    /// Unlike [InstOp::Rem] this panics on division by zero, instead of
+    /// raising an error.
+    RemPanic,
+    /// The exponent operation. `a ** b`.
+    Pow,
     /// The bitwise and operation. `a & b`.
     BitAnd,
     /// The bitwise xor operation. `a ^ b`.
@@ -1427,6 +1585,18 @@ pub enum InstOp {
     /// => <boolean>
     /// ```
     IsNot,
+    /// Convert the second item on the stack into the type on the top of the
+    /// stack, using a conversion registered with
+    /// [Module::conversion][crate::Module::conversion].
+    ///
+    /// # Operation
+    ///
+    /// ```text
+    /// <type>
+    /// <value>
+    /// => <value>
+    /// ```
+    As,
     /// Pop two values from the stack and test if they are both boolean true.
     ///
     /// # Operation
@@ -1465,9 +1635,18 @@ impl fmt::Display for InstOp {
             Self::Div => {
                 write!(f, "/")?;
             }
+            Self::DivPanic => {
+                write!(f, "/")?;
+            }
             Self::Rem => {
                 write!(f, "%")?;
             }
+            Self::RemPanic => {
+                write!(f, "%")?;
+            }
+            Self::Pow => {
+                write!(f, "**")?;
+            }
             Self::BitAnd => {
                 write!(f, "&")?;
             }
@@ -1507,6 +1686,9 @@ impl fmt::Display for InstOp {
             Self::IsNot => {
                 write!(f, "is not")?;
             }
+            Self::As => {
+                write!(f, "as")?;
+            }
             Self::And => {
                 write!(f, "&&")?;
             }
@@ -57,12 +57,15 @@ mod access;
 mod any_obj;
 mod args;
 mod awaited;
+mod big_int;
 pub mod budget;
 mod bytes;
 mod call;
 mod compile_meta;
 mod const_value;
 pub mod debug;
+pub mod debug_limit;
+pub mod depth;
 mod env;
 pub mod format;
 mod from_value;
@@ -71,8 +74,10 @@ mod future;
 mod generator;
 mod generator_state;
 mod guarded_args;
+mod handle;
 mod hash;
 mod id;
+mod inline_cache;
 mod inst;
 mod internal;
 mod item;
@@ -90,6 +95,7 @@ mod protocol;
 mod protocol_caller;
 mod range;
 mod raw_str;
+mod resume;
 mod runtime_context;
 mod select;
 mod shared;
@@ -154,11 +160,13 @@ pub use self::compile_meta::{
     CompileMetaStruct, CompileMetaTuple, CompileMod, CompileSource,
 };
 pub use self::const_value::ConstValue;
+pub use self::env::call_site;
 pub use self::format::{Format, FormatSpec};
 pub use self::from_value::{FromValue, UnsafeFromValue};
 pub use self::generator::Generator;
 pub use self::generator_state::GeneratorState;
 pub use self::guarded_args::GuardedArgs;
+pub use self::handle::{Handle, Handles};
 pub use self::id::Id;
 pub use self::iterator::Iterator;
 pub use self::key::Key;
@@ -167,6 +175,7 @@ pub use self::location::Location;
 pub use self::module::{InstFnNameHash, InstallWith, Module};
 pub use self::named::Named;
 pub use self::raw_str::RawStr;
+pub use self::resume::NativeStep;
 pub use self::runtime_context::RuntimeContext;
 pub use self::select::Select;
 pub use self::source::Source;
@@ -192,11 +201,14 @@ pub use crate::any::Any;
 pub use crate::awaited::Awaited;
 pub use crate::bytes::Bytes;
 pub use crate::call::Call;
-pub use crate::context::{Context, ContextError, ContextSignature, ContextTypeInfo};
-pub use crate::debug::{DebugInfo, DebugInst};
+pub use crate::context::{
+    Context, ContextError, ContextOperator, ContextSignature, ContextTypeInfo,
+};
+pub use crate::debug::{DebugInfo, DebugInst, DebugVariable};
 pub use crate::function::{Function, SyncFunction};
 pub use crate::future::Future;
-pub use crate::hash::{Hash, IntoTypeHash};
+pub use crate::hash::{Hash, IntoTypeHash, ItemPath};
+pub use crate::inline_cache::InlineCache;
 pub use crate::inst::{
     Inst, InstAddress, InstAssignOp, InstOp, InstRangeLimits, InstTarget, InstValue, InstVariant,
     PanicReason, TypeCheck,
@@ -210,15 +222,15 @@ pub use crate::range::{Range, RangeLimits};
 pub use crate::shared::{Mut, RawMut, RawRef, Ref, Shared, SharedPointerGuard};
 pub use crate::stack::{Stack, StackError};
 pub use crate::type_of::TypeOf;
-pub use crate::unit::{Unit, UnitFn};
-pub use crate::value::{Rtti, Struct, TupleStruct, UnitStruct, Value, VariantRtti};
+pub use crate::unit::{Unit, UnitFn, UnitFormatError};
+pub use crate::value::{Rtti, Struct, TupleStruct, UnitStruct, Value, ValueKind, VariantRtti};
 pub use crate::vec_tuple::VecTuple;
 pub use crate::visibility::Visibility;
-pub use crate::vm::{CallFrame, Vm};
+pub use crate::vm::{CallFrame, FrameView, Vm};
 pub use crate::vm_call::VmCall;
 pub use crate::vm_error::{VmError, VmErrorKind, VmIntegerRepr};
 pub use crate::vm_execution::{VmExecution, VmSendExecution};
-pub use crate::vm_halt::{VmHalt, VmHaltInfo};
+pub use crate::vm_halt::{VmDebugState, VmHalt, VmHaltInfo};
 pub(crate) use runestick_macros::__internal_impl_any;
 pub use runestick_macros::{Any, FromValue};
 
@@ -4,10 +4,12 @@
 //! through native code.
 
 use crate::context::{ContextError, Handler, Macro};
+use crate::resume;
 use crate::{collections::HashMap, ConstValue};
 use crate::{
-    FromValue, Future, GeneratorState, Hash, IntoComponent, Item, Named, Protocol, Stack,
-    StaticType, ToValue, TypeCheck, TypeInfo, TypeOf, UnsafeFromValue, Value, VmError, VmErrorKind,
+    FromValue, Future, GeneratorState, Hash, IntoComponent, Item, Named, NativeStep, Protocol,
+    Stack, StaticType, ToValue, TypeCheck, TypeInfo, TypeOf, UnsafeFromValue, Value, VmError,
+    VmErrorKind,
 };
 use std::any;
 use std::future;
@@ -117,6 +119,8 @@ pub(crate) struct ModuleAssociatedFn {
     pub(crate) args: Option<usize>,
     pub(crate) type_info: TypeInfo,
     pub(crate) name: String,
+    /// If the function is async.
+    pub(crate) is_async: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -129,6 +133,21 @@ pub(crate) struct ModuleAssocKey {
 pub(crate) struct ModuleFn {
     pub(crate) handler: Arc<Handler>,
     pub(crate) args: Option<usize>,
+    pub(crate) arg_names: Option<Vec<String>>,
+    /// If the function is allowed to yield back to the virtual machine.
+    pub(crate) resumable: bool,
+    /// If the function is async.
+    pub(crate) is_async: bool,
+}
+
+pub(crate) struct ModuleConversion {
+    pub(crate) handler: Arc<Handler>,
+    pub(crate) from: TypeInfo,
+    pub(crate) to: TypeInfo,
+}
+
+pub(crate) struct ModuleOperator {
+    pub(crate) precedence: usize,
 }
 
 pub(crate) struct ModuleMacro {
@@ -154,6 +173,11 @@ pub struct Module {
     pub(crate) unit_type: Option<ModuleUnitType>,
     /// Registered generator state type.
     pub(crate) internal_enums: Vec<ModuleInternalEnum>,
+    /// Custom binary operators.
+    pub(crate) operators: HashMap<Box<str>, ModuleOperator>,
+    /// Conversions between types, keyed by the hashes of the types converted
+    /// from and to.
+    pub(crate) conversions: HashMap<(Hash, Hash), ModuleConversion>,
 }
 
 impl Module {
@@ -195,6 +219,8 @@ impl Module {
             unit_type: None,
             internal_enums: Vec::new(),
             constants: Default::default(),
+            operators: Default::default(),
+            conversions: Default::default(),
         }
     }
 
@@ -417,6 +443,35 @@ impl Module {
     /// module.function(&["optional"], |a: Option<String>| Ok::<_, runestick::Error>(()))?;
     /// # Ok(()) }
     /// ```
+    ///
+    /// Functions returning a `Result` whose error can be converted into a
+    /// value produce a `Result` which can be matched on in the script. This is
+    /// useful for fallible constructors. A function returning
+    /// `Result<T, VmError>` instead raises the error in the virtual machine.
+    ///
+    /// ```rust
+    /// use runestick::Any;
+    ///
+    /// #[derive(Any)]
+    /// struct Port(u16);
+    ///
+    /// #[derive(Any)]
+    /// struct PortError;
+    ///
+    /// impl Port {
+    ///     fn parse(input: &str) -> Result<Self, PortError> {
+    ///         input.parse().map(Port).map_err(|_| PortError)
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<Port>()?;
+    /// module.ty::<PortError>()?;
+    /// module.function(&["Port", "parse"], Port::parse)?;
+    /// # Ok(()) }
+    /// ```
     pub fn function<Func, Args, N>(&mut self, name: N, f: Func) -> Result<(), ContextError>
     where
         Func: Function<Args>,
@@ -425,7 +480,7 @@ impl Module {
     {
         let name = Item::with_item(name);
 
-        if self.functions.contains_key(&name) {
+        if self.functions.contains_key(&name) || self.constants.contains_key(&name) {
             return Err(ContextError::ConflictingFunctionName { name });
         }
 
@@ -434,14 +489,70 @@ impl Module {
             ModuleFn {
                 handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
                 args: Some(Func::args()),
+                arg_names: None,
+                resumable: false,
+                is_async: false,
             },
         );
 
         Ok(())
     }
 
+    /// Register a custom binary operator, like `<>`, which calls the given
+    /// function with its left and right hand side.
+    ///
+    /// The operator must consist of two or three ASCII punctuation characters
+    /// which are lexed as exactly two tokens, and is only recognized if those
+    /// tokens are written without any whitespace between them. Since the
+    /// context doesn't know how sources are lexed, this is checked by the
+    /// compiler, which rejects operators which lex as a single builtin token,
+    /// like `==` or `->`, and operators which could be read as a builtin
+    /// binary operator followed by a unary one, like `<-` in `a <-1`.
+    ///
+    /// Custom operators are recognized in sources and in the output of macros,
+    /// but not by a parser which a macro constructs over its own input, so a
+    /// macro which parses its input sees the operator as two separate tokens.
+    ///
+    /// The `precedence` determines how tightly the operator binds, using the
+    /// same levels as the builtin operators. For example `11` binds like `*`,
+    /// `10` like `+` and `5` like `==`. Custom operators are left-associative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// fn concat(a: String, b: String) -> String {
+    ///     a + &b
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    /// module.binary_operator("<>", 10, concat)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn binary_operator<Func, Args>(
+        &mut self,
+        op: &str,
+        precedence: usize,
+        f: Func,
+    ) -> Result<(), ContextError>
+    where
+        Func: Function<Args>,
+    {
+        if self.operators.contains_key(op) {
+            return Err(ContextError::ConflictingOperator { op: op.into() });
+        }
+
+        self.function(&[op], f)?;
+        self.operators
+            .insert(op.into(), ModuleOperator { precedence });
+        Ok(())
+    }
+
     /// Register a constant value, at a crate, module or associated level.
     ///
+    /// Constants share their names with functions, so registering a constant
+    /// with the same name as a function is an error.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -462,7 +573,7 @@ impl Module {
     {
         let name = Item::with_item(name);
 
-        if self.constants.contains_key(&name) {
+        if self.constants.contains_key(&name) || self.functions.contains_key(&name) {
             return Err(ContextError::ConflictingConstantName { name });
         }
 
@@ -537,7 +648,7 @@ impl Module {
     {
         let name = Item::with_item(name);
 
-        if self.functions.contains_key(&name) {
+        if self.functions.contains_key(&name) || self.constants.contains_key(&name) {
             return Err(ContextError::ConflictingFunctionName { name });
         }
 
@@ -546,6 +657,9 @@ impl Module {
             ModuleFn {
                 handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
                 args: Some(Func::args()),
+                arg_names: None,
+                resumable: false,
+                is_async: true,
             },
         );
 
@@ -562,7 +676,7 @@ impl Module {
     {
         let name = Item::with_item(name);
 
-        if self.functions.contains_key(&name) {
+        if self.functions.contains_key(&name) || self.constants.contains_key(&name) {
             return Err(ContextError::ConflictingFunctionName { name });
         }
 
@@ -571,12 +685,120 @@ impl Module {
             ModuleFn {
                 handler: Arc::new(move |stack, args| f(stack, args)),
                 args: None,
+                arg_names: None,
+                resumable: false,
+                is_async: false,
             },
         );
 
         Ok(())
     }
 
+    /// Register a raw function which can cooperatively yield back to the
+    /// virtual machine, so that long running native calls are subject to the
+    /// instruction [budget][crate::budget] and can be stepped through.
+    ///
+    /// The function is called with its arguments on the stack and a state
+    /// which is preserved between calls. If it returns [NativeStep::Yield] it
+    /// must leave its arguments on the stack, and is called again with the
+    /// same arguments and state once the virtual machine resumes. Once done it
+    /// pops its arguments, pushes its return value and returns
+    /// [NativeStep::Complete].
+    ///
+    /// Only direct calls from a script can yield. When called in any other way,
+    /// like through a function pointer, the function is called repeatedly
+    /// until it completes.
+    pub fn resumable_fn<F, N>(&mut self, name: N, f: F) -> Result<(), ContextError>
+    where
+        F: 'static
+            + Fn(&mut Stack, usize, &mut Option<Value>) -> Result<NativeStep, VmError>
+            + Send
+            + Sync,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        if self.functions.contains_key(&name) || self.constants.contains_key(&name) {
+            return Err(ContextError::ConflictingFunctionName { name });
+        }
+
+        let handler = move |stack: &mut Stack, args| {
+            match resume::take_state() {
+                Some(mut state) => {
+                    if let NativeStep::Yield = f(stack, args, &mut state)? {
+                        resume::suspend(state);
+                    }
+                }
+                None => {
+                    let mut state = None;
+                    while let NativeStep::Yield = f(stack, args, &mut state)? {}
+                }
+            }
+
+            Ok(())
+        };
+
+        self.functions.insert(
+            name,
+            ModuleFn {
+                handler: Arc::new(handler),
+                args: None,
+                arg_names: None,
+                resumable: true,
+                is_async: false,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Attach parameter names to an already registered function.
+    ///
+    /// The names are exposed through [ContextSignature][crate::ContextSignature]
+    /// and are intended for tooling like signature help. For functions with a
+    /// fixed number of arguments, the number of names must match.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// fn add(a: i64, b: i64) -> i64 {
+    ///     a + b
+    /// }
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.function(&["add"], add)?;
+    /// module.function_arg_names(&["add"], &["a", "b"])?;
+    /// # Ok(()) }
+    /// ```
+    pub fn function_arg_names<N>(&mut self, name: N, names: &[&str]) -> Result<(), ContextError>
+    where
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let name = Item::with_item(name);
+
+        let f = match self.functions.get_mut(&name) {
+            Some(f) => f,
+            None => return Err(ContextError::MissingFunction { name }),
+        };
+
+        if let Some(args) = f.args {
+            if args != names.len() {
+                return Err(ContextError::ArgNamesMismatch {
+                    name,
+                    expected: args,
+                    actual: names.len(),
+                });
+            }
+        }
+
+        f.arg_names = Some(names.iter().map(|n| n.to_string()).collect());
+        Ok(())
+    }
+
     /// Register an instance function.
     ///
     /// # Examples
@@ -634,6 +856,60 @@ impl Module {
         self.assoc_fn(name, f, ModuleAssociatedKind::FieldFn(protocol))
     }
 
+    /// Register a conversion from one type to another, which can be used in
+    /// scripts through the `as` operator like `celsius as Fahrenheit`.
+    ///
+    /// Both types must be registered in the context the module is installed
+    /// into, either by this module or by a module which was installed before
+    /// it. Otherwise installing the module fails with
+    /// [ContextError::MissingConversionType]. A conversion in the opposite
+    /// direction is registered separately.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Any;
+    ///
+    /// #[derive(Any)]
+    /// struct Celsius(f64);
+    ///
+    /// #[derive(Any)]
+    /// struct Fahrenheit(f64);
+    ///
+    /// # fn main() -> runestick::Result<()> {
+    /// let mut module = runestick::Module::default();
+    ///
+    /// module.ty::<Celsius>()?;
+    /// module.ty::<Fahrenheit>()?;
+    /// module.conversion(|c: &Celsius| Fahrenheit(c.0 * 1.8 + 32.0))?;
+    /// module.conversion(|f: &Fahrenheit| Celsius((f.0 - 32.0) / 1.8))?;
+    ///
+    /// let mut context = runestick::Context::new();
+    /// context.install(&module)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn conversion<Func, Instance>(&mut self, f: Func) -> Result<(), ContextError>
+    where
+        Func: InstFn<(Instance,)>,
+        Func::Return: TypeOf,
+    {
+        let from = Func::instance_type_info();
+        let to = <Func::Return as TypeOf>::type_info();
+        let key = (
+            Func::instance_type_hash(),
+            <Func::Return as TypeOf>::type_hash(),
+        );
+
+        if self.conversions.contains_key(&key) {
+            return Err(ContextError::ConflictingConversion { from, to });
+        }
+
+        let handler: Arc<Handler> = Arc::new(move |stack, args| f.fn_call(stack, args));
+        self.conversions
+            .insert(key, ModuleConversion { handler, from, to });
+        Ok(())
+    }
+
     /// Install an associated function.
     fn assoc_fn<N, Func, Args>(
         &mut self,
@@ -667,13 +943,21 @@ impl Module {
             args: Some(Func::args()),
             type_info,
             name,
+            is_async: false,
         };
 
         self.associated_functions.insert(key, instance_function);
         Ok(())
     }
 
-    /// Register an instance function.
+    /// Register an async instance function.
+    ///
+    /// The instance and any other arguments can be borrowed, like with
+    /// `async fn fetch(&self)`. The values they borrow from stay borrowed until
+    /// the returned future resolves or is dropped, so accessing them in a way
+    /// which conflicts with the borrow in the meantime, like calling a
+    /// function which borrows the instance mutably, results in an access
+    /// error.
     ///
     /// # Examples
     ///
@@ -727,6 +1011,7 @@ impl Module {
             args: Some(Func::args()),
             type_info,
             name,
+            is_async: true,
         };
 
         self.associated_functions.insert(key, instance_function);
@@ -1007,7 +1292,9 @@ macro_rules! impl_register {
                 // Safety: Future is owned and will only be called within the
                 // context of the virtual machine, which will provide
                 // exclusive thread-local access to itself while the future is
-                // being polled.
+                // being polled. The guards of borrowed arguments are moved
+                // into the future, so the values they borrow from stay
+                // borrowed until it's dropped.
                 #[allow(unused)]
                 let ret = unsafe {
                     impl_register!{@unsafe-inst-vars inst, $count, $($ty, $var, $num,)*}
@@ -1,17 +1,15 @@
 //! `std::any` module.
 
-use crate::{Any, ContextError, Module, Protocol, Value};
-use std::any::TypeId as StdTypeId;
+use crate::{Any, ContextError, Hash, Module, Protocol, Value, VmError};
 use std::fmt;
 use std::fmt::Write as _;
 
 #[derive(Any, Debug)]
 #[rune(module = "crate")]
-#[repr(transparent)]
-struct TypeId(StdTypeId);
+struct TypeId(Hash);
 
-fn type_id_of_val(item: Value) -> TypeId {
-    unsafe { std::mem::transmute(item.type_hash().expect("no type known for item!")) }
+fn type_id_of_val(item: Value) -> Result<TypeId, VmError> {
+    Ok(TypeId(item.type_hash()?))
 }
 
 fn format_type_id(item: &TypeId, buf: &mut String) -> fmt::Result {
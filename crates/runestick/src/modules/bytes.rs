@@ -1,6 +1,7 @@
 //! `std::bytes` module.
 
-use crate::{Bytes, ContextError, Module};
+use super::string::{string_range, ByteBounds};
+use crate::{Bytes, ContextError, Module, Protocol, Value, VmError, VmErrorKind};
 
 /// Construct the `std::bytes` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -17,7 +18,9 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("pop", Bytes::pop)?;
     module.inst_fn("last", Bytes::last)?;
 
-    module.inst_fn("len", Bytes::len)?;
+    module.inst_fn(Protocol::LEN, Bytes::len)?;
+    module.inst_fn(Protocol::IS_EMPTY, Bytes::is_empty)?;
+    module.inst_fn(Protocol::INDEX_GET, bytes_index_get)?;
     module.inst_fn("capacity", Bytes::capacity)?;
     module.inst_fn("clear", Bytes::clear)?;
     module.inst_fn("reserve", Bytes::reserve)?;
@@ -26,3 +29,45 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("shrink_to_fit", Bytes::shrink_to_fit)?;
     Ok(module)
 }
+
+/// Get a byte by index, or a sub-slice of bytes by range.
+fn bytes_index_get(bytes: &Bytes, index: Value) -> Result<Value, VmError> {
+    use crate::TypeOf as _;
+
+    let len = bytes.len();
+
+    match index {
+        Value::Integer(index) => match bytes.get(index as usize) {
+            Some(byte) => Ok(Value::from(*byte as i64)),
+            None => Err(VmError::from(VmErrorKind::OutOfRange {
+                index: index.into(),
+                len: len.into(),
+            })),
+        },
+        Value::Range(range) => {
+            let range = range.borrow_ref()?;
+
+            let (start, end) = match string_range(&range)? {
+                Some(ByteBounds { start, end }) => (start.unwrap_or(0), end.unwrap_or(len)),
+                None => return Err(VmError::from(VmErrorKind::IndexOutOfBounds)),
+            };
+
+            if end > len {
+                return Err(VmError::from(VmErrorKind::OutOfRange {
+                    index: end.into(),
+                    len: len.into(),
+                }));
+            }
+
+            if start > end {
+                return Err(VmError::from(VmErrorKind::IndexOutOfBounds));
+            }
+
+            Ok(Value::from(Bytes::from_vec(bytes[start..end].to_vec())))
+        }
+        index => Err(VmError::from(VmErrorKind::UnsupportedIndexGet {
+            target: Bytes::type_info(),
+            index: index.type_info()?,
+        })),
+    }
+}
@@ -420,7 +420,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("is_empty", HashMap::is_empty)?;
     module.inst_fn("iter", HashMap::iter)?;
     module.inst_fn("keys", HashMap::keys)?;
-    module.inst_fn("len", HashMap::len)?;
+    module.inst_fn(crate::Protocol::LEN, HashMap::len)?;
     module.inst_fn("remove", HashMap::remove)?;
     module.inst_fn("values", HashMap::values)?;
     module.inst_fn(crate::Protocol::INTO_ITER, HashMap::iter)?;
@@ -440,7 +440,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("intersection", HashSet::intersection)?;
     module.inst_fn("is_empty", HashSet::is_empty)?;
     module.inst_fn("iter", HashSet::iter)?;
-    module.inst_fn("len", HashSet::len)?;
+    module.inst_fn(crate::Protocol::LEN, HashSet::len)?;
     module.inst_fn("remove", HashSet::remove)?;
     module.inst_fn("union", HashSet::union)?;
     module.inst_fn(crate::Protocol::INTO_ITER, HashSet::iter)?;
@@ -455,7 +455,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("extend", VecDeque::extend)?;
     module.inst_fn("insert", VecDeque::insert)?;
     module.inst_fn("iter", VecDeque::iter)?;
-    module.inst_fn("len", VecDeque::len)?;
+    module.inst_fn(crate::Protocol::LEN, VecDeque::len)?;
     module.inst_fn("pop_back", VecDeque::pop_back)?;
     module.inst_fn("pop_front", VecDeque::pop_front)?;
     module.inst_fn("push_back", VecDeque::push_back)?;
@@ -1,6 +1,6 @@
 //! The `std::fmt` module.
 
-use crate::{ContextError, Module, Protocol};
+use crate::{ContextError, FromValue as _, Module, Protocol, Stack, Value, VmError, VmErrorKind};
 use std::fmt;
 use std::fmt::Write as _;
 
@@ -11,9 +11,72 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn(Protocol::STRING_DISPLAY, format_fmt_error)?;
 
     module.ty::<crate::Format>()?;
+    module.raw_fn(&["format"], format_impl)?;
     Ok(module)
 }
 
 fn format_fmt_error(error: &std::fmt::Error, buf: &mut String) -> fmt::Result {
     write!(buf, "{}", error)
 }
+
+/// Format the arguments according to the format string passed as the first
+/// argument, like `format("{} + {} = {}", 1, 2, 3)`.
+///
+/// Each `{}` is replaced with the next argument formatted using the
+/// [Protocol::STRING_DISPLAY] protocol, and `{{` and `}}` are escaped braces.
+fn format_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    let mut values = stack.drain_stack_top(args)?.collect::<Vec<_>>().into_iter();
+
+    let format = match values.next() {
+        Some(format) => String::from_value(format)?,
+        None => {
+            return Err(VmError::from(VmErrorKind::BadArgumentCount {
+                actual: 0,
+                expected: 1,
+            }))
+        }
+    };
+
+    let mut out = String::new();
+    let mut buf = String::new();
+    let mut expected = 0;
+    let mut it = format.chars().peekable();
+
+    while let Some(c) = it.next() {
+        match (c, it.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                it.next();
+                out.push(c);
+            }
+            ('{', Some('}')) => {
+                it.next();
+                expected += 1;
+
+                if let Some(value) = values.next() {
+                    if let Err(fmt::Error) = value.string_display(&mut out, &mut buf)? {
+                        return Err(VmError::from(VmErrorKind::FormatError));
+                    }
+                }
+            }
+            ('{', _) | ('}', _) => {
+                return Err(VmError::panic(format!(
+                    "unmatched `{}` in format string, use `{}{}` to escape it",
+                    c, c, c
+                )));
+            }
+            (c, _) => {
+                out.push(c);
+            }
+        }
+    }
+
+    if expected != args - 1 {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: args - 1,
+            expected,
+        }));
+    }
+
+    stack.push(Value::from(out));
+    Ok(())
+}
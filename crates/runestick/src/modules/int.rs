@@ -1,6 +1,9 @@
 //! The `std::int` module.
 
-use crate::{ContextError, Module};
+use crate::{ContextError, Module, Protocol};
+use num_bigint::BigInt;
+use std::fmt;
+use std::fmt::Write as _;
 use std::num::ParseIntError;
 
 /// Construct the `std::int` module.
@@ -8,6 +11,9 @@ pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["int"]);
 
     module.ty::<ParseIntError>()?;
+    module.ty::<BigInt>()?;
+    module.inst_fn(Protocol::STRING_DISPLAY, format_big_int)?;
+    module.inst_fn(Protocol::STRING_DEBUG, format_big_int)?;
 
     module.function(&["parse"], parse)?;
     module.function(&["max"], i64::max)?;
@@ -44,6 +50,11 @@ fn parse(s: &str) -> Result<i64, ParseIntError> {
     Ok(str::parse::<i64>(s)?)
 }
 
+/// Format an integer which has been promoted to a big integer.
+fn format_big_int(value: &BigInt, buf: &mut String) -> fmt::Result {
+    write!(buf, "{}", value)
+}
+
 /// Convert a whole number to float.
 fn to_float(value: i64) -> f64 {
     value as f64
@@ -1,6 +1,7 @@
 //! The `std::io` module.
 
-use crate::{ContextError, Module, Panic, Protocol, Stack, Value, VmError};
+use crate::debug_limit::{self, DebugLimits};
+use crate::{ContextError, Module, Protocol, Stack, Value, VmError, VmErrorKind};
 use std::fmt;
 use std::fmt::Write as _;
 use std::io;
@@ -12,6 +13,7 @@ pub fn module(stdio: bool) -> Result<Module, ContextError> {
 
     module.ty::<io::Error>()?;
     module.inst_fn(Protocol::STRING_DISPLAY, format_io_error)?;
+    module.function(&["set_debug_limits"], set_debug_limits)?;
 
     if stdio {
         module.function(&["print"], print_impl)?;
@@ -26,26 +28,44 @@ fn format_io_error(error: &std::io::Error, buf: &mut String) -> fmt::Result {
     write!(buf, "{}", error)
 }
 
+/// Limit how deeply nested collections are debug formatted and how many
+/// elements are printed for each of them, like through `dbg`.
+///
+/// The limits apply until the script returns to its caller.
+fn set_debug_limits(max_depth: usize, max_elements: usize) {
+    debug_limit::set(DebugLimits {
+        max_depth,
+        max_elements,
+    });
+}
+
 fn dbg_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
-    let stdout = io::stdout();
-    let mut stdout = stdout.lock();
+    let mut out = String::new();
 
     for value in stack.drain_stack_top(args)? {
-        writeln!(stdout, "{:?}", value).map_err(VmError::panic)?;
+        // NB: the debug implementation of `Value` uses the
+        // `Protocol::STRING_DEBUG` protocol, falling back to type information
+        // for values which don't implement it.
+        writeln!(out, "{:?}", value).map_err(|_| VmErrorKind::FormatError)?;
     }
 
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    stdout.write_all(out.as_bytes()).map_err(VmError::panic)?;
     stack.push(Value::Unit);
     Ok(())
 }
 
-fn print_impl(m: &str) -> Result<(), Panic> {
+fn print_impl(value: Value) -> Result<(), VmError> {
+    let out = value.display_string()?;
     let stdout = io::stdout();
     let mut stdout = stdout.lock();
-    write!(stdout, "{}", m).map_err(Panic::custom)
+    write!(stdout, "{}", out).map_err(VmError::panic)
 }
 
-fn println_impl(m: &str) -> Result<(), Panic> {
+fn println_impl(value: Value) -> Result<(), VmError> {
+    let out = value.display_string()?;
     let stdout = io::stdout();
     let mut stdout = stdout.lock();
-    writeln!(stdout, "{}", m).map_err(Panic::custom)
+    writeln!(stdout, "{}", out).map_err(VmError::panic)
 }
@@ -1,11 +1,15 @@
 //! The `std::mem` module.
 
-use crate::{ContextError, Module, Value, VmError};
+use crate::{ContextError, Module, Value, VmError, VmErrorKind, VmIntegerRepr};
+use std::convert::TryFrom as _;
+use std::mem;
 
 /// Construct the `std` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", &["mem"]);
     module.function(&["drop"], drop_impl)?;
+    module.function(&["take"], take_impl)?;
+    module.function(&["replace"], replace_impl)?;
     Ok(module)
 }
 
@@ -13,3 +17,59 @@ fn drop_impl(value: Value) -> Result<(), VmError> {
     value.take()?;
     Ok(())
 }
+
+/// Take the value out of the given object field or vector element, leaving a
+/// unit in its place.
+fn take_impl(container: Value, key: Value) -> Result<Value, VmError> {
+    replace_impl(container, key, Value::Unit)
+}
+
+/// Replace the value of the given object field or vector element, returning
+/// the old value.
+fn replace_impl(container: Value, key: Value, value: Value) -> Result<Value, VmError> {
+    match (&container, &key) {
+        (Value::Object(object), Value::String(field)) => {
+            let field = field.borrow_ref()?;
+            let mut object = object.borrow_mut()?;
+
+            if let Some(slot) = object.get_mut(field.as_str()) {
+                return Ok(mem::replace(slot, value));
+            }
+
+            return Err(VmError::from(VmErrorKind::MissingField {
+                target: container.type_info()?,
+                field: field.as_str().to_owned(),
+            }));
+        }
+        (Value::Object(object), Value::StaticString(field)) => {
+            let mut object = object.borrow_mut()?;
+
+            if let Some(slot) = object.get_mut(field.as_str()) {
+                return Ok(mem::replace(slot, value));
+            }
+
+            return Err(VmError::from(VmErrorKind::MissingField {
+                target: container.type_info()?,
+                field: field.as_str().to_owned(),
+            }));
+        }
+        (Value::Vec(vec), Value::Integer(index)) => {
+            let mut vec = vec.borrow_mut()?;
+
+            if let Some(slot) = usize::try_from(*index).ok().and_then(|i| vec.get_mut(i)) {
+                return Ok(mem::replace(slot, value));
+            }
+
+            return Err(VmError::from(VmErrorKind::OutOfRange {
+                index: VmIntegerRepr::from(*index),
+                len: VmIntegerRepr::from(vec.len()),
+            }));
+        }
+        _ => (),
+    }
+
+    Err(VmError::from(VmErrorKind::UnsupportedIndexGet {
+        target: container.type_info()?,
+        index: key.type_info()?,
+    }))
+}
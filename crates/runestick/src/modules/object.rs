@@ -8,11 +8,13 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.ty::<Object>()?;
 
-    module.inst_fn("len", Object::len)?;
+    module.inst_fn(Protocol::LEN, Object::len)?;
     module.inst_fn("insert", Object::insert)?;
     module.inst_fn("clear", Object::clear)?;
     module.inst_fn("contains_key", contains_key)?;
     module.inst_fn("get", get)?;
+    module.inst_fn("extend", extend)?;
+    module.function(&["merge"], merge)?;
 
     module.inst_fn("iter", Object::into_iterator)?;
     module.inst_fn(Protocol::INTO_ITER, Object::into_iterator)?;
@@ -29,6 +31,24 @@ fn get(object: &Object, key: &str) -> Option<Value> {
     object.get(key).cloned()
 }
 
+/// Insert every key of `other` into `object`, overwriting existing keys.
+///
+/// Values are not cloned deeply, so any containers are shared between the
+/// two objects.
+fn extend(object: &mut Object, other: &Object) {
+    for (key, value) in other.iter() {
+        object.insert(key.clone(), value.clone());
+    }
+}
+
+/// Construct a new object with the keys of both `a` and `b`, where `b` takes
+/// precedence over `a` for keys they have in common.
+fn merge(a: &Object, b: &Object) -> Object {
+    let mut object = a.clone();
+    extend(&mut object, b);
+    object
+}
+
 fn keys(object: &Object) -> Iterator {
     let iter = object.keys().cloned().collect::<Vec<_>>().into_iter();
     Iterator::from_double_ended("std::object::Keys", iter)
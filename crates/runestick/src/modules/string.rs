@@ -1,6 +1,8 @@
 //! The `std::string` module.
 
-use crate::{Any, Bytes, ContextError, Iterator, Module, Protocol, Value, VmError, VmErrorKind};
+use crate::{
+    Any, Bytes, ContextError, Iterator, Module, Protocol, Range, Value, VmError, VmErrorKind,
+};
 
 /// Construct the `std::string` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -13,7 +15,10 @@ pub fn module() -> Result<Module, ContextError> {
     module.function(&["String", "with_capacity"], String::with_capacity)?;
 
     module.inst_fn("cmp", str::cmp)?;
-    module.inst_fn("len", String::len)?;
+    // NB: `len` is the length in bytes, matching Rust. Use `char_len` for the
+    // number of characters.
+    module.inst_fn(Protocol::LEN, String::len)?;
+    module.inst_fn("char_len", char_len)?;
     module.inst_fn("starts_with", str::starts_with::<&str>)?;
     module.inst_fn("ends_with", str::ends_with::<&str>)?;
     module.inst_fn("capacity", String::capacity)?;
@@ -35,6 +40,9 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("is_empty", str::is_empty)?;
     module.inst_fn("chars", string_chars)?;
     module.inst_fn(Protocol::ADD, add)?;
+    // NB: `+=` appends to the string in place, so building a string in a loop
+    // through it is amortized. Use `String::with_capacity` to allocate up
+    // front.
     module.inst_fn(Protocol::ADD_ASSIGN, String::push_str)?;
     module.inst_fn(Protocol::INDEX_GET, string_index_get)?;
     module.inst_fn("get", string_get)?;
@@ -67,6 +75,12 @@ fn into_bytes(s: String) -> Bytes {
     Bytes::from_vec(s.into_bytes())
 }
 
+/// Get the number of characters in the string, as opposed to `len` which
+/// returns the number of bytes.
+fn char_len(s: &str) -> usize {
+    s.chars().count()
+}
+
 fn char_at(s: &str, index: usize) -> Option<char> {
     if !s.is_char_boundary(index) {
         return None;
@@ -124,42 +138,63 @@ fn string_chars(s: &str) -> Iterator {
     Iterator::from_double_ended("std::str::Chars", iter)
 }
 
+/// The byte bounds of a range.
+pub(super) struct ByteBounds {
+    /// The inclusive start of the range, if any.
+    pub(super) start: Option<usize>,
+    /// The exclusive end of the range, if any.
+    pub(super) end: Option<usize>,
+}
+
+/// Get the byte bounds of the given range.
+///
+/// Returns `None` if the range can't be represented.
+pub(super) fn string_range(range: &Range) -> Result<Option<ByteBounds>, VmError> {
+    use crate::{FromValue as _, RangeLimits};
+
+    let start = match range.start.clone() {
+        Some(value) => Some(<usize>::from_value(value)?),
+        None => None,
+    };
+
+    let end = match range.end.clone() {
+        Some(value) => Some(<usize>::from_value(value)?),
+        None => None,
+    };
+
+    let end = match range.limits {
+        RangeLimits::HalfOpen => end,
+        RangeLimits::Closed => match end {
+            Some(end) => match end.checked_add(1) {
+                Some(end) => Some(end),
+                None => return Ok(None),
+            },
+            None => return Err(VmError::from(VmErrorKind::UnsupportedRange)),
+        },
+    };
+
+    Ok(Some(ByteBounds { start, end }))
+}
+
 /// Get a specific string index.
 fn string_get(s: &str, key: Value) -> Result<Option<String>, VmError> {
-    use crate::{FromValue as _, RangeLimits, TypeOf as _};
+    use crate::TypeOf as _;
 
     match key {
         Value::Range(range) => {
             let range = range.borrow_ref()?;
 
-            let start = match range.start.clone() {
-                Some(value) => Some(<usize>::from_value(value)?),
-                None => None,
-            };
-
-            let end = match range.end.clone() {
-                Some(value) => Some(<usize>::from_value(value)?),
-                None => None,
-            };
-
-            let out = match range.limits {
-                RangeLimits::HalfOpen => match (start, end) {
+            let out = match string_range(&range)? {
+                Some(ByteBounds { start, end }) => match (start, end) {
                     (Some(start), Some(end)) => s.get(start..end),
                     (Some(start), None) => s.get(start..),
                     (None, Some(end)) => s.get(..end),
                     (None, None) => s.get(..),
                 },
-                RangeLimits::Closed => match (start, end) {
-                    (Some(start), Some(end)) => s.get(start..=end),
-                    (None, Some(end)) => s.get(..=end),
-                    _ => return Err(VmError::from(VmErrorKind::UnsupportedRange)),
-                },
+                None => None,
             };
 
-            return Ok(match out {
-                Some(out) => Some(out.to_owned()),
-                None => None,
-            });
+            Ok(out.map(str::to_owned))
         }
         index => Err(VmError::from(VmErrorKind::UnsupportedIndexGet {
             target: String::type_info(),
@@ -169,6 +204,21 @@ fn string_get(s: &str, key: Value) -> Result<Option<String>, VmError> {
 }
 
 /// Get a specific string index.
+///
+/// Unlike [string_get], this errors if the range doesn't fall on a character
+/// boundary.
 fn string_index_get(s: &str, key: Value) -> Result<String, VmError> {
+    if let Value::Range(range) = &key {
+        let range = range.borrow_ref()?;
+
+        if let Some(ByteBounds { start, end }) = string_range(&range)? {
+            for offset in start.into_iter().chain(end) {
+                if offset <= s.len() && !s.is_char_boundary(offset) {
+                    return Err(VmError::from(VmErrorKind::NotCharBoundary { offset }));
+                }
+            }
+        }
+    }
+
     string_get(s, key)?.ok_or_else(|| VmError::panic("missing string slice"))
 }
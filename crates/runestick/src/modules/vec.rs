@@ -1,6 +1,7 @@
 //! The `std::vec` module.
 
-use crate::{ContextError, Module, Protocol, Value, Vec};
+use crate::collections::HashSet;
+use crate::{ContextError, Function, Key, Module, Protocol, Value, Vec, Vm, VmError};
 
 /// Construct the `std::vec` module.
 pub fn module() -> Result<Module, ContextError> {
@@ -12,16 +13,21 @@ pub fn module() -> Result<Module, ContextError> {
     module.inst_fn("clear", Vec::clear)?;
     module.inst_fn("clone", Vec::clone)?;
     module.inst_fn("extend", Vec::extend)?;
+    module.inst_fn("find", find)?;
     module.inst_fn("get", vec_get)?;
     module.inst_fn("iter", Vec::into_iterator)?;
-    module.inst_fn("len", Vec::len)?;
+    module.inst_fn(Protocol::LEN, Vec::len)?;
     module.inst_fn("pop", Vec::pop)?;
+    module.inst_fn("position", position)?;
     module.inst_fn("push", Vec::push)?;
     module.inst_fn("remove", Vec::remove)?;
     module.inst_fn("sort_by", sort_by)?;
     module.inst_fn("insert", Vec::insert)?;
     module.inst_fn(Protocol::INTO_ITER, Vec::into_iterator)?;
     module.inst_fn(Protocol::INDEX_SET, Vec::set)?;
+    module.inst_fn("intersection", intersection)?;
+    module.inst_fn("union", union)?;
+    module.inst_fn("difference", difference)?;
 
     // TODO: parameterize with generics.
     module.inst_fn("sort_int", sort_int)?;
@@ -42,10 +48,143 @@ fn vec_get(vec: &Vec, index: usize) -> Option<Value> {
     vec.get(index).cloned()
 }
 
-fn sort_by(vec: &mut Vec, comparator: &crate::Function) {
+/// Find the index of the first element matching the predicate.
+///
+/// Stops calling the predicate at the first match, or if it errors.
+fn position(vec: &Vec, predicate: &Function) -> Result<Option<usize>, VmError> {
+    for (index, value) in vec.iter().enumerate() {
+        if predicate.call::<_, bool>((value.clone(),))? {
+            return Ok(Some(index));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Find the first element matching the predicate.
+///
+/// Stops calling the predicate at the first match, or if it errors.
+fn find(vec: &Vec, predicate: &Function) -> Result<Option<Value>, VmError> {
+    for value in vec.iter() {
+        if predicate.call::<_, bool>((value.clone(),))? {
+            return Ok(Some(value.clone()));
+        }
+    }
+
+    Ok(None)
+}
+
+fn sort_by(vec: &mut Vec, comparator: &Function) {
     vec.sort_by(|a, b| {
         comparator
             .call::<_, std::cmp::Ordering>((a, b))
             .expect("an ordering")
     })
 }
+
+/// The kind of set operation to perform over two vectors.
+#[derive(Clone, Copy)]
+enum SetOp {
+    Intersection,
+    Union,
+    Difference,
+}
+
+/// Elements of `a` which are also in `b`.
+///
+/// The result preserves the order of `a`, and only keeps the first of any
+/// equal elements.
+fn intersection(a: &Vec, b: &Vec) -> Result<Vec, VmError> {
+    set_op(SetOp::Intersection, a, b)
+}
+
+/// Elements of `a` followed by elements of `b` which are not in `a`.
+///
+/// The result preserves the order of `a` and `b`, and only keeps the first of
+/// any equal elements.
+fn union(a: &Vec, b: &Vec) -> Result<Vec, VmError> {
+    set_op(SetOp::Union, a, b)
+}
+
+/// Elements of `a` which are not in `b`.
+///
+/// The result preserves the order of `a`, and only keeps the first of any
+/// equal elements.
+fn difference(a: &Vec, b: &Vec) -> Result<Vec, VmError> {
+    set_op(SetOp::Difference, a, b)
+}
+
+/// Perform a set operation.
+///
+/// If every element can be used as a [Key] this is backed by hash sets,
+/// otherwise it falls back to comparing elements pairwise with the equality
+/// protocol.
+fn set_op(op: SetOp, a: &Vec, b: &Vec) -> Result<Vec, VmError> {
+    if let (Ok(a_keys), Ok(b_keys)) = (keys(a), keys(b)) {
+        let other = b_keys.iter().collect::<HashSet<_>>();
+        let mut seen = HashSet::new();
+        let mut output = Vec::new();
+
+        for (value, key) in a.iter().zip(&a_keys) {
+            if keep(op, other.contains(key)) && seen.insert(key) {
+                output.push(value.clone());
+            }
+        }
+
+        if let SetOp::Union = op {
+            for (value, key) in b.iter().zip(&b_keys) {
+                if seen.insert(key) {
+                    output.push(value.clone());
+                }
+            }
+        }
+
+        return Ok(output);
+    }
+
+    return crate::env::with(|context, unit| {
+        let mut vm = Vm::new(context.clone(), unit.clone());
+        let mut seen = std::vec::Vec::new();
+        let mut output = Vec::new();
+
+        for value in a.iter() {
+            if keep(op, contains(&mut vm, b, value)?) && !contains(&mut vm, &seen, value)? {
+                seen.push(value.clone());
+                output.push(value.clone());
+            }
+        }
+
+        if let SetOp::Union = op {
+            for value in b.iter() {
+                if !contains(&mut vm, &seen, value)? {
+                    seen.push(value.clone());
+                    output.push(value.clone());
+                }
+            }
+        }
+
+        Ok(output)
+    });
+
+    fn keys(vec: &Vec) -> Result<std::vec::Vec<Key>, VmError> {
+        vec.iter().map(Key::from_value).collect()
+    }
+
+    fn keep(op: SetOp, in_other: bool) -> bool {
+        match op {
+            SetOp::Intersection => in_other,
+            SetOp::Union => true,
+            SetOp::Difference => !in_other,
+        }
+    }
+
+    fn contains(vm: &mut Vm, values: &[Value], value: &Value) -> Result<bool, VmError> {
+        for v in values {
+            if Value::value_ptr_eq(vm, v, value)? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
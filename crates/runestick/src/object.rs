@@ -1,4 +1,5 @@
 use crate::collections::{btree_map, BTreeMap};
+use crate::debug_limit;
 use crate::{
     FromValue, InstallWith, Item, Mut, Named, RawMut, RawRef, RawStr, Ref, ToValue,
     UnsafeFromValue, Value, Vm, VmError,
@@ -191,25 +192,25 @@ impl Object {
         self.inner
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order.
+    /// An iterator visiting all key-value pairs in order by key.
     /// The iterator element type is `(&'a String, &'a Value)`.
     pub fn iter(&self) -> Iter<'_> {
         self.inner.iter()
     }
 
-    /// An iterator visiting all keys in arbitrary order.
+    /// An iterator visiting all keys in order by key.
     /// The iterator element type is `&'a String`.
     pub fn keys(&self) -> Keys<'_> {
         self.inner.keys()
     }
 
-    /// An iterator visiting all values in arbitrary order.
+    /// An iterator visiting all values in order by key.
     /// The iterator element type is `&'a Value`.
     pub fn values(&self) -> Values<'_> {
         self.inner.values()
     }
 
-    /// An iterator visiting all key-value pairs in arbitrary order,
+    /// An iterator visiting all key-value pairs in order by key,
     /// with mutable references to the values.
     /// The iterator element type is `(&'a String, &'a mut Value)`.
     pub fn iter_mut(&mut self) -> IterMut<'_> {
@@ -256,7 +257,7 @@ impl IntoIterator for Object {
     type IntoIter = IntoIter;
 
     /// Creates a consuming iterator, that is, one that moves each key-value
-    /// pair out of the object in arbitrary order. The object cannot be used
+    /// pair out of the object in order by key. The object cannot be used
     /// after calling this.
     fn into_iter(self) -> Self::IntoIter {
         self.inner.into_iter()
@@ -265,7 +266,19 @@ impl IntoIterator for Object {
 
 impl fmt::Debug for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_map().entries(self.inner.iter()).finish()
+        let entries = debug_limit::enter(self.inner.len());
+        let shown = self.inner.iter().take(entries.shown());
+
+        // NB: a debug map can't hold the marker for left out entries, so they
+        // are formatted as a debug set instead which looks the same.
+        match entries.omitted() {
+            Some(omitted) => f
+                .debug_set()
+                .entries(shown.map(|(key, value)| Entry::key(key, value)))
+                .entry(&omitted)
+                .finish(),
+            None => f.debug_map().entries(shown).finish(),
+        }
     }
 }
 
@@ -342,13 +355,80 @@ pub struct DebugStruct<'a> {
 
 impl fmt::Display for DebugStruct<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut d = f.debug_struct(&self.item.to_string());
+        let entries = debug_limit::enter(self.st.len());
+        let shown = self.st.iter().take(entries.shown());
+
+        let omitted = match entries.omitted() {
+            Some(omitted) => omitted,
+            None => {
+                let mut d = f.debug_struct(&self.item.to_string());
+
+                for (key, value) in shown {
+                    d.field(key, value);
+                }
+
+                return d.finish();
+            }
+        };
+
+        // NB: a debug struct can't hold the marker for left out fields, so
+        // they are written out to look the same.
+        if f.alternate() {
+            write!(f, "{} ", self.item)?;
+
+            return f
+                .debug_set()
+                .entries(shown.map(|(key, value)| Entry::field(key, value)))
+                .entry(&omitted)
+                .finish();
+        }
+
+        write!(f, "{} {{ ", self.item)?;
+
+        for (key, value) in shown {
+            write!(f, "{:?}, ", Entry::field(key, value))?;
+        }
+
+        write!(f, "{:?} }}", omitted)
+    }
+}
+
+/// An entry which is debug formatted as `key: value`.
+struct Entry<'a> {
+    key: &'a str,
+    value: &'a Value,
+    /// If the key is the name of a field, which is printed without quotes.
+    field: bool,
+}
+
+impl<'a> Entry<'a> {
+    fn key(key: &'a str, value: &'a Value) -> Self {
+        Self {
+            key,
+            value,
+            field: false,
+        }
+    }
 
-        for (key, value) in self.st.iter() {
-            d.field(key, value);
+    fn field(key: &'a str, value: &'a Value) -> Self {
+        Self {
+            key,
+            value,
+            field: true,
+        }
+    }
+}
+
+impl fmt::Debug for Entry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field {
+            f.write_str(self.key)?;
+        } else {
+            fmt::Debug::fmt(self.key, f)?;
         }
 
-        d.finish()
+        f.write_str(": ")?;
+        fmt::Debug::fmt(self.value, f)
     }
 }
 
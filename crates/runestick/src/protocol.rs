@@ -127,6 +127,18 @@ impl Protocol {
         hash: Hash::new(0x29a54b727f980ebf),
     };
 
+    /// The function to implement for the exponent operation.
+    pub const POW: Protocol = Protocol {
+        name: "**",
+        hash: Hash::new(0x850efdb74201be56),
+    };
+
+    /// The function to implement for the exponent assign operation.
+    pub const POW_ASSIGN: Protocol = Protocol {
+        name: "**=",
+        hash: Hash::new(0x3ab94596fb5a635d),
+    };
+
     /// The function to implement for the division operation.
     pub const DIV: Protocol = Protocol {
         name: "/",
@@ -235,6 +247,25 @@ impl Protocol {
         hash: Hash::new(0xc3cde069de2ba320),
     };
 
+    /// Function used to get the length of a collection.
+    ///
+    /// This has the same hash as an instance function named `len`, so
+    /// implementing the protocol makes `.len()` available in scripts.
+    pub const LEN: Protocol = Protocol {
+        name: "len",
+        hash: Hash::new(0x781ce796b98306a2),
+    };
+
+    /// Function used to test if a collection is empty.
+    ///
+    /// This has the same hash as an instance function named `is_empty`. Types
+    /// which implement [Protocol::LEN] natively but not this protocol get an
+    /// `is_empty` function derived from their length.
+    pub const IS_EMPTY: Protocol = Protocol {
+        name: "is_empty",
+        hash: Hash::new(0x931f454f502a9538),
+    };
+
     /// Function used to convert an argument into a future.
     pub const INTO_FUTURE: Protocol = Protocol {
         name: "into_future",
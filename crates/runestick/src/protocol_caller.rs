@@ -1,4 +1,8 @@
-use crate::{GuardedArgs, Hash, Protocol, Stack, UnitFn, Value, Vm, VmError, VmErrorKind};
+use crate::{
+    Call, GuardedArgs, Hash, Protocol, RuntimeContext, Stack, Unit, UnitFn, Value, Vm, VmError,
+    VmErrorKind,
+};
+use std::sync::Arc;
 
 /// Trait used for integrating an instance function call.
 pub(crate) trait ProtocolCaller {
@@ -11,6 +15,10 @@ pub(crate) trait ProtocolCaller {
     ) -> Result<Value, VmError>
     where
         A: GuardedArgs;
+
+    /// Test if the unit implements the given protocol function for the target,
+    /// like the ones derived for script types.
+    fn has_unit_protocol_fn(&self, protocol: Protocol, target: &Value) -> Result<bool, VmError>;
 }
 
 /// Use the global environment caller.
@@ -28,7 +36,7 @@ impl ProtocolCaller for EnvProtocolCaller {
     where
         A: GuardedArgs,
     {
-        return crate::env::with(|context, unit| {
+        crate::env::with(|context, unit| {
             let count = args.count() + 1;
             let hash = Hash::instance_function(target.type_hash()?, protocol.hash);
 
@@ -38,17 +46,7 @@ impl ProtocolCaller for EnvProtocolCaller {
                 call,
             }) = unit.lookup(hash)
             {
-                check_args(count, expected)?;
-
-                let mut stack = Stack::with_capacity(count);
-                stack.push(target);
-
-                // Safety: We hold onto the guard until the vm has completed.
-                let _guard = unsafe { args.unsafe_into_stack(&mut stack)? };
-
-                let mut vm = Vm::new_with_stack(context.clone(), unit.clone(), stack);
-                vm.set_ip(offset);
-                return call.call_with_vm(vm);
+                return call_unit_fn(context, unit, offset, call, expected, target, args);
             }
 
             let handler = match context.lookup(hash) {
@@ -62,21 +60,13 @@ impl ProtocolCaller for EnvProtocolCaller {
             // Safety: We hold onto the guard until the vm has completed.
             let _guard = unsafe { args.unsafe_into_stack(&mut stack)? };
 
-            handler(&mut stack, count)?;
+            context.call_handler(handler, &mut stack, count)?;
             Ok(stack.pop()?)
-        });
-
-        /// Check that arguments matches expected or raise the appropriate error.
-        fn check_args(args: usize, expected: usize) -> Result<(), VmError> {
-            if args != expected {
-                return Err(VmError::from(VmErrorKind::BadArgumentCount {
-                    actual: args,
-                    expected,
-                }));
-            }
+        })
+    }
 
-            Ok(())
-        }
+    fn has_unit_protocol_fn(&self, protocol: Protocol, target: &Value) -> Result<bool, VmError> {
+        crate::env::with(|_, unit| has_unit_protocol_fn(unit, protocol, target))
     }
 }
 
@@ -90,6 +80,21 @@ impl ProtocolCaller for &mut Vm {
     where
         A: GuardedArgs,
     {
+        let hash = Hash::instance_function(target.type_hash()?, protocol.hash);
+
+        // NB: functions in the unit can't be called in place, since the
+        // caller expects the result right away. So run them to completion on
+        // a virtual machine of their own.
+        if let Some(UnitFn::Offset {
+            offset,
+            args: expected,
+            call,
+        }) = self.unit().lookup(hash)
+        {
+            let (context, unit) = (self.context(), self.unit());
+            return call_unit_fn(context, unit, offset, call, expected, target, args);
+        }
+
         if !self.call_instance_fn(target, protocol, args)? {
             return Err(VmError::from(VmErrorKind::MissingFunction {
                 hash: protocol.hash,
@@ -98,4 +103,47 @@ impl ProtocolCaller for &mut Vm {
 
         Ok(self.stack.pop()?)
     }
+
+    fn has_unit_protocol_fn(&self, protocol: Protocol, target: &Value) -> Result<bool, VmError> {
+        has_unit_protocol_fn(self.unit(), protocol, target)
+    }
+}
+
+/// Test if the unit implements the given protocol function for the target.
+fn has_unit_protocol_fn(unit: &Unit, protocol: Protocol, target: &Value) -> Result<bool, VmError> {
+    let hash = Hash::instance_function(target.type_hash()?, protocol.hash);
+    Ok(matches!(unit.lookup(hash), Some(UnitFn::Offset { .. })))
+}
+
+/// Call the unit function at the given offset on a virtual machine of its own.
+fn call_unit_fn<A>(
+    context: &Arc<RuntimeContext>,
+    unit: &Arc<Unit>,
+    offset: usize,
+    call: Call,
+    expected: usize,
+    target: Value,
+    args: A,
+) -> Result<Value, VmError>
+where
+    A: GuardedArgs,
+{
+    let count = args.count() + 1;
+
+    if count != expected {
+        return Err(VmError::from(VmErrorKind::BadArgumentCount {
+            actual: count,
+            expected,
+        }));
+    }
+
+    let mut stack = Stack::with_capacity(count);
+    stack.push(target);
+
+    // Safety: We hold onto the guard until the vm has completed.
+    let _guard = unsafe { args.unsafe_into_stack(&mut stack)? };
+
+    let mut vm = Vm::new_with_stack(context.clone(), unit.clone(), stack);
+    vm.set_ip(offset);
+    call.call_with_vm(vm)
 }
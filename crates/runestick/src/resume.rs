@@ -0,0 +1,85 @@
+//! Support for native functions which cooperatively yield back to the virtual
+//! machine, see [Module::resumable_fn][crate::Module::resumable_fn].
+
+use crate::Value;
+use std::cell::RefCell;
+
+thread_local! { static SLOT: RefCell<Option<Slot>> = const { RefCell::new(None) } }
+
+/// The outcome of a single call to a resumable native function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NativeStep {
+    /// The function completed, after popping its arguments and pushing its
+    /// return value onto the stack.
+    Complete,
+    /// The function yields back to the virtual machine, leaving its arguments
+    /// on the stack. It's called again with the same arguments and state once
+    /// the virtual machine resumes.
+    Yield,
+}
+
+/// The state of a resumable native function which yielded.
+#[derive(Debug, Clone)]
+pub(crate) struct Suspended {
+    state: Option<Value>,
+}
+
+struct Slot {
+    /// The state to resume with, until it's taken by the called function.
+    resume: Option<Option<Value>>,
+    /// The state of the called function if it yielded.
+    suspended: Option<Option<Value>>,
+}
+
+/// Call the given function, which calls a resumable native function that is
+/// allowed to yield.
+///
+/// `suspended` is the state of a previously suspended call which is being
+/// resumed. Returns the state to resume with if the function yielded.
+pub(crate) fn call<E>(
+    suspended: Option<Suspended>,
+    f: impl FnOnce() -> Result<(), E>,
+) -> Result<Option<Suspended>, E> {
+    let slot = Slot {
+        resume: Some(suspended.and_then(|s| s.state)),
+        suspended: None,
+    };
+
+    let guard = SlotGuard(SLOT.with(|tls| tls.borrow_mut().replace(slot)));
+    f()?;
+
+    let slot = SLOT.with(|tls| tls.borrow_mut().take());
+    drop(guard);
+
+    Ok(slot
+        .and_then(|slot| slot.suspended)
+        .map(|state| Suspended { state }))
+}
+
+/// Take the state of the resumable native function which is being called.
+///
+/// Returns `None` if the function is not allowed to yield, like when it's
+/// called through a function pointer or by another native function.
+pub(crate) fn take_state() -> Option<Option<Value>> {
+    SLOT.with(|tls| tls.borrow_mut().as_mut()?.resume.take())
+}
+
+/// Suspend the resumable native function which is being called with the given
+/// state.
+pub(crate) fn suspend(state: Option<Value>) {
+    SLOT.with(|tls| {
+        if let Some(slot) = tls.borrow_mut().as_mut() {
+            slot.suspended = Some(state);
+        }
+    });
+}
+
+/// Restores the previous slot when dropped, so that calls can nest.
+struct SlotGuard(Option<Slot>);
+
+impl Drop for SlotGuard {
+    fn drop(&mut self) {
+        let slot = self.0.take();
+        SLOT.with(|tls| *tls.borrow_mut() = slot);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::collections::HashMap;
 use crate::context::Handler;
-use crate::{ConstValue, Hash, Item, TypeCheck};
+use crate::{ConstValue, Hash, Item, Stack, TypeCheck, TypeInfo, VmError, VmErrorKind};
+use std::any::Any;
 use std::fmt;
+use std::panic;
 use std::sync::Arc;
 
 /// Static run context visible to the virtual machine.
@@ -13,13 +15,26 @@ use std::sync::Arc;
 #[derive(Default)]
 pub struct RuntimeContext {
     /// Registered native function handlers.
-    pub(crate) functions: HashMap<Hash, Arc<Handler>>,
+    pub(crate) functions: HashMap<Hash, FunctionHandler>,
+
+    /// Conversions between types, keyed by the hashes of the types converted
+    /// from and to.
+    pub(crate) conversions: HashMap<(Hash, Hash), Arc<Handler>>,
 
     /// Registered types.
     pub(crate) types: HashMap<Hash, TypeCheck>,
 
+    /// Information on registered types, keyed by the hash of their values.
+    pub(crate) type_infos: HashMap<Hash, TypeInfo>,
+
     /// Named constant values
     pub(crate) constants: HashMap<Hash, ConstValue>,
+
+    /// Convert panics in native functions into errors.
+    pub(crate) catch_native_panics: bool,
+
+    /// Promote integers which overflow into big integers.
+    pub(crate) promote_integers: bool,
 }
 
 impl RuntimeContext {
@@ -35,13 +50,92 @@ impl RuntimeContext {
 
     /// Lookup the given native function handler in the context.
     pub fn lookup(&self, hash: Hash) -> Option<&Arc<Handler>> {
+        Some(&self.functions.get(&hash)?.handler)
+    }
+
+    /// Lookup the given native function in the context, including whether
+    /// it's allowed to yield back to the virtual machine.
+    pub(crate) fn lookup_function(&self, hash: Hash) -> Option<&FunctionHandler> {
         self.functions.get(&hash)
     }
 
+    /// Lookup the handler converting a value of the type `from` into the type
+    /// `to`.
+    pub(crate) fn lookup_conversion(&self, from: Hash, to: Hash) -> Option<&Arc<Handler>> {
+        self.conversions.get(&(from, to))
+    }
+
+    /// Lookup information on the registered type whose values have the given
+    /// hash.
+    pub(crate) fn lookup_type_info(&self, hash: Hash) -> Option<&TypeInfo> {
+        self.type_infos.get(&hash)
+    }
+
     /// Read a constant value from the unit.
     pub fn constant(&self, hash: Hash) -> Option<&ConstValue> {
         self.constants.get(&hash)
     }
+
+    /// Configure whether panics in native functions should be caught and
+    /// converted into a [VmErrorKind::NativePanic] error instead of unwinding
+    /// through the virtual machine.
+    ///
+    /// This is disabled by default.
+    pub fn set_catch_native_panics(&mut self, enabled: bool) {
+        self.catch_native_panics = enabled;
+    }
+
+    /// Configure whether integer arithmetic which overflows should promote the
+    /// result into an arbitrary precision integer instead of raising an error.
+    ///
+    /// Results which fit into an `i64` are demoted back into regular integers.
+    ///
+    /// This is disabled by default.
+    pub fn set_promote_integers(&mut self, enabled: bool) {
+        self.promote_integers = enabled;
+    }
+
+    /// Call the given native function handler, catching panics if configured
+    /// to do so.
+    #[inline]
+    pub(crate) fn call_handler(
+        &self,
+        handler: &Arc<Handler>,
+        stack: &mut Stack,
+        args: usize,
+    ) -> Result<(), VmError> {
+        if !self.catch_native_panics {
+            return handler(stack, args);
+        }
+
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| handler(stack, args))) {
+            Ok(result) => result,
+            Err(payload) => Err(VmError::from(VmErrorKind::NativePanic {
+                message: panic_message(&*payload),
+            })),
+        }
+    }
+}
+
+/// A native function handler registered in the runtime context.
+pub(crate) struct FunctionHandler {
+    /// The handler to call.
+    pub(crate) handler: Arc<Handler>,
+    /// Whether the function is allowed to yield back to the virtual machine.
+    pub(crate) resumable: bool,
+}
+
+/// Extract the message from a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        return (*message).to_owned();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    String::from("Box<dyn Any>")
 }
 
 impl fmt::Debug for RuntimeContext {
@@ -268,6 +268,24 @@ impl<T> Shared<T> {
 }
 
 impl<T: ?Sized> Shared<T> {
+    /// Test if two shared values refer to the same allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use runestick::Shared;
+    ///
+    /// let a = Shared::new(1u32);
+    /// let b = a.clone();
+    /// let c = Shared::new(1u32);
+    ///
+    /// assert!(Shared::ptr_eq(&a, &b));
+    /// assert!(!Shared::ptr_eq(&a, &c));
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.inner.cast::<()>() == other.inner.cast::<()>()
+    }
+
     /// Get a reference to the interior value while checking for shared access.
     ///
     /// This prevents other exclusive accesses from being performed while the
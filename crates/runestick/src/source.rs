@@ -36,6 +36,22 @@ impl Source {
         }
     }
 
+    /// Construct a new source with the given name, which is associated with
+    /// the given path.
+    ///
+    /// The name is used in diagnostics, while the path is used to load
+    /// modules relative to the source.
+    pub fn with_path<N, S, P>(name: N, source: S, path: P) -> Self
+    where
+        N: AsRef<str>,
+        S: AsRef<str>,
+        P: AsRef<Path>,
+    {
+        let mut this = Self::new(name, source);
+        this.path = Some(path.as_ref().to_owned());
+        this
+    }
+
     /// Access all line starts in the source.
     pub fn line_starts(&self) -> &[usize] {
         &self.line_starts
@@ -67,7 +83,8 @@ impl Source {
         self.source.len()
     }
 
-    /// Get the name of the source.
+    /// Get the name of the source, which is used to refer to it in
+    /// diagnostics.
     pub fn name(&self) -> &str {
         &self.name
     }
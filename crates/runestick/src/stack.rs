@@ -11,6 +11,12 @@ use thiserror::Error;
 pub struct StackError(());
 
 /// The stack of the virtual machine, where all values are stored.
+///
+/// The stack is backed by a single contiguous vector which grows on demand.
+/// Growth follows the amortized strategy of [Vec], so pushing values is
+/// amortized `O(1)` but the exact growth factor is unspecified. If the required
+/// size is known up front, use [Stack::with_capacity] or [Stack::reserve] to
+/// avoid reallocating at all.
 #[derive(Debug, Clone)]
 pub struct Stack {
     /// The current stack of values.
@@ -31,6 +37,8 @@ impl Stack {
     }
 
     /// Extend the current stack.
+    ///
+    /// See [Stack::push_iter] to push values which first have to be converted.
     pub fn extend<I>(&mut self, iter: I)
     where
         I: IntoIterator<Item = Value>,
@@ -51,6 +59,16 @@ impl Stack {
         }
     }
 
+    /// Get the number of values the stack can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+
+    /// Reserve capacity for at least `additional` more values on the stack.
+    pub fn reserve(&mut self, additional: usize) {
+        self.stack.reserve(additional);
+    }
+
     /// Clear the current stack.
     pub fn clear(&mut self) {
         self.stack.clear();
@@ -120,6 +138,16 @@ impl Stack {
         self.stack.push(Value::from(value));
     }
 
+    /// Push every value produced by the given iterator onto the stack, in
+    /// order.
+    pub fn push_iter<I>(&mut self, iter: I)
+    where
+        I: IntoIterator,
+        Value: From<I::Item>,
+    {
+        self.stack.extend(iter.into_iter().map(Value::from));
+    }
+
     /// Pop a reference to a value from the stack.
     pub fn pop(&mut self) -> Result<Value, StackError> {
         if self.stack.len() == self.stack_bottom {
@@ -168,6 +196,17 @@ impl Stack {
 
     /// Pop a sequence of values from the stack.
     pub fn pop_sequence(&mut self, count: usize) -> Result<Vec<Value>, StackError> {
+        self.pop_n(count)
+    }
+
+    /// Pop the top `count` values from the stack, like the arguments passed to
+    /// a raw function.
+    ///
+    /// The values are returned in the order that they were pushed, which for
+    /// arguments is the order they were passed in. Errors without modifying
+    /// the stack if there are fewer than `count` values in the current stack
+    /// frame.
+    pub fn pop_n(&mut self, count: usize) -> Result<Vec<Value>, StackError> {
         Ok(self.drain_stack_top(count)?.collect::<Vec<_>>())
     }
 
@@ -97,6 +97,25 @@ where
     }
 }
 
+/// Errors which are boxed as trait objects are raised in the virtual machine,
+/// so any error implementing [std::error::Error] can be propagated with `?`
+/// from a native function.
+///
+/// Errors which the script should be able to match on instead have to
+/// implement [ToValue], like through `#[derive(Any)]`, in which case they're
+/// passed to the script as `Err(error)`.
+impl<T> ToValue for Result<T, Box<dyn std::error::Error + Send + Sync>>
+where
+    T: ToValue,
+{
+    fn to_value(self) -> Result<Value, VmError> {
+        match self {
+            Ok(value) => Ok(value.to_value()?),
+            Err(error) => Err(VmError::from(VmErrorKind::Custom { error })),
+        }
+    }
+}
+
 impl<T, E> ToValue for Result<T, E>
 where
     T: ToValue,
@@ -149,6 +168,8 @@ number_value_trait!(i32);
 number_value_trait!(i128);
 number_value_trait!(isize);
 
+/// Every `f32` is exactly representable as an `f64`, so this conversion is
+/// lossless.
 impl ToValue for f32 {
     fn to_value(self) -> Result<Value, VmError> {
         Ok(Value::Float(self as f64))
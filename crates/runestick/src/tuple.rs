@@ -1,3 +1,4 @@
+use crate::debug_limit;
 use crate::{ConstValue, FromValue, Mut, Ref, Value, Vm, VmError};
 use std::fmt;
 use std::ops;
@@ -74,9 +75,11 @@ impl Tuple {
 
 impl fmt::Debug for Tuple {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = debug_limit::enter(self.inner.len());
+
         write!(f, "(")?;
 
-        let mut it = self.inner.iter();
+        let mut it = self.inner[..entries.shown()].iter();
         let last = it.next_back();
 
         for el in it {
@@ -87,6 +90,14 @@ impl fmt::Debug for Tuple {
             write!(f, "{:?}", last)?;
         }
 
+        if let Some(omitted) = entries.omitted() {
+            if last.is_some() {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{:?}", omitted)?;
+        }
+
         write!(f, ")")?;
         Ok(())
     }
@@ -190,6 +201,7 @@ macro_rules! impl_tuple {
             $($ty: $crate::FromValue,)*
         {
             fn from_value(value: $crate::Value) -> Result<Self, $crate::VmError> {
+                let _guard = $crate::depth::enter()?;
                 let tuple = value.into_tuple()?.take()?;
 
                 if tuple.len() != $count {
@@ -3,16 +3,44 @@
 //! A unit consists of a sequence of instructions, and lookaside tables for
 //! metadata like function locations.
 
-use crate::collections::HashMap;
+use crate::collections::{HashMap, HashSet};
+use crate::debug::DebugSignature;
 use crate::{
-    Call, ConstValue, DebugInfo, Hash, Inst, Rtti, StaticString, VariantRtti, VmError, VmErrorKind,
+    Call, ConstValue, DebugInfo, Hash, Inst, InstTarget, Item, Rtti, Span, StaticString,
+    VariantRtti, VmError, VmErrorKind,
 };
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::ops::Range;
 use std::sync::Arc;
+use thiserror::Error;
+
+/// The version of the format used by [Unit::to_bytes]. Bump this whenever the
+/// serialized representation of a unit changes.
+const UNIT_FORMAT_VERSION: u32 = 1;
+
+/// Error raised when serializing or deserializing a [Unit].
+#[derive(Debug, Error)]
+pub enum UnitFormatError {
+    /// The unit was serialized with a different version of the format.
+    #[error("unit has format version {actual}, but expected version {expected}")]
+    UnsupportedVersion {
+        /// The version of the format the unit was serialized with.
+        actual: u32,
+        /// The version of the format supported by this crate.
+        expected: u32,
+    },
+    /// The unit could not be encoded or decoded.
+    #[error("failed to encode or decode unit: {error}")]
+    Bincode {
+        /// The source error.
+        #[from]
+        error: bincode::Error,
+    },
+}
 
 /// Instructions from a single source file.
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Unit {
     /// The instructions contained in the source file.
     instructions: Vec<Inst>,
@@ -66,12 +94,48 @@ impl Unit {
         }
     }
 
+    /// Serialize the unit into bytes, which can be loaded again with
+    /// [Unit::from_bytes].
+    ///
+    /// The bytes are prefixed with the version of the format, so that units
+    /// serialized by an incompatible version of this crate are rejected.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, UnitFormatError> {
+        let mut bytes = bincode::serialize(&UNIT_FORMAT_VERSION)?;
+        bincode::serialize_into(&mut bytes, self)?;
+        Ok(bytes)
+    }
+
+    /// Deserialize a unit from bytes produced by [Unit::to_bytes].
+    ///
+    /// Errors with [UnitFormatError::UnsupportedVersion] if the unit was
+    /// serialized with a different version of the format.
+    pub fn from_bytes(mut bytes: &[u8]) -> Result<Self, UnitFormatError> {
+        let version: u32 = bincode::deserialize_from(&mut bytes)?;
+
+        if version != UNIT_FORMAT_VERSION {
+            return Err(UnitFormatError::UnsupportedVersion {
+                actual: version,
+                expected: UNIT_FORMAT_VERSION,
+            });
+        }
+
+        Ok(bincode::deserialize(bytes)?)
+    }
+
     /// Access debug information for the given location if it is available.
     pub fn debug_info(&self) -> Option<&DebugInfo> {
         let debug = self.debug.as_ref()?;
         Some(&**debug)
     }
 
+    /// Get the text of the source with the given id covered by the span.
+    ///
+    /// This is only available if the unit was compiled with sources embedded,
+    /// otherwise `None` is returned.
+    pub fn source_snippet(&self, source_id: usize, span: Span) -> Option<&str> {
+        self.debug_info()?.source_snippet(source_id, span)
+    }
+
     /// Get the instruction at the given instruction pointer.
     pub fn instruction_at(&self, ip: usize) -> Option<&Inst> {
         self.instructions.get(ip)
@@ -139,10 +203,330 @@ impl Unit {
         self.functions.get(&hash).copied()
     }
 
+    /// Lookup the debug signature of a function, if debug information is
+    /// available.
+    pub fn lookup_signature(&self, hash: Hash) -> Option<&DebugSignature> {
+        self.debug_info()?.functions.get(&hash)
+    }
+
     /// Read a constant value from the unit.
     pub fn constant(&self, hash: Hash) -> Option<&ConstValue> {
         self.constants.get(&hash)
     }
+
+    /// Replace the body of the function with the given `hash` with the body of
+    /// the function with the same hash in `from`.
+    ///
+    /// The new body overwrites the old one if it fits, and is otherwise
+    /// appended to this unit with the function pointed to it.
+    /// Closures, async blocks and other functions which are nested under the
+    /// replaced function are always copied along with it, as are functions
+    /// which the new body references but which only exist in `from`. Beyond
+    /// that, the new body may only call functions and use types which are
+    /// also present in this unit.
+    ///
+    /// Errors with [VmErrorKind::MissingFunction] if either unit doesn't
+    /// have the function, and with [VmErrorKind::FunctionSignatureMismatch]
+    /// if the number of arguments or the way it's called differs.
+    pub fn replace_function(&mut self, hash: Hash, from: &Unit) -> Result<(), VmError> {
+        self.replace_function_with(hash, from, |_| false)
+    }
+
+    /// Replace the body of a function like [Unit::replace_function], where
+    /// `is_running` tests if any instruction in the given range is being
+    /// executed. The body of a function which is being executed is never
+    /// overwritten, so that it can finish on the old body.
+    pub(crate) fn replace_function_with(
+        &mut self,
+        hash: Hash,
+        from: &Unit,
+        is_running: impl Fn(Range<usize>) -> bool,
+    ) -> Result<(), VmError> {
+        let (old_offset, old_call, old_args) = match self.functions.get(&hash) {
+            Some(UnitFn::Offset { offset, call, args }) => (*offset, *call, *args),
+            _ => return Err(VmError::from(VmErrorKind::MissingFunction { hash })),
+        };
+
+        let (start, call, args) = match from.functions.get(&hash) {
+            Some(UnitFn::Offset { offset, call, args }) => (*offset, *call, *args),
+            _ => return Err(VmError::from(VmErrorKind::MissingFunction { hash })),
+        };
+
+        if call != old_call || args != old_args {
+            return Err(VmError::from(VmErrorKind::FunctionSignatureMismatch {
+                hash,
+            }));
+        }
+
+        let old = self.function_region(old_offset);
+        let old = Some(old).filter(|old| !is_running(old.clone()));
+        let (offset, mut referenced) = self.relocate_function(hash, start, from, old)?;
+        self.repoint_functions(old_offset, offset);
+
+        // NB: closures and async blocks are named after the function they're
+        // declared in, so they are replaced along with it even if this unit
+        // already has a function with the same hash. Other functions are only
+        // copied if they only exist in `from`.
+        let item = from
+            .debug_info()
+            .and_then(|debug| debug.functions.get(&hash))
+            .map(|signature| &signature.path);
+
+        let mut visited = HashSet::new();
+        visited.insert(hash);
+
+        while let Some((hash, closure)) = referenced.pop() {
+            if !visited.insert(hash) {
+                continue;
+            }
+
+            let nested = closure || from.is_nested(item, hash);
+
+            if !nested && self.functions.contains_key(&hash) {
+                continue;
+            }
+
+            let (start, call, args) = match from.functions.get(&hash) {
+                Some(UnitFn::Offset { offset, call, args }) => (*offset, *call, *args),
+                _ => continue,
+            };
+
+            let old_offset = match self.functions.get(&hash) {
+                Some(UnitFn::Offset { offset, .. }) => Some(*offset),
+                _ => None,
+            };
+
+            let old = old_offset
+                .map(|offset| self.function_region(offset))
+                .filter(|old| !is_running(old.clone()));
+
+            let (offset, nested) = self.relocate_function(hash, start, from, old)?;
+
+            if let Some(old_offset) = old_offset {
+                self.repoint_functions(old_offset, offset);
+            }
+
+            self.functions
+                .insert(hash, UnitFn::Offset { offset, call, args });
+            referenced.extend(nested);
+        }
+
+        Ok(())
+    }
+
+    /// Point every function whose body starts at `old` to `new` instead.
+    ///
+    /// NB: the same body can be registered under more than one hash, like
+    /// instance functions which are also callable by their full path.
+    fn repoint_functions(&mut self, old: usize, new: usize) {
+        for f in self.functions.values_mut() {
+            if let UnitFn::Offset { offset, .. } = f {
+                if *offset == old {
+                    *offset = new;
+                }
+            }
+        }
+    }
+
+    /// The range of instructions making up the body of the function starting
+    /// at `start`.
+    ///
+    /// NB: functions are laid out one after another, so the body ends where
+    /// the next function begins.
+    fn function_region(&self, start: usize) -> Range<usize> {
+        let end = self
+            .functions
+            .values()
+            .filter_map(|f| match f {
+                UnitFn::Offset { offset, .. } if *offset > start => Some(*offset),
+                _ => None,
+            })
+            .min()
+            .unwrap_or(self.instructions.len());
+
+        start..end
+    }
+
+    /// Test if the function with the given `hash` is declared inside of
+    /// `item`, according to debug info.
+    fn is_nested(&self, item: Option<&Item>, hash: Hash) -> bool {
+        let (item, debug) = match (item, self.debug_info()) {
+            (Some(item), Some(debug)) => (item, debug),
+            _ => return false,
+        };
+
+        match debug.functions.get(&hash) {
+            Some(signature) => signature.path != *item && signature.path.starts_with(item),
+            None => false,
+        }
+    }
+
+    /// Copy the body of the function with the given `hash` starting at
+    /// `start` in `from` into this unit.
+    ///
+    /// The body overwrites the `old` region if it fits, or if the region is at
+    /// the end of the unit so that it can grow. Otherwise it's appended.
+    ///
+    /// Returns the offset of the copied body, and the hashes of the functions
+    /// it references, and whether they are referenced as closures.
+    fn relocate_function(
+        &mut self,
+        hash: Hash,
+        start: usize,
+        from: &Unit,
+        old: Option<Range<usize>>,
+    ) -> Result<(usize, Vec<(Hash, bool)>), VmError> {
+        let region = from.function_region(start);
+
+        let body = from
+            .instructions
+            .get(region.clone())
+            .ok_or(VmErrorKind::IpOutOfBounds)?;
+
+        let mut referenced = Vec::new();
+        let mut relocated = Vec::with_capacity(body.len());
+
+        for inst in body {
+            match *inst {
+                Inst::Call { hash, .. } | Inst::LoadFn { hash } => {
+                    referenced.push((hash, false));
+                }
+                Inst::Closure { hash, .. } => {
+                    referenced.push((hash, true));
+                }
+                _ => (),
+            }
+
+            let inst = match *inst {
+                Inst::ObjectIndexGet { slot } => Inst::ObjectIndexGet {
+                    slot: self.relocate_string(from, slot)?,
+                },
+                Inst::ObjectIndexSet { slot } => Inst::ObjectIndexSet {
+                    slot: self.relocate_string(from, slot)?,
+                },
+                Inst::ObjectIndexGetAt { offset, slot } => Inst::ObjectIndexGetAt {
+                    offset,
+                    slot: self.relocate_string(from, slot)?,
+                },
+                Inst::Assign {
+                    target: InstTarget::Field(slot),
+                    op,
+                } => Inst::Assign {
+                    target: InstTarget::Field(self.relocate_string(from, slot)?),
+                    op,
+                },
+                Inst::String { slot } => Inst::String {
+                    slot: self.relocate_string(from, slot)?,
+                },
+                Inst::EqStaticString { slot } => Inst::EqStaticString {
+                    slot: self.relocate_string(from, slot)?,
+                },
+                Inst::Bytes { slot } => Inst::Bytes {
+                    slot: self.relocate_bytes(from, slot)?,
+                },
+                Inst::EqStaticBytes { slot } => Inst::EqStaticBytes {
+                    slot: self.relocate_bytes(from, slot)?,
+                },
+                Inst::Object { slot } => Inst::Object {
+                    slot: self.relocate_object_keys(from, slot)?,
+                },
+                Inst::Struct { hash, slot } => Inst::Struct {
+                    hash,
+                    slot: self.relocate_object_keys(from, slot)?,
+                },
+                Inst::StructVariant { hash, slot } => Inst::StructVariant {
+                    hash,
+                    slot: self.relocate_object_keys(from, slot)?,
+                },
+                Inst::MatchObject {
+                    type_check,
+                    slot,
+                    exact,
+                } => Inst::MatchObject {
+                    type_check,
+                    slot: self.relocate_object_keys(from, slot)?,
+                    exact,
+                },
+                inst => inst,
+            };
+
+            relocated.push(inst);
+        }
+
+        let len = self.instructions.len();
+
+        let reused = old
+            .clone()
+            .filter(|old| relocated.len() <= old.len() || old.end == len);
+
+        let offset = match &reused {
+            Some(old) => old.start,
+            None => len,
+        };
+
+        // NB: a body which is written at the end of the unit can grow past
+        // the old one.
+        let overwritten = offset..usize::min(offset + relocated.len(), len);
+        let aligned = matches!(&self.debug, Some(debug) if debug.instructions.len() == len);
+        self.instructions.splice(overwritten.clone(), relocated);
+
+        // Keep debug information aligned with the instructions, if both units
+        // have it.
+        if let (Some(debug), Some(from)) = (&mut self.debug, from.debug_info()) {
+            if let (true, Some(body)) = (aligned, from.instructions.get(region)) {
+                debug.instructions.splice(overwritten, body.iter().cloned());
+
+                if let Some(old) = &old {
+                    debug.functions_rev.remove(&old.start);
+                }
+
+                // NB: variables aren't copied, so the ones of an overwritten
+                // body are stale.
+                if let Some(old) = &reused {
+                    let stale = debug
+                        .variables
+                        .range(old.clone())
+                        .map(|(ip, _)| *ip)
+                        .collect::<Vec<_>>();
+
+                    for ip in stale {
+                        debug.variables.remove(&ip);
+                    }
+                }
+
+                debug.functions_rev.insert(offset, hash);
+
+                if let Some(signature) = from.functions.get(&hash) {
+                    debug.functions.insert(hash, signature.clone());
+                }
+            }
+        }
+
+        Ok((offset, referenced))
+    }
+
+    /// Copy the static string in the given slot of `from` into this unit.
+    fn relocate_string(&mut self, from: &Unit, slot: usize) -> Result<usize, VmError> {
+        let string = from.lookup_string(slot)?.clone();
+        self.static_strings.push(string);
+        Ok(self.static_strings.len() - 1)
+    }
+
+    /// Copy the static byte string in the given slot of `from` into this unit.
+    fn relocate_bytes(&mut self, from: &Unit, slot: usize) -> Result<usize, VmError> {
+        let bytes = from.lookup_bytes(slot)?.to_vec();
+        self.static_bytes.push(bytes);
+        Ok(self.static_bytes.len() - 1)
+    }
+
+    /// Copy the object keys in the given slot of `from` into this unit.
+    fn relocate_object_keys(&mut self, from: &Unit, slot: usize) -> Result<usize, VmError> {
+        let keys = from
+            .lookup_object_keys(slot)
+            .ok_or(VmErrorKind::MissingStaticObjectKeys { slot })?;
+        self.static_object_keys.push(keys.into());
+        Ok(self.static_object_keys.len() - 1)
+    }
 }
 
 /// The kind and necessary information on registered functions.
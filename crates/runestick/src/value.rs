@@ -139,7 +139,7 @@ impl Struct {
 
 impl fmt::Debug for Struct {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.data.debug_struct(&self.rtti.item))
+        fmt::Display::fmt(&self.data.debug_struct(&self.rtti.item), f)
     }
 }
 
@@ -216,6 +216,10 @@ impl cmp::Ord for Rtti {
 }
 
 /// An entry on the stack.
+///
+/// Primitives like integers, floats, booleans and characters are stored
+/// inline, so operating on them never allocates. Everything else is reference
+/// counted, usually through [Shared].
 #[derive(Clone)]
 pub enum Value {
     /// The unit value.
@@ -283,18 +287,142 @@ pub enum Value {
     Any(Shared<AnyObj>),
 }
 
+/// The kind of a [Value], without any of its data.
+///
+/// This is returned by [Value::kind] and is cheap to copy and compare, so it
+/// can be used to dispatch on the kind of a value without borrowing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// The unit value.
+    Unit,
+    /// A boolean.
+    Bool,
+    /// A single byte.
+    Byte,
+    /// A character.
+    Char,
+    /// A number.
+    Integer,
+    /// A float.
+    Float,
+    /// A type hash. Describes a type in the virtual machine.
+    Type,
+    /// A static string.
+    StaticString,
+    /// A UTF-8 string.
+    String,
+    /// A byte string.
+    Bytes,
+    /// A vector containing any values.
+    Vec,
+    /// A tuple.
+    Tuple,
+    /// An object.
+    Object,
+    /// A range.
+    Range,
+    /// A stored future.
+    Future,
+    /// A Stream.
+    Stream,
+    /// A stored generator.
+    Generator,
+    /// Generator state.
+    GeneratorState,
+    /// An empty value indicating nothing.
+    Option,
+    /// A stored result in a slot.
+    Result,
+    /// An struct with a well-defined type.
+    UnitStruct,
+    /// A tuple with a well-defined type.
+    TupleStruct,
+    /// An struct with a well-defined type.
+    Struct,
+    /// The variant of an enum.
+    Variant,
+    /// A stored function pointer.
+    Function,
+    /// A value being formatted.
+    Format,
+    /// An iterator.
+    Iterator,
+    /// An opaque value that can be downcasted.
+    Any,
+}
+
 impl Value {
+    /// Get the kind of the value.
+    ///
+    /// This doesn't access the data of the value, so it never fails.
+    pub fn kind(&self) -> ValueKind {
+        match self {
+            Self::Unit => ValueKind::Unit,
+            Self::Bool(..) => ValueKind::Bool,
+            Self::Byte(..) => ValueKind::Byte,
+            Self::Char(..) => ValueKind::Char,
+            Self::Integer(..) => ValueKind::Integer,
+            Self::Float(..) => ValueKind::Float,
+            Self::Type(..) => ValueKind::Type,
+            Self::StaticString(..) => ValueKind::StaticString,
+            Self::String(..) => ValueKind::String,
+            Self::Bytes(..) => ValueKind::Bytes,
+            Self::Vec(..) => ValueKind::Vec,
+            Self::Tuple(..) => ValueKind::Tuple,
+            Self::Object(..) => ValueKind::Object,
+            Self::Range(..) => ValueKind::Range,
+            Self::Future(..) => ValueKind::Future,
+            Self::Stream(..) => ValueKind::Stream,
+            Self::Generator(..) => ValueKind::Generator,
+            Self::GeneratorState(..) => ValueKind::GeneratorState,
+            Self::Option(..) => ValueKind::Option,
+            Self::Result(..) => ValueKind::Result,
+            Self::UnitStruct(..) => ValueKind::UnitStruct,
+            Self::TupleStruct(..) => ValueKind::TupleStruct,
+            Self::Struct(..) => ValueKind::Struct,
+            Self::Variant(..) => ValueKind::Variant,
+            Self::Function(..) => ValueKind::Function,
+            Self::Format(..) => ValueKind::Format,
+            Self::Iterator(..) => ValueKind::Iterator,
+            Self::Any(..) => ValueKind::Any,
+        }
+    }
+
     /// Format the value using the [Protocol::STRING_DISPLAY] protocol.
     ///
     /// Also requires a work buffer `buf` which will be used in case the value
     /// provided required formatting.
     ///
+    /// Floats are formatted using the shortest representation which round
+    /// trips back into the same value, and always include either a decimal
+    /// point or an exponent so that they can't be confused with integers
+    /// (`1.0`, `1e300`). Negative zero is formatted as `-0.0`, infinities as
+    /// `inf` and `-inf`, and any NaN as `NaN`.
+    ///
     /// Note that this function will always failed if called outside of a
     /// virtual machine.
     pub fn string_display(&self, s: &mut String, buf: &mut String) -> Result<fmt::Result, VmError> {
         self.string_display_with(s, buf, EnvProtocolCaller)
     }
 
+    /// Format the value into a new string using the
+    /// [Protocol::STRING_DISPLAY] protocol.
+    ///
+    /// Errors with [VmErrorKind::FormatError] if the value fails to format.
+    ///
+    /// Note that this function will always failed if called outside of a
+    /// virtual machine.
+    pub fn display_string(&self) -> Result<String, VmError> {
+        let mut s = String::new();
+        let mut buf = String::new();
+
+        if let Err(fmt::Error) = self.string_display(&mut s, &mut buf)? {
+            return Err(VmError::from(VmErrorKind::FormatError));
+        }
+
+        Ok(s)
+    }
+
     /// Internal impl of string_display with a customizable caller.
     pub(crate) fn string_display_with(
         &self,
@@ -366,6 +494,13 @@ impl Value {
         use crate::FromValue as _;
         use std::fmt::Write as _;
 
+        // NB: script types which derive `Debug` are formatted through the
+        // derived protocol function.
+        let derived = matches!(
+            self,
+            Value::UnitStruct(..) | Value::TupleStruct(..) | Value::Struct(..) | Value::Variant(..)
+        ) && caller.has_unit_protocol_fn(Protocol::STRING_DEBUG, self)?;
+
         let result = match self {
             Value::Unit => {
                 write!(s, "()")
@@ -383,7 +518,9 @@ impl Value {
                 write!(s, "{:?}", value)
             }
             Value::Float(value) => {
-                write!(s, "{:?}", value)
+                let mut buffer = ryu::Buffer::new();
+                s.push_str(buffer.format(*value));
+                Ok(())
             }
             Value::Type(value) => {
                 write!(s, "Type({})", value)
@@ -427,16 +564,16 @@ impl Value {
             Value::Result(value) => {
                 write!(s, "{:?}", value)
             }
-            Value::UnitStruct(value) => {
+            Value::UnitStruct(value) if !derived => {
                 write!(s, "{:?}", value)
             }
-            Value::TupleStruct(value) => {
+            Value::TupleStruct(value) if !derived => {
                 write!(s, "{:?}", value)
             }
-            Value::Struct(value) => {
+            Value::Struct(value) if !derived => {
                 write!(s, "{:?}", value)
             }
-            Value::Variant(value) => {
+            Value::Variant(value) if !derived => {
                 write!(s, "{:?}", value)
             }
             Value::Function(value) => {
@@ -834,7 +971,7 @@ impl Value {
                 let (data, guard) = Ref::into_raw(any);
                 Ok((data, guard))
             }
-            actual => Err(VmError::expected_any(actual.type_info()?)),
+            actual => Err(VmError::expected_external::<T>(actual.type_info()?)),
         }
     }
 
@@ -858,7 +995,7 @@ impl Value {
                 let (data, guard) = Mut::into_raw(any);
                 Ok((data, guard))
             }
-            actual => Err(VmError::expected_any(actual.type_info()?)),
+            actual => Err(VmError::expected_external::<T>(actual.type_info()?)),
         }
     }
 
@@ -930,11 +1067,36 @@ impl Value {
         })
     }
 
+    /// Compare two script types of the same kind through their derived
+    /// `PartialEq` implementation, if they have one.
+    fn derived_eq(vm: &mut Vm, a: &Value, b: &Value) -> Result<Option<bool>, VmError> {
+        use crate::FromValue as _;
+
+        match (a, b) {
+            (Self::UnitStruct(..), Self::UnitStruct(..))
+            | (Self::TupleStruct(..), Self::TupleStruct(..))
+            | (Self::Struct(..), Self::Struct(..))
+            | (Self::Variant(..), Self::Variant(..)) => (),
+            _ => return Ok(None),
+        }
+
+        if a.type_hash()? != b.type_hash()? || !vm.has_unit_protocol_fn(Protocol::EQ, a)? {
+            return Ok(None);
+        }
+
+        let eq = vm.call_protocol_fn(Protocol::EQ, a.clone(), (b.clone(),))?;
+        Ok(Some(bool::from_value(eq)?))
+    }
+
     /// Optimized function to test if two value pointers are deeply equal to
     /// each other.
     ///
     /// This is the basis for the eq operation (`==`).
     pub(crate) fn value_ptr_eq(vm: &mut Vm, a: &Value, b: &Value) -> Result<bool, VmError> {
+        if let Some(eq) = Self::derived_eq(vm, a, b)? {
+            return Ok(eq);
+        }
+
         match (a, b) {
             (Self::Unit, Self::Unit) => return Ok(true),
             (Self::Bool(a), Self::Bool(b)) => return Ok(a == b),
@@ -1011,6 +1173,12 @@ impl Value {
             (Self::StaticString(a), Self::StaticString(b)) => {
                 return Ok(***a == ***b);
             }
+            (Self::Bytes(a), Self::Bytes(b)) => {
+                return Ok(*a.borrow_ref()? == *b.borrow_ref()?);
+            }
+            // NB: byte strings are never equal to anything but other byte
+            // strings.
+            (Self::Bytes(..), _) | (_, Self::Bytes(..)) => return Ok(false),
             (Self::Option(a), Self::Option(b)) => match (&*a.borrow_ref()?, &*b.borrow_ref()?) {
                 (Some(a), Some(b)) => return Self::value_ptr_eq(vm, a, b),
                 (None, None) => return Ok(true),
@@ -1022,6 +1190,10 @@ impl Value {
                 _ => return Ok(false),
             },
             (a, b) => {
+                if let Some(eq) = crate::big_int::eq(a, b)? {
+                    return Ok(eq);
+                }
+
                 if vm.call_instance_fn(a.clone(), Protocol::EQ, (b.clone(),))? {
                     use crate::FromValue as _;
                     return Ok(bool::from_value(vm.stack.pop()?)?);
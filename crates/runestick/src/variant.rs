@@ -87,15 +87,16 @@ pub enum VariantData {
 
 impl fmt::Debug for Variant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.rtti.item)?;
-
         match &self.data {
-            VariantData::Unit => {}
+            VariantData::Unit => {
+                write!(f, "{}", self.rtti.item)?;
+            }
             VariantData::Struct(st) => {
-                write!(f, "{:?}", st)?;
+                fmt::Display::fmt(&st.debug_struct(&self.rtti.item), f)?;
             }
             VariantData::Tuple(tuple) => {
-                write!(f, "{:?}", tuple)?;
+                write!(f, "{}", self.rtti.item)?;
+                fmt::Debug::fmt(tuple, f)?;
             }
         }
 
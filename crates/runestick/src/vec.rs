@@ -1,3 +1,4 @@
+use crate::debug_limit;
 use crate::{
     FromValue, InstallWith, Mut, Named, RawMut, RawRef, RawStr, Ref, Shared, ToValue,
     UnsafeFromValue, Value, Vm, VmError,
@@ -5,11 +6,16 @@ use crate::{
 use std::cmp;
 use std::fmt;
 use std::ops;
+use std::rc::Rc;
 use std::slice;
 use std::vec;
 
 /// Struct representing a dynamic vector.
 ///
+/// Vectors are copy-on-write. Cloning one only clones a handle to its
+/// elements, which are copied the first time either of the clones is
+/// mutated.
+///
 /// # Examples
 ///
 /// ```rust
@@ -29,14 +35,18 @@ use std::vec;
 #[derive(Clone)]
 #[repr(transparent)]
 pub struct Vec {
-    inner: vec::Vec<Value>,
+    inner: Rc<vec::Vec<Value>>,
 }
 
 impl Vec {
+    /// The maximum number of elements in a vector constructed by repeating a
+    /// value, like `[0; 5]`.
+    pub const MAX_REPEAT: usize = 1 << 24;
+
     /// Construct a new empty dynamic vector.
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
-            inner: vec::Vec::new(),
+            inner: Rc::new(vec::Vec::new()),
         }
     }
 
@@ -45,20 +55,29 @@ impl Vec {
     where
         F: FnMut(&Value, &Value) -> cmp::Ordering,
     {
-        self.inner.sort_by(compare)
+        self.make_mut().sort_by(compare)
     }
 
     /// Construct a new dynamic vector guaranteed to have at least the given
     /// capacity.
     pub fn with_capacity(cap: usize) -> Self {
         Self {
-            inner: vec::Vec::with_capacity(cap),
+            inner: Rc::new(vec::Vec::with_capacity(cap)),
         }
     }
 
     /// Convert into inner std vector.
+    ///
+    /// This copies the elements if they are shared with a clone of this
+    /// vector.
     pub fn into_inner(self) -> vec::Vec<Value> {
-        self.inner
+        Rc::try_unwrap(self.inner).unwrap_or_else(|inner| (*inner).clone())
+    }
+
+    /// Get mutable access to the elements of the vector, copying them first
+    /// if they're shared.
+    fn make_mut(&mut self) -> &mut vec::Vec<Value> {
+        Rc::make_mut(&mut self.inner)
     }
 
     /// Returns `true` if the dynamic vector contains no elements.
@@ -80,14 +99,14 @@ impl Vec {
                 len: self.len().into(),
             }))
         } else {
-            self.inner[index] = value;
+            self.make_mut()[index] = value;
             Ok(())
         }
     }
 
     /// Appends an element to the back of a dynamic vector.
     pub fn push(&mut self, value: Value) {
-        self.inner.push(value);
+        self.make_mut().push(value);
     }
 
     /// Appends an element to the back of a dynamic vector, converting it as
@@ -96,7 +115,7 @@ impl Vec {
     where
         T: ToValue,
     {
-        self.inner.push(value.to_value()?);
+        self.make_mut().push(value.to_value()?);
         Ok(())
     }
 
@@ -120,18 +139,18 @@ impl Vec {
 
     /// Get the mutable value at the given index.
     pub fn get_mut(&mut self, index: usize) -> Option<&mut Value> {
-        self.inner.get_mut(index)
+        self.make_mut().get_mut(index)
     }
 
     /// Removes the last element from a dynamic vector and returns it, or
     /// [`None`] if it is empty.
     pub fn pop(&mut self) -> Option<Value> {
-        self.inner.pop()
+        self.make_mut().pop()
     }
 
     /// Removes the element at the specified index from a dynamic vector.
     pub fn remove(&mut self, index: usize) {
-        self.inner.remove(index);
+        self.make_mut().remove(index);
     }
 
     /// Clears the vector, removing all values.
@@ -139,13 +158,13 @@ impl Vec {
     /// Note that this method has no effect on the allocated capacity of the
     /// vector.
     pub fn clear(&mut self) {
-        self.inner.clear();
+        self.make_mut().clear();
     }
 
     /// Inserts an element at position index within the vector, shifting all
     /// elements after it to the right.
     pub fn insert(&mut self, index: usize, value: Value) {
-        self.inner.insert(index, value);
+        self.make_mut().insert(index, value);
     }
 
     /// Extend this vector with something that implements the into_iter
@@ -161,8 +180,18 @@ impl Vec {
     }
 
     /// Convert into a runestick iterator.
+    ///
+    /// The iterator shares the elements of the vector, so mutating the vector
+    /// while it's being iterated over copies them instead.
     pub fn into_iterator(&self) -> crate::Iterator {
-        crate::Iterator::from_double_ended("std::vec::Iter", self.clone().into_iter())
+        crate::Iterator::from_double_ended(
+            "std::vec::Iter",
+            Iter {
+                inner: self.inner.clone(),
+                front: 0,
+                back: self.inner.len(),
+            },
+        )
     }
 
     /// Compare two vectors for equality.
@@ -181,6 +210,12 @@ impl Vec {
     }
 }
 
+impl Default for Vec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Named for Vec {
     const BASE_NAME: RawStr = RawStr::from_str("Vec");
 }
@@ -189,7 +224,16 @@ impl InstallWith for Vec {}
 
 impl fmt::Debug for Vec {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(&*self.inner).finish()
+        let entries = debug_limit::enter(self.inner.len());
+
+        let mut d = f.debug_list();
+        d.entries(&self.inner[..entries.shown()]);
+
+        if let Some(omitted) = entries.omitted() {
+            d.entry(&omitted);
+        }
+
+        d.finish()
     }
 }
 
@@ -203,7 +247,7 @@ impl ops::Deref for Vec {
 
 impl ops::DerefMut for Vec {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut *self.inner
+        &mut *self.make_mut()
     }
 }
 
@@ -212,7 +256,7 @@ impl IntoIterator for Vec {
     type IntoIter = vec::IntoIter<Value>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.inner.into_iter()
+        self.into_inner().into_iter()
     }
 }
 
@@ -230,20 +274,59 @@ impl<'a> IntoIterator for &'a mut Vec {
     type IntoIter = slice::IterMut<'a, Value>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.inner.iter_mut()
+        self.make_mut().iter_mut()
+    }
+}
+
+/// An iterator over a vector which shares its elements.
+struct Iter {
+    inner: Rc<vec::Vec<Value>>,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let value = self.inner[self.front].clone();
+        self.front += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.inner[self.back].clone())
     }
 }
 
 impl From<vec::Vec<Value>> for Vec {
     fn from(inner: vec::Vec<Value>) -> Self {
-        Self { inner }
+        Self {
+            inner: Rc::new(inner),
+        }
     }
 }
 
 impl From<Box<[Value]>> for Vec {
     fn from(inner: Box<[Value]>) -> Self {
         Self {
-            inner: inner.to_vec(),
+            inner: Rc::new(inner.to_vec()),
         }
     }
 }
@@ -271,6 +354,7 @@ where
     T: FromValue,
 {
     fn from_value(value: Value) -> Result<Self, VmError> {
+        let _guard = crate::depth::enter()?;
         let vec = value.into_vec()?;
         let vec = vec.take()?;
 
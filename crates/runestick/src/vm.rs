@@ -1,23 +1,31 @@
+use crate::big_int;
 use crate::budget;
+use crate::debug_limit::DebugLimits;
 use crate::future::SelectFuture;
+use crate::inline_cache::CachedFn;
+use crate::resume::{self, Suspended};
 use crate::unit::UnitFn;
 use crate::{
     Args, Awaited, BorrowMut, Bytes, Call, Format, FormatSpec, FromValue, Function, Future,
-    Generator, GuardedArgs, Hash, Inst, InstAddress, InstAssignOp, InstFnNameHash, InstOp,
-    InstRangeLimits, InstTarget, InstValue, InstVariant, IntoTypeHash, Object, Panic, Protocol,
-    Range, RangeLimits, RuntimeContext, Select, Shared, Stack, Stream, Struct, Tuple, TypeCheck,
-    Unit, UnitStruct, Value, Variant, VariantData, Vec, VmError, VmErrorKind, VmExecution, VmHalt,
-    VmIntegerRepr, VmSendExecution,
+    Generator, GuardedArgs, Hash, InlineCache, Inst, InstAddress, InstAssignOp, InstFnNameHash,
+    InstOp, InstRangeLimits, InstTarget, InstValue, InstVariant, IntoTypeHash, Item, Object, Panic,
+    PanicReason, Protocol, Range, RangeLimits, RuntimeContext, Select, Shared, Source, Stack,
+    Stream, Struct, Tuple, TypeCheck, TypeInfo, Unit, UnitStruct, Value, Variant, VariantData, Vec,
+    VmError, VmErrorKind, VmExecution, VmHalt, VmIntegerRepr, VmSendExecution,
 };
+use num_bigint::BigInt;
+use std::cmp::Ordering;
 use std::fmt;
 use std::mem;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::vec;
 
 enum TargetFallback<'a> {
     Value(Value, Value),
     Field(&'a Value, Hash, Value),
-    Index(&'a Value, usize, Value),
+    TupleField(&'a Value, usize, Value),
+    Index(&'a Value, Value, Value),
 }
 
 enum TargetValue<'a, 'b> {
@@ -40,7 +48,7 @@ macro_rules! target_value {
                     $guard = value;
                     TargetValue::Value(&mut *$guard, rhs)
                 } else {
-                    TargetValue::Fallback(TargetFallback::Index(&$lhs, index, rhs))
+                    TargetValue::Fallback(TargetFallback::TupleField(&$lhs, index, rhs))
                 }
             }
             InstTarget::Field(field) => {
@@ -54,6 +62,17 @@ macro_rules! target_value {
                     TargetValue::Fallback(TargetFallback::Field(&$lhs, field.hash(), rhs))
                 }
             }
+            InstTarget::Index => {
+                let index = $vm.stack.pop()?;
+                $lhs = $vm.stack.pop()?;
+
+                if let Some(value) = Vm::try_index_get_mut(&$lhs, &index)? {
+                    $guard = value;
+                    TargetValue::Value(&mut *$guard, rhs)
+                } else {
+                    TargetValue::Fallback(TargetFallback::Index(&$lhs, index, rhs))
+                }
+            }
         }
     }};
 }
@@ -67,10 +86,27 @@ pub struct Vm {
     pub(crate) unit: Arc<Unit>,
     /// The current instruction pointer.
     ip: usize,
+    /// The offset of the function which is currently executing, if known.
+    entry: Option<usize>,
     /// The current stack.
     pub(crate) stack: Stack,
     /// Frames relative to the stack.
     call_frames: vec::Vec<CallFrame>,
+    /// Cache of resolved instance functions for each call site.
+    inline_cache: InlineCache,
+    /// Watched fields, by the object they belong to.
+    watchpoints: vec::Vec<(Value, Box<str>)>,
+    /// Breakpoints to halt at before their instruction is executed.
+    breakpoints: vec::Vec<Breakpoint>,
+    /// Set when execution halted on a breakpoint, so that resuming executes
+    /// the instruction instead of halting on it again.
+    resume_breakpoint: bool,
+    /// The state of a resumable native function call which yielded, and is
+    /// resumed by executing the current instruction again.
+    suspended: Option<Suspended>,
+    /// Debug formatting limits set by the script, which are applied whenever
+    /// it executes.
+    pub(crate) debug_limits: Option<DebugLimits>,
 }
 
 impl Vm {
@@ -79,6 +115,16 @@ impl Vm {
         Self::new_with_stack(context, unit, Stack::new())
     }
 
+    /// Construct a new runestick virtual machine with a stack that has room
+    /// for at least `capacity` values before it has to grow.
+    pub fn with_stack_capacity(
+        context: Arc<RuntimeContext>,
+        unit: Arc<Unit>,
+        capacity: usize,
+    ) -> Self {
+        Self::new_with_stack(context, unit, Stack::with_capacity(capacity))
+    }
+
     /// Construct a new runestick virtual machine.
     pub const fn new_with_stack(
         context: Arc<RuntimeContext>,
@@ -89,8 +135,15 @@ impl Vm {
             context,
             unit,
             ip: 0,
+            entry: None,
             stack,
             call_frames: vec::Vec::new(),
+            inline_cache: InlineCache::new(),
+            watchpoints: vec::Vec::new(),
+            breakpoints: vec::Vec::new(),
+            resume_breakpoint: false,
+            suspended: None,
+            debug_limits: None,
         }
     }
 
@@ -114,9 +167,13 @@ impl Vm {
     }
 
     /// Set  the current instruction pointer.
+    ///
+    /// This is used to start executing the function at the given offset, so
+    /// it's also considered to be the function which is currently executing.
     #[inline]
     pub fn set_ip(&mut self, ip: usize) {
         self.ip = ip;
+        self.entry = Some(ip);
     }
 
     /// Get the stack.
@@ -131,6 +188,18 @@ impl Vm {
         &self.stack
     }
 
+    /// Get the cache of resolved instance functions.
+    #[inline]
+    pub fn inline_cache(&self) -> &InlineCache {
+        &self.inline_cache
+    }
+
+    /// Get the cache of resolved instance functions mutably.
+    #[inline]
+    pub fn inline_cache_mut(&mut self) -> &mut InlineCache {
+        &mut self.inline_cache
+    }
+
     /// Get the stack mutably.
     #[inline]
     pub fn stack_mut(&mut self) -> &mut Stack {
@@ -149,12 +218,245 @@ impl Vm {
         &self.unit
     }
 
+    /// Replace the body of the function with the given `hash` with the one in
+    /// `from`, so that subsequent calls through this virtual machine use the
+    /// new body.
+    ///
+    /// The unit is copied if it's shared, so other virtual machines and
+    /// anything already executing keep using the old body. See
+    /// [Unit::replace_function] for details.
+    ///
+    /// This clears the [inline cache][Vm::inline_cache], since call sites
+    /// might have cached the old body.
+    pub fn replace_function<H>(&mut self, hash: H, from: &Unit) -> Result<(), VmError>
+    where
+        H: IntoTypeHash,
+    {
+        // NB: the function might be executing on this virtual machine, in
+        // which case its body has to stay in place.
+        let entries = self
+            .call_frames
+            .iter()
+            .filter_map(|frame| frame.entry)
+            .chain(self.entry)
+            .collect::<vec::Vec<_>>();
+
+        Arc::make_mut(&mut self.unit).replace_function_with(
+            hash.into_type_hash(),
+            from,
+            |range| entries.iter().any(|entry| range.contains(entry)),
+        )?;
+
+        self.inline_cache.clear();
+        Ok(())
+    }
+
     /// Access the current instruction pointer.
     #[inline]
     pub fn ip(&self) -> usize {
         self.ip
     }
 
+    /// Get the item of the function which is executing in the top call frame.
+    ///
+    /// This requires the unit to have debug info, and returns `None` if it
+    /// doesn't or if the virtual machine isn't executing a function.
+    pub fn current_item(&self) -> Option<&Item> {
+        let debug = self.unit.debug_info()?;
+        let (_, signature) = debug.function_at(self.entry?)?;
+        Some(&signature.path)
+    }
+
+    /// Set a watchpoint on the field of the given object, struct or variant,
+    /// which causes execution to halt after an instruction writes to it.
+    ///
+    /// Elements of vectors and tuples are watched through their index, like
+    /// `"0"`. Hitting a watchpoint is reported by
+    /// [VmExecution::resume_debug][crate::VmExecution::resume_debug].
+    ///
+    /// Targets are matched by identity, so only writes to the same shared
+    /// value as `target` are caught.
+    pub fn watch(&mut self, target: &Value, field: &str) {
+        self.watchpoints.push((target.clone(), field.into()));
+    }
+
+    /// Remove a watchpoint previously set through [Vm::watch].
+    ///
+    /// Returns `true` if the watchpoint existed.
+    pub fn unwatch(&mut self, target: &Value, field: &str) -> bool {
+        let len = self.watchpoints.len();
+
+        self.watchpoints
+            .retain(|(t, f)| !(is_same_shared(t, target) && f.as_ref() == field));
+
+        self.watchpoints.len() != len
+    }
+
+    /// Set a breakpoint on the given line of the function `item`, which causes
+    /// execution to halt before the breakpoint instruction is executed.
+    ///
+    /// The line is zero-based and is resolved through `source`, which must be
+    /// the source the function was compiled from. The breakpoint is placed on
+    /// the first instruction of the function whose span starts on that line.
+    ///
+    /// Hitting a breakpoint is reported by
+    /// [VmExecution::resume_debug][crate::VmExecution::resume_debug].
+    ///
+    /// Returns the instruction pointer of the breakpoint, or `None` if the
+    /// unit doesn't have debug info, the function doesn't exist, or the
+    /// function has no instructions starting on the given line.
+    pub fn set_breakpoint_item(
+        &mut self,
+        item: &Item,
+        line: usize,
+        source: &Source,
+    ) -> Option<usize> {
+        let ip = self.resolve_line(item, line, source)?;
+        self.insert_breakpoint(ip, None);
+        Some(ip)
+    }
+
+    /// Set a breakpoint like [Vm::set_breakpoint_item], which only halts
+    /// execution if `condition` returns `true` for the frame which is about to
+    /// execute the breakpoint instruction.
+    ///
+    /// If the condition errors, for example because a variable it looks up
+    /// isn't in scope, execution halts anyway and the error is logged as a
+    /// warning.
+    pub fn set_conditional_breakpoint_item<F>(
+        &mut self,
+        item: &Item,
+        line: usize,
+        source: &Source,
+        condition: F,
+    ) -> Option<usize>
+    where
+        F: 'static + Send + Sync + Fn(&FrameView<'_>) -> Result<bool, VmError>,
+    {
+        let ip = self.resolve_line(item, line, source)?;
+        self.insert_breakpoint(ip, Some(BreakpointCondition::Native(Arc::new(condition))));
+        Some(ip)
+    }
+
+    /// Set a breakpoint like [Vm::set_conditional_breakpoint_item], where the
+    /// condition is a script function.
+    ///
+    /// The function is called with an object of the named variables which
+    /// are in scope, like `|locals| locals.n == 5`, and must return a
+    /// boolean.
+    pub fn set_conditional_breakpoint_item_function(
+        &mut self,
+        item: &Item,
+        line: usize,
+        source: &Source,
+        condition: Function,
+    ) -> Option<usize> {
+        let ip = self.resolve_line(item, line, source)?;
+        self.insert_breakpoint(ip, Some(BreakpointCondition::Function(Rc::new(condition))));
+        Some(ip)
+    }
+
+    /// Resolve the first instruction of the function `item` whose span starts
+    /// on the given zero-based line.
+    fn resolve_line(&self, item: &Item, line: usize, source: &Source) -> Option<usize> {
+        let debug = self.unit.debug_info()?;
+
+        let entry = debug
+            .functions_rev
+            .iter()
+            .filter(|(_, hash)| {
+                matches!(debug.functions.get(hash), Some(signature) if &signature.path == item)
+            })
+            .map(|(offset, _)| *offset)
+            .min()?;
+
+        let end = debug
+            .functions_rev
+            .keys()
+            .copied()
+            .filter(|offset| *offset > entry)
+            .min()
+            .unwrap_or(debug.instructions.len());
+
+        let ip = debug
+            .instructions
+            .get(entry..end)?
+            .iter()
+            .position(|inst| {
+                let start = inst.span.start.into_usize();
+                source.position_to_unicode_line_char(start).0 == line
+            })?
+            + entry;
+
+        Some(ip)
+    }
+
+    /// Insert a breakpoint, replacing the condition of any existing breakpoint
+    /// at the same instruction.
+    fn insert_breakpoint(&mut self, ip: usize, condition: Option<BreakpointCondition>) {
+        match self.breakpoints.iter_mut().find(|b| b.ip == ip) {
+            Some(breakpoint) => breakpoint.condition = condition,
+            None => self.breakpoints.push(Breakpoint { ip, condition }),
+        }
+    }
+
+    /// Remove a breakpoint previously set through [Vm::set_breakpoint_item]
+    /// or [Vm::set_conditional_breakpoint_item], by the instruction pointer it
+    /// resolved to.
+    ///
+    /// Returns `true` if the breakpoint existed.
+    pub fn clear_breakpoint(&mut self, ip: usize) -> bool {
+        let len = self.breakpoints.len();
+        self.breakpoints.retain(|b| b.ip != ip);
+
+        if self.breakpoints.is_empty() {
+            self.resume_breakpoint = false;
+        }
+
+        self.breakpoints.len() != len
+    }
+
+    /// Iterate over views of every active call frame, starting with the
+    /// outermost one and ending with the frame which is currently executing.
+    ///
+    /// Variable names and items are only available if the unit has debug
+    /// info.
+    pub fn frames(&self) -> impl DoubleEndedIterator<Item = FrameView<'_>> + '_ {
+        (0..=self.call_frames.len()).map(move |n| self.frame_view(n))
+    }
+
+    /// Construct a view of the call frame at the given depth, where the depth
+    /// equal to the number of call frames is the currently executing one.
+    fn frame_view(&self, n: usize) -> FrameView<'_> {
+        let (ip, entry, bottom) = match self.call_frames.get(n) {
+            Some(frame) => (frame.ip, frame.entry, frame.stack_bottom),
+            None => (self.ip, self.entry, self.stack.stack_bottom()),
+        };
+
+        let top = match self.call_frames.get(n + 1) {
+            Some(frame) => frame.stack_bottom,
+            None if n < self.call_frames.len() => self.stack.stack_bottom(),
+            None => self.stack.len(),
+        };
+
+        let mut item = None;
+        let mut names = vec::Vec::new();
+
+        if let (Some(debug), Some(entry)) = (self.unit.debug_info(), entry) {
+            item = debug
+                .function_at(entry)
+                .map(|(_, signature)| &signature.path);
+            names = debug.variables_at(entry, ip);
+        }
+
+        FrameView {
+            item,
+            ip,
+            values: self.stack.get(bottom..top).unwrap_or(&[]),
+            names,
+        }
+    }
+
     /// Advance the instruction pointer.
     #[inline]
     pub(crate) fn advance(&mut self) {
@@ -164,8 +466,10 @@ impl Vm {
     /// Reset this virtual machine, freeing all memory used.
     pub fn clear(&mut self) {
         self.ip = 0;
+        self.entry = None;
         self.stack.clear();
         self.call_frames.clear();
+        self.suspended = None;
     }
 
     /// Modify the current instruction pointer.
@@ -256,24 +560,73 @@ impl Vm {
     /// This is accomplished by preventing values escaping from being
     /// non-exclusively sent with the execution or escaping the execution. We
     /// only support encoding arguments which themselves are `Send`.
+    ///
+    /// Watchpoints hold on to the values they watch and breakpoint conditions
+    /// set through [Vm::set_conditional_breakpoint_item_function] hold on to
+    /// a script function, so this errors with
+    /// [VmErrorKind::UnsendableDebugState] if any of them are set.
     pub fn send_execute<A, N>(mut self, name: N, args: A) -> Result<VmSendExecution, VmError>
     where
         N: IntoTypeHash,
         A: Send + Args,
     {
-        // Safety: make sure the stack is clear, preventing any values from
-        // being sent along with the virtual machine.
+        // Safety: make sure the stack and the state of any suspended call is
+        // clear and that no watched values or script conditions are held,
+        // preventing any values from being sent along with the virtual
+        // machine.
+        let script_condition = self
+            .breakpoints
+            .iter()
+            .any(|b| matches!(b.condition, Some(BreakpointCondition::Function(..))));
+
+        if !self.watchpoints.is_empty() || script_condition {
+            return Err(VmError::from(VmErrorKind::UnsendableDebugState));
+        }
+
         self.stack.clear();
+        self.suspended = None;
+        self.resume_breakpoint = false;
 
         let execution = self.execute(name, args)?;
         Ok(VmSendExecution(execution))
     }
 
-    /// Call the given function immediately, returning the produced value.
+    /// Call the given function immediately, converting the produced value
+    /// into `T`.
+    ///
+    /// Arguments are converted into values using [`ToValue`], and the produced
+    /// value is converted using [`FromValue`]. If any conversion fails, the
+    /// error describes the type which was expected. Use [`Value`] as `T` to
+    /// get the produced value as-is.
     ///
     /// This function permits for using references since it doesn't defer its
     /// execution.
     ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use runestick::{Context, ItemPath, Unit, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// fn main() -> runestick::Result<()> {
+    ///     let context = Context::with_default_modules()?;
+    ///     let context = Arc::new(context.runtime());
+    ///     let unit = Arc::new(Unit::default());
+    ///     // Normally the unit would be created by compiling some source,
+    ///     // and since this one is empty it won't do anything.
+    ///
+    ///     let vm = Vm::new(context, unit);
+    ///
+    ///     let output = vm.clone().call::<i64>(&["math", "add"], (1i64, 2i64))?;
+    ///     println!("output: {}", output);
+    ///
+    ///     // Functions can also be named by their path.
+    ///     let output = vm.call::<i64>(ItemPath::new("math::add"), (1i64, 2i64))?;
+    ///     println!("output: {}", output);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
     /// # Panics
     ///
     /// If any of the arguments passed in are references, and that references is
@@ -281,12 +634,12 @@ impl Vm {
     /// this call will panic as we are trying to free the metadata relatedc to
     /// the reference.
     ///
+    /// [`ToValue`]: crate::ToValue
     /// [`Mut<T>`]: crate::Mut
     /// [`Ref<T>`]: crate::Ref
-    pub fn call<A, N>(mut self, name: N, args: A) -> Result<Value, VmError>
+    pub fn call<T>(mut self, name: impl IntoTypeHash, args: impl GuardedArgs) -> Result<T, VmError>
     where
-        N: IntoTypeHash,
-        A: GuardedArgs,
+        T: FromValue,
     {
         self.set_entrypoint(name, args.count())?;
 
@@ -299,7 +652,7 @@ impl Vm {
         // reference of the value. We should prevent it from being possible to
         // take any owned references to values held by this.
         drop(guard);
-        Ok(value)
+        T::from_value(value)
     }
 
     /// Convert this virtual machine into an execution.
@@ -307,8 +660,12 @@ impl Vm {
         VmExecution::new(self)
     }
 
-    /// Call the given function immediately asynchronously, returning the
-    /// produced value.
+    /// Call the given function immediately asynchronously, converting the
+    /// produced value into `T` the same way as [Vm::call].
+    ///
+    /// Any futures awaited by the function are polled by the returned future,
+    /// so the host's executor is free to run other tasks while they are
+    /// pending. The function may be either a regular or an `async` function.
     ///
     /// This function permits for using references since it doesn't defer its
     /// execution.
@@ -322,10 +679,13 @@ impl Vm {
     ///
     /// [`Mut<T>`]: crate::Mut
     /// [`Ref<T>`]: crate::Ref
-    pub async fn async_call<A, N>(mut self, name: N, args: A) -> Result<Value, VmError>
+    pub async fn async_call<T>(
+        mut self,
+        name: impl IntoTypeHash,
+        args: impl GuardedArgs,
+    ) -> Result<T, VmError>
     where
-        N: IntoTypeHash,
-        A: GuardedArgs,
+        T: FromValue,
     {
         self.set_entrypoint(name, args.count())?;
 
@@ -338,7 +698,7 @@ impl Vm {
         // reference of the value. We should prevent it from being possible to
         // take any owned references to values held by this.
         drop(guard);
-        Ok(value)
+        T::from_value(value)
     }
 
     // Update the instruction pointer to match the function matching the given
@@ -372,7 +732,7 @@ impl Vm {
             }
         };
 
-        self.ip = offset;
+        self.set_ip(offset);
         self.stack.clear();
         Ok(())
     }
@@ -418,7 +778,37 @@ impl Vm {
             }
         };
 
-        handler(&mut self.stack, count)?;
+        let _ip = crate::env::set_ip(self.ip);
+        self.context.call_handler(handler, &mut self.stack, count)?;
+        Ok(true)
+    }
+
+    /// Helper to call a native index function, like the `INDEX_GET` protocol,
+    /// which completes before returning.
+    fn call_index_fn<A>(
+        &mut self,
+        protocol: Protocol,
+        target: &Value,
+        args: A,
+    ) -> Result<bool, VmError>
+    where
+        A: GuardedArgs,
+    {
+        let count = args.count() + 1;
+        let hash = Hash::instance_function(target.type_hash()?, protocol);
+
+        let handler = match self.context.lookup(hash) {
+            Some(handler) => handler,
+            None => return Ok(false),
+        };
+
+        self.stack.push(target.clone());
+
+        // Safety: We hold onto the guard for the duration of this call.
+        let _guard = unsafe { args.unsafe_into_stack(&mut self.stack)? };
+
+        let _ip = crate::env::set_ip(self.ip);
+        self.context.call_handler(handler, &mut self.stack, count)?;
         Ok(true)
     }
 
@@ -450,7 +840,8 @@ impl Vm {
             }
         };
 
-        handler(&mut self.stack, count)?;
+        let _ip = crate::env::set_ip(self.ip);
+        self.context.call_handler(handler, &mut self.stack, count)?;
         Ok(true)
     }
 
@@ -458,6 +849,7 @@ impl Vm {
         &mut self,
         int_op: fn(i64, i64) -> bool,
         float_op: fn(f64, f64) -> bool,
+        big_int_op: fn(&BigInt, &BigInt) -> bool,
         op: &'static str,
         lhs: InstAddress,
         rhs: InstAddress,
@@ -469,11 +861,16 @@ impl Vm {
             (Value::Integer(lhs), Value::Integer(rhs)) => int_op(lhs, rhs),
             (Value::Float(lhs), Value::Float(rhs)) => float_op(lhs, rhs),
             (lhs, rhs) => {
+                if let Some(out) = big_int::binary(&lhs, &rhs, big_int_op)? {
+                    self.stack.push(out);
+                    return Ok(());
+                }
+
                 return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
                     op,
                     lhs: lhs.type_info()?,
                     rhs: rhs.type_info()?,
-                }))
+                }));
             }
         };
 
@@ -490,10 +887,12 @@ impl Vm {
 
         self.call_frames.push(CallFrame {
             ip: self.ip,
+            entry: self.entry,
             stack_bottom: stack_top,
         });
 
         self.ip = ip.overflowing_sub(1).0;
+        self.entry = Some(ip);
         Ok(())
     }
 
@@ -509,6 +908,7 @@ impl Vm {
 
         self.stack.pop_stack_top(frame.stack_bottom)?;
         self.ip = frame.ip;
+        self.entry = frame.entry;
         Ok(false)
     }
 
@@ -671,6 +1071,41 @@ impl Vm {
         Ok(Some(value))
     }
 
+    /// Get a mutable reference to the value at the given index, for use as the
+    /// target of an assignment operation.
+    fn try_index_get_mut<'a>(
+        target: &'a Value,
+        index: &Value,
+    ) -> Result<Option<BorrowMut<'a, Value>>, VmError> {
+        let value = match index {
+            Value::String(string) => {
+                let string = string.borrow_ref()?;
+                Self::try_object_like_index_get_mut(target, string.as_str())?
+            }
+            Value::StaticString(string) => {
+                Self::try_object_like_index_get_mut(target, string.as_ref())?
+            }
+            Value::Integer(integer) => {
+                use std::convert::TryInto as _;
+
+                let index = match (*integer).try_into() {
+                    Ok(index) => index,
+                    Err(..) => {
+                        return Err(VmError::from(VmErrorKind::MissingIndex {
+                            target: target.type_info()?,
+                            index: VmIntegerRepr::from(*integer),
+                        }));
+                    }
+                };
+
+                Self::try_tuple_like_index_get_mut(target, index)?
+            }
+            _ => None,
+        };
+
+        Ok(value)
+    }
+
     /// Implementation of getting a mutable string index on an object-like type.
     fn try_object_like_index_get_mut<'a>(
         target: &'a Value,
@@ -991,6 +1426,64 @@ impl Vm {
         Ok(a.type_hash()? == hash)
     }
 
+    /// Internal implementation of the conversion operator.
+    fn internal_as(&mut self, lhs: InstAddress, rhs: InstAddress) -> Result<(), VmError> {
+        let b = self.stack.address(rhs)?;
+        let a = self.stack.address(lhs)?;
+
+        let hash = match b {
+            Value::Type(hash) => hash,
+            _ => {
+                return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
+                    op: "as",
+                    lhs: a.type_info()?,
+                    rhs: b.type_info()?,
+                }));
+            }
+        };
+
+        let type_hash = a.type_hash()?;
+
+        if type_hash == hash {
+            self.stack.push(a);
+            return Ok(());
+        }
+
+        let handler = match self.context.lookup_conversion(type_hash, hash) {
+            Some(handler) => handler,
+            None => {
+                let to = match self.lookup_type_info(hash) {
+                    Some(to) => to,
+                    None => return Err(VmError::from(VmErrorKind::MissingRtti { hash })),
+                };
+
+                return Err(VmError::from(VmErrorKind::MissingConversion {
+                    from: a.type_info()?,
+                    to,
+                }));
+            }
+        };
+
+        self.stack.push(a);
+        self.context.call_handler(handler, &mut self.stack, 1)?;
+        Ok(())
+    }
+
+    /// Look up information on the type with the given hash, which is either
+    /// registered in the context or declared in the unit.
+    fn lookup_type_info(&self, hash: Hash) -> Option<TypeInfo> {
+        if let Some(type_info) = self.context.lookup_type_info(hash) {
+            return Some(type_info.clone());
+        }
+
+        if let Some(rtti) = self.unit.lookup_rtti(hash) {
+            return Some(TypeInfo::Typed(rtti.clone()));
+        }
+
+        let rtti = self.unit.lookup_variant_rtti(hash)?;
+        Some(TypeInfo::Variant(rtti.clone()))
+    }
+
     fn internal_boolean_op(
         &mut self,
         bool_op: impl FnOnce(bool, bool) -> bool,
@@ -1063,7 +1556,7 @@ impl Vm {
     fn call_generator_fn(&mut self, offset: usize, args: usize) -> Result<(), VmError> {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
-        vm.ip = offset;
+        vm.set_ip(offset);
         self.stack.push(Generator::new(vm));
         Ok(())
     }
@@ -1072,7 +1565,7 @@ impl Vm {
     fn call_stream_fn(&mut self, offset: usize, args: usize) -> Result<(), VmError> {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
-        vm.ip = offset;
+        vm.set_ip(offset);
         self.stack.push(Stream::new(vm));
         Ok(())
     }
@@ -1081,7 +1574,7 @@ impl Vm {
     fn call_async_fn(&mut self, offset: usize, args: usize) -> Result<(), VmError> {
         let stack = self.stack.drain_stack_top(args)?.collect::<Stack>();
         let mut vm = Self::new_with_stack(self.context.clone(), self.unit.clone(), stack);
-        vm.ip = offset;
+        vm.set_ip(offset);
         self.stack.push(Future::new(vm.async_complete()));
         Ok(())
     }
@@ -1114,32 +1607,50 @@ impl Vm {
     fn internal_num_assign(
         &mut self,
         target: InstTarget,
+        op: InstAssignOp,
         protocol: Protocol,
-        error: fn() -> VmErrorKind,
+        error: fn(Ordering) -> VmErrorKind,
         integer_op: fn(i64, i64) -> Option<i64>,
         float_op: fn(f64, f64) -> f64,
+        big_int_op: fn(&BigInt, &BigInt) -> Option<BigInt>,
     ) -> Result<(), VmError> {
         let lhs;
         let mut guard;
 
         let fallback = match target_value!(self, target, guard, lhs) {
-            TargetValue::Value(lhs, rhs) => match (lhs, rhs) {
-                (Value::Integer(lhs), Value::Integer(rhs)) => {
-                    let out = integer_op(*lhs, rhs).ok_or_else(error)?;
-                    *lhs = out;
-                    return Ok(());
-                }
-                (Value::Float(lhs), Value::Float(rhs)) => {
-                    let out = float_op(*lhs, rhs);
-                    *lhs = out;
-                    return Ok(());
+            TargetValue::Value(lhs, rhs) => {
+                let promoted = match (&mut *lhs, &rhs) {
+                    (Value::Integer(lhs), Value::Integer(rhs)) => match integer_op(*lhs, *rhs) {
+                        Some(out) => {
+                            *lhs = out;
+                            return Ok(());
+                        }
+                        None if self.context.promote_integers => {
+                            Some(big_int_op(&BigInt::from(*lhs), &BigInt::from(*rhs)))
+                        }
+                        None => return Err(VmError::from(error(rhs.cmp(&0)))),
+                    },
+                    (Value::Float(lhs), Value::Float(rhs)) => {
+                        let out = float_op(*lhs, *rhs);
+                        *lhs = out;
+                        return Ok(());
+                    }
+                    (lhs, rhs) => big_int::binary(lhs, rhs, big_int_op)?,
+                };
+
+                match promoted {
+                    Some(Some(out)) => {
+                        *lhs = big_int::into_value(out);
+                        return Ok(());
+                    }
+                    Some(None) => return Err(VmError::from(error(big_int::sign(&rhs)?))),
+                    None => TargetFallback::Value(lhs.clone(), rhs),
                 }
-                (lhs, rhs) => TargetFallback::Value(lhs.clone(), rhs),
-            },
+            }
             TargetValue::Fallback(fallback) => fallback,
         };
 
-        self.target_fallback(fallback, protocol)
+        self.target_fallback(fallback, protocol, op)
     }
 
     /// Execute a fallback operation.
@@ -1147,6 +1658,7 @@ impl Vm {
         &mut self,
         fallback: TargetFallback<'_>,
         protocol: Protocol,
+        op: InstAssignOp,
     ) -> Result<(), VmError> {
         match fallback {
             TargetFallback::Value(lhs, rhs) => {
@@ -1173,11 +1685,42 @@ impl Vm {
                 <()>::from_value(value)?;
                 Ok(())
             }
-            TargetFallback::Index(lhs, ..) => {
+            TargetFallback::TupleField(lhs, ..) => {
                 Err(VmError::from(VmErrorKind::UnsupportedTupleIndexGet {
                     target: lhs.type_info()?,
                 }))
             }
+            TargetFallback::Index(lhs, index, rhs) => {
+                // NB: the indexed value can't be borrowed in place, so the
+                // operation is performed on the value returned by the
+                // `INDEX_GET` protocol which is then stored through the
+                // `INDEX_SET` protocol.
+                if !self.call_index_fn(Protocol::INDEX_GET, lhs, (&index,))? {
+                    return Err(VmError::from(VmErrorKind::UnsupportedIndexGet {
+                        target: lhs.type_info()?,
+                        index: index.type_info()?,
+                    }));
+                }
+
+                let offset = self.stack.len() - self.stack.stack_bottom() - 1;
+                self.stack.push(rhs);
+                self.op_assign(InstTarget::Offset(offset), op)?;
+                let value = self.stack.pop()?;
+                let value_type = value.type_info()?;
+
+                if !self.call_index_fn(Protocol::INDEX_SET, lhs, (&index, value))? {
+                    return Err(VmError::from(VmErrorKind::UnsupportedIndexSet {
+                        target: lhs.type_info()?,
+                        index: index.type_info()?,
+                        value: value_type,
+                    }));
+                }
+
+                // NB: like with index set operations, the value produced by
+                // the handler is discarded.
+                self.stack.pop()?;
+                Ok(())
+            }
         }
     }
 
@@ -1185,9 +1728,10 @@ impl Vm {
     fn internal_num(
         &mut self,
         protocol: Protocol,
-        error: fn() -> VmErrorKind,
+        error: fn(Ordering) -> VmErrorKind,
         integer_op: fn(i64, i64) -> Option<i64>,
         float_op: fn(f64, f64) -> f64,
+        big_int_op: fn(&BigInt, &BigInt) -> Option<BigInt>,
         lhs: InstAddress,
         rhs: InstAddress,
     ) -> Result<(), VmError> {
@@ -1196,7 +1740,16 @@ impl Vm {
 
         let (lhs, rhs) = match (lhs, rhs) {
             (Value::Integer(lhs), Value::Integer(rhs)) => {
-                self.stack.push(integer_op(lhs, rhs).ok_or_else(error)?);
+                let out = match integer_op(lhs, rhs) {
+                    Some(out) => Value::Integer(out),
+                    None if self.context.promote_integers => {
+                        let out = big_int_op(&BigInt::from(lhs), &BigInt::from(rhs));
+                        big_int::into_value(out.ok_or_else(|| error(rhs.cmp(&0)))?)
+                    }
+                    None => return Err(VmError::from(error(rhs.cmp(&0)))),
+                };
+
+                self.stack.push(out);
                 return Ok(());
             }
             (Value::Float(lhs), Value::Float(rhs)) => {
@@ -1206,6 +1759,16 @@ impl Vm {
             (lhs, rhs) => (lhs, rhs),
         };
 
+        if let Some(out) = big_int::binary(&lhs, &rhs, big_int_op)? {
+            let out = match out {
+                Some(out) => out,
+                None => return Err(VmError::from(error(big_int::sign(&rhs)?))),
+            };
+
+            self.stack.push(big_int::into_value(out));
+            return Ok(());
+        }
+
         if !self.call_instance_fn(lhs.clone(), protocol, (&rhs,))? {
             return Err(VmError::from(VmErrorKind::UnsupportedBinaryOperation {
                 op: protocol.name,
@@ -1285,6 +1848,7 @@ impl Vm {
     fn internal_infallible_bitwise_assign(
         &mut self,
         target: InstTarget,
+        op: InstAssignOp,
         protocol: Protocol,
         integer_op: fn(&mut i64, i64),
     ) -> Result<(), VmError> {
@@ -1302,7 +1866,7 @@ impl Vm {
             TargetValue::Fallback(fallback) => fallback,
         };
 
-        self.target_fallback(fallback, protocol)
+        self.target_fallback(fallback, protocol, op)
     }
 
     fn internal_bitwise(
@@ -1338,6 +1902,7 @@ impl Vm {
     fn internal_bitwise_assign(
         &mut self,
         target: InstTarget,
+        op: InstAssignOp,
         protocol: Protocol,
         error: fn() -> VmErrorKind,
         integer_op: fn(i64, i64) -> Option<i64>,
@@ -1357,7 +1922,31 @@ impl Vm {
             TargetValue::Fallback(fallback) => fallback,
         };
 
-        self.target_fallback(fallback, protocol)
+        self.target_fallback(fallback, protocol, op)
+    }
+
+    /// Error raised by division operations which are configured to panic on
+    /// division by zero.
+    fn divide_by_zero_panic(_: Ordering) -> VmErrorKind {
+        VmErrorKind::Panic {
+            reason: Panic::from(PanicReason::DivideByZero),
+        }
+    }
+
+    /// Integer exponentiation, which fails on overflow and for negative
+    /// exponents.
+    fn checked_pow(a: i64, b: i64) -> Option<i64> {
+        use std::convert::TryFrom as _;
+        a.checked_pow(u32::try_from(b).ok()?)
+    }
+
+    /// Error raised by a failed exponentiation, given the sign of its
+    /// exponent.
+    fn pow_error(exponent: Ordering) -> VmErrorKind {
+        match exponent {
+            Ordering::Less => VmErrorKind::NegativeExponent,
+            _ => VmErrorKind::Overflow,
+        }
     }
 
     /// Check that arguments matches expected or raise the appropriate error.
@@ -1440,11 +2029,11 @@ impl Vm {
         Ok(())
     }
 
-    /// Copy a value from a position relative to the top of the stack, to the
-    /// top of the stack.
+    /// Copy the value in a local slot of the current call frame to the top of
+    /// the stack.
     #[cfg_attr(feature = "bench", inline(never))]
-    fn op_copy(&mut self, offset: usize) -> Result<(), VmError> {
-        let value = self.stack.at_offset(offset)?.clone();
+    fn op_load_local(&mut self, slot: usize) -> Result<(), VmError> {
+        let value = self.stack.at_offset(slot)?.clone();
         self.stack.push(value);
         Ok(())
     }
@@ -1472,12 +2061,12 @@ impl Vm {
         Ok(())
     }
 
-    /// Copy a value from a position relative to the top of the stack, to the
-    /// top of the stack.
+    /// Pop the top of the stack and store it in a local slot of the current
+    /// call frame.
     #[cfg_attr(feature = "bench", inline(never))]
-    fn op_replace(&mut self, offset: usize) -> Result<(), VmError> {
+    fn op_store_local(&mut self, slot: usize) -> Result<(), VmError> {
         let mut value = self.stack.pop()?;
-        let stack_value = self.stack.at_offset_mut(offset)?;
+        let stack_value = self.stack.at_offset_mut(slot)?;
         mem::swap(stack_value, &mut value);
         Ok(())
     }
@@ -1546,10 +2135,48 @@ impl Vm {
         Ok(())
     }
 
-    /// Construct a new tuple.
+    /// Construct a new vector by repeating a value.
     #[cfg_attr(feature = "bench", inline(never))]
-    fn op_tuple(&mut self, count: usize) -> Result<(), VmError> {
-        let tuple = self.stack.pop_sequence(count)?;
+    fn op_vec_repeat(&mut self) -> Result<(), VmError> {
+        let count = usize::from_value(self.stack.pop()?)?;
+        let value = self.stack.pop()?;
+
+        if count > Vec::MAX_REPEAT {
+            return Err(VmError::from(VmErrorKind::VecRepeatTooLarge {
+                count,
+                max: Vec::MAX_REPEAT,
+            }));
+        }
+
+        let mut values = vec::Vec::new();
+
+        // NB: the count is bounded above, but the allocation might still fail.
+        if values.try_reserve_exact(count).is_err() {
+            return Err(VmError::from(VmErrorKind::VecAllocationFailed { count }));
+        }
+
+        for _ in 0..count {
+            values.push(clone_container(&value)?);
+        }
+
+        self.stack.push(Shared::new(Vec::from(values)));
+        Ok(())
+    }
+
+    /// Extend a vector with the elements of an iterable.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_vec_extend(&mut self) -> Result<(), VmError> {
+        let iterable = self.stack.pop()?;
+        let vec = self.stack.pop()?.into_vec()?;
+        vec.borrow_mut()?.extend(iterable)?;
+        self.stack.push(vec);
+        Ok(())
+    }
+
+    /// Construct a new tuple.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_tuple(&mut self, count: usize) -> Result<(), VmError> {
+        let tuple = self.stack.pop_sequence(count)?;
         self.stack.push(Tuple::from(tuple));
         Ok(())
     }
@@ -1595,6 +2222,40 @@ impl Vm {
         Ok(())
     }
 
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_bool_not(&mut self) -> Result<(), VmError> {
+        let value = match self.stack.pop()? {
+            Value::Bool(value) => Value::from(!value),
+            other => {
+                let operand = other.type_info()?;
+                return Err(VmError::from(VmErrorKind::UnsupportedUnaryOperation {
+                    op: "!",
+                    operand,
+                }));
+            }
+        };
+
+        self.stack.push(value);
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_bit_not(&mut self) -> Result<(), VmError> {
+        let value = match self.stack.pop()? {
+            Value::Integer(value) => Value::from(!value),
+            other => {
+                let operand = other.type_info()?;
+                return Err(VmError::from(VmErrorKind::UnsupportedUnaryOperation {
+                    op: "!",
+                    operand,
+                }));
+            }
+        };
+
+        self.stack.push(value);
+        Ok(())
+    }
+
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_neg(&mut self) -> Result<(), VmError> {
         let value = self.stack.pop()?;
@@ -1623,9 +2284,10 @@ impl Vm {
             InstOp::Add => {
                 self.internal_num(
                     Protocol::ADD,
-                    || VmErrorKind::Overflow,
+                    |_| VmErrorKind::Overflow,
                     i64::checked_add,
                     std::ops::Add::add,
+                    big_int::add,
                     lhs,
                     rhs,
                 )?;
@@ -1633,9 +2295,10 @@ impl Vm {
             InstOp::Sub => {
                 self.internal_num(
                     Protocol::SUB,
-                    || VmErrorKind::Underflow,
+                    |_| VmErrorKind::Underflow,
                     i64::checked_sub,
                     std::ops::Sub::sub,
+                    big_int::sub,
                     lhs,
                     rhs,
                 )?;
@@ -1643,9 +2306,10 @@ impl Vm {
             InstOp::Mul => {
                 self.internal_num(
                     Protocol::MUL,
-                    || VmErrorKind::Overflow,
+                    |_| VmErrorKind::Overflow,
                     i64::checked_mul,
                     std::ops::Mul::mul,
+                    big_int::mul,
                     lhs,
                     rhs,
                 )?;
@@ -1653,9 +2317,21 @@ impl Vm {
             InstOp::Div => {
                 self.internal_num(
                     Protocol::DIV,
-                    || VmErrorKind::DivideByZero,
+                    |_| VmErrorKind::DivideByZero,
+                    i64::checked_div,
+                    std::ops::Div::div,
+                    big_int::div,
+                    lhs,
+                    rhs,
+                )?;
+            }
+            InstOp::DivPanic => {
+                self.internal_num(
+                    Protocol::DIV,
+                    Self::divide_by_zero_panic,
                     i64::checked_div,
                     std::ops::Div::div,
+                    big_int::div,
                     lhs,
                     rhs,
                 )?;
@@ -1663,9 +2339,32 @@ impl Vm {
             InstOp::Rem => {
                 self.internal_num(
                     Protocol::REM,
-                    || VmErrorKind::DivideByZero,
+                    |_| VmErrorKind::DivideByZero,
+                    i64::checked_rem,
+                    std::ops::Rem::rem,
+                    big_int::rem,
+                    lhs,
+                    rhs,
+                )?;
+            }
+            InstOp::RemPanic => {
+                self.internal_num(
+                    Protocol::REM,
+                    Self::divide_by_zero_panic,
                     i64::checked_rem,
                     std::ops::Rem::rem,
+                    big_int::rem,
+                    lhs,
+                    rhs,
+                )?;
+            }
+            InstOp::Pow => {
+                self.internal_num(
+                    Protocol::POW,
+                    Self::pow_error,
+                    Self::checked_pow,
+                    f64::powf,
+                    big_int::pow,
                     lhs,
                     rhs,
                 )?;
@@ -1713,16 +2412,30 @@ impl Vm {
                 self.internal_infallible_bitwise(Protocol::SHR, std::ops::Shr::shr, lhs, rhs)?;
             }
             InstOp::Gt => {
-                self.internal_boolean_ops(|a, b| a > b, |a, b| a > b, ">", lhs, rhs)?;
+                self.internal_boolean_ops(|a, b| a > b, |a, b| a > b, |a, b| a > b, ">", lhs, rhs)?;
             }
             InstOp::Gte => {
-                self.internal_boolean_ops(|a, b| a >= b, |a, b| a >= b, ">=", lhs, rhs)?;
+                self.internal_boolean_ops(
+                    |a, b| a >= b,
+                    |a, b| a >= b,
+                    |a, b| a >= b,
+                    ">=",
+                    lhs,
+                    rhs,
+                )?;
             }
             InstOp::Lt => {
-                self.internal_boolean_ops(|a, b| a < b, |a, b| a < b, "<", lhs, rhs)?;
+                self.internal_boolean_ops(|a, b| a < b, |a, b| a < b, |a, b| a < b, "<", lhs, rhs)?;
             }
             InstOp::Lte => {
-                self.internal_boolean_ops(|a, b| a <= b, |a, b| a <= b, "<=", lhs, rhs)?;
+                self.internal_boolean_ops(
+                    |a, b| a <= b,
+                    |a, b| a <= b,
+                    |a, b| a <= b,
+                    "<=",
+                    lhs,
+                    rhs,
+                )?;
             }
             InstOp::Eq => {
                 let rhs = self.stack.address(rhs)?;
@@ -1750,6 +2463,9 @@ impl Vm {
                 let is_instance = self.is_instance(lhs, rhs)?;
                 self.stack.push(!is_instance);
             }
+            InstOp::As => {
+                self.internal_as(lhs, rhs)?;
+            }
         }
 
         Ok(())
@@ -1763,51 +2479,95 @@ impl Vm {
             InstAssignOp::Add => {
                 self.internal_num_assign(
                     target,
+                    op,
                     Protocol::ADD_ASSIGN,
-                    || VmErrorKind::Overflow,
+                    |_| VmErrorKind::Overflow,
                     i64::checked_add,
                     std::ops::Add::add,
+                    big_int::add,
                 )?;
             }
             InstAssignOp::Sub => {
                 self.internal_num_assign(
                     target,
+                    op,
                     Protocol::SUB_ASSIGN,
-                    || VmErrorKind::Underflow,
+                    |_| VmErrorKind::Underflow,
                     i64::checked_sub,
                     std::ops::Sub::sub,
+                    big_int::sub,
                 )?;
             }
             InstAssignOp::Mul => {
                 self.internal_num_assign(
                     target,
+                    op,
                     Protocol::MUL_ASSIGN,
-                    || VmErrorKind::Overflow,
+                    |_| VmErrorKind::Overflow,
                     i64::checked_mul,
                     std::ops::Mul::mul,
+                    big_int::mul,
                 )?;
             }
             InstAssignOp::Div => {
                 self.internal_num_assign(
                     target,
+                    op,
                     Protocol::DIV_ASSIGN,
-                    || VmErrorKind::DivideByZero,
+                    |_| VmErrorKind::DivideByZero,
                     i64::checked_div,
                     std::ops::Div::div,
+                    big_int::div,
+                )?;
+            }
+            InstAssignOp::DivPanic => {
+                self.internal_num_assign(
+                    target,
+                    op,
+                    Protocol::DIV_ASSIGN,
+                    Self::divide_by_zero_panic,
+                    i64::checked_div,
+                    std::ops::Div::div,
+                    big_int::div,
                 )?;
             }
             InstAssignOp::Rem => {
                 self.internal_num_assign(
                     target,
+                    op,
+                    Protocol::REM_ASSIGN,
+                    |_| VmErrorKind::DivideByZero,
+                    i64::checked_rem,
+                    std::ops::Rem::rem,
+                    big_int::rem,
+                )?;
+            }
+            InstAssignOp::RemPanic => {
+                self.internal_num_assign(
+                    target,
+                    op,
                     Protocol::REM_ASSIGN,
-                    || VmErrorKind::DivideByZero,
+                    Self::divide_by_zero_panic,
                     i64::checked_rem,
                     std::ops::Rem::rem,
+                    big_int::rem,
+                )?;
+            }
+            InstAssignOp::Pow => {
+                self.internal_num_assign(
+                    target,
+                    op,
+                    Protocol::POW_ASSIGN,
+                    Self::pow_error,
+                    Self::checked_pow,
+                    f64::powf,
+                    big_int::pow,
                 )?;
             }
             InstAssignOp::BitAnd => {
                 self.internal_infallible_bitwise_assign(
                     target,
+                    op,
                     Protocol::BIT_AND_ASSIGN,
                     std::ops::BitAndAssign::bitand_assign,
                 )?;
@@ -1815,6 +2575,7 @@ impl Vm {
             InstAssignOp::BitXor => {
                 self.internal_infallible_bitwise_assign(
                     target,
+                    op,
                     Protocol::BIT_XOR_ASSIGN,
                     std::ops::BitXorAssign::bitxor_assign,
                 )?;
@@ -1822,6 +2583,7 @@ impl Vm {
             InstAssignOp::BitOr => {
                 self.internal_infallible_bitwise_assign(
                     target,
+                    op,
                     Protocol::BIT_OR_ASSIGN,
                     std::ops::BitOrAssign::bitor_assign,
                 )?;
@@ -1829,6 +2591,7 @@ impl Vm {
             InstAssignOp::Shl => {
                 self.internal_bitwise_assign(
                     target,
+                    op,
                     Protocol::SHL_ASSIGN,
                     || VmErrorKind::Overflow,
                     |a, b| a.checked_shl(u32::try_from(b).ok()?),
@@ -1837,6 +2600,7 @@ impl Vm {
             InstAssignOp::Shr => {
                 self.internal_infallible_bitwise_assign(
                     target,
+                    op,
                     Protocol::SHR_ASSIGN,
                     std::ops::ShrAssign::shr_assign,
                 )?;
@@ -2240,6 +3004,8 @@ impl Vm {
         let mut buf = String::with_capacity(16);
 
         for value in values {
+            buf.clear();
+
             if let Err(fmt::Error) = value.string_display_with(&mut out, &mut buf, &mut *self)? {
                 return Err(VmError::from(VmErrorKind::FormatError));
             }
@@ -2349,6 +3115,18 @@ impl Vm {
         Ok(())
     }
 
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_eq_float(&mut self, float: f64) -> Result<(), VmError> {
+        let value = self.stack.pop()?;
+
+        self.stack.push(match value {
+            Value::Float(actual) => actual == float,
+            _ => false,
+        });
+
+        Ok(())
+    }
+
     /// Test if the top of stack is equal to the string at the given static
     /// string location.
     #[cfg_attr(feature = "bench", inline(never))]
@@ -2373,6 +3151,26 @@ impl Vm {
         Ok(())
     }
 
+    /// Test if the top of stack is equal to the byte string at the given
+    /// static byte string location.
+    #[cfg_attr(feature = "bench", inline(never))]
+    fn op_eq_static_bytes(&mut self, slot: usize) -> Result<(), VmError> {
+        let value = self.stack.pop()?;
+
+        let equal = match value {
+            Value::Bytes(actual) => {
+                let bytes = self.unit.lookup_bytes(slot)?;
+                let actual = actual.borrow_ref()?;
+                *actual == *bytes
+            }
+            _ => false,
+        };
+
+        self.stack.push(Value::Bool(equal));
+
+        Ok(())
+    }
+
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_match_sequence(&mut self, ty: TypeCheck, len: usize, exact: bool) -> Result<(), VmError> {
         let value = self.stack.pop()?;
@@ -2497,7 +3295,7 @@ impl Vm {
                     .lookup(hash)
                     .ok_or_else(|| VmErrorKind::MissingFunction { hash })?;
 
-                Function::from_handler(handler.clone(), hash)
+                Function::from_handler(self.context.clone(), handler.clone(), hash)
             }
         };
 
@@ -2535,8 +3333,17 @@ impl Vm {
     }
 
     /// Implementation of a function call.
+    ///
+    /// If `resume` is set, a resumable native function is allowed to yield, in
+    /// which case its state is returned and the instruction is executed again
+    /// once the virtual machine is resumed.
     #[cfg_attr(feature = "bench", inline(never))]
-    fn op_call(&mut self, hash: Hash, args: usize) -> Result<(), VmError> {
+    fn op_call(
+        &mut self,
+        hash: Hash,
+        args: usize,
+        resume: bool,
+    ) -> Result<Option<Suspended>, VmError> {
         match self.unit.lookup(hash) {
             Some(info) => match info {
                 UnitFn::Offset {
@@ -2597,16 +3404,29 @@ impl Vm {
                 }
             },
             None => {
-                let handler = self
+                let function = self
                     .context
-                    .lookup(hash)
+                    .lookup_function(hash)
                     .ok_or_else(|| VmErrorKind::MissingFunction { hash })?;
 
-                handler(&mut self.stack, args)?;
+                let _ip = crate::env::set_ip(self.ip);
+
+                if resume && function.resumable {
+                    let suspended = self.suspended.take();
+                    let context = &self.context;
+                    let stack = &mut self.stack;
+
+                    return resume::call(suspended, || {
+                        context.call_handler(&function.handler, stack, args)
+                    });
+                }
+
+                self.context
+                    .call_handler(&function.handler, &mut self.stack, args)?;
             }
         }
 
-        Ok(())
+        Ok(None)
     }
 
     #[cfg_attr(feature = "bench", inline(never))]
@@ -2622,45 +3442,100 @@ impl Vm {
     fn inner_op_call_instance(&mut self, inst_fn: Hash, args: usize) -> Result<(), VmError> {
         // NB: +1 to include the instance itself.
         let args = args + 1;
-        let instance = self.stack.at_offset_from_top(args)?;
-        let type_hash = instance.type_hash()?;
-        let hash = Hash::instance_function(type_hash, inst_fn);
+        let type_hash = self.stack.at_offset_from_top(args)?.type_hash()?;
 
-        match self.unit.lookup(hash) {
-            Some(info) => match info {
-                UnitFn::Offset {
-                    offset,
-                    call,
-                    args: expected,
-                } => {
-                    Self::check_args(args, expected)?;
-                    self.call_offset_fn(offset, call, args)?;
-                }
-                _ => {
-                    return Err(VmError::from(VmErrorKind::MissingInstanceFunction {
-                        instance: instance.type_info()?,
-                        hash,
-                    }));
-                }
-            },
+        let (offset, call, expected) = match self.inline_cache.get(self.ip, type_hash) {
+            Some(CachedFn::Offset { offset, call, args }) => (*offset, *call, *args),
+            Some(CachedFn::Handler(handler)) => {
+                let _ip = crate::env::set_ip(self.ip);
+                self.context.call_handler(handler, &mut self.stack, args)?;
+                return Ok(());
+            }
             None => {
-                let handler = match self.context.lookup(hash) {
-                    Some(handler) => handler,
+                let hash = Hash::instance_function(type_hash, inst_fn);
+
+                match self.unit.lookup(hash) {
+                    Some(UnitFn::Offset {
+                        offset,
+                        call,
+                        args: expected,
+                    }) => {
+                        let target = CachedFn::Offset {
+                            offset,
+                            call,
+                            args: expected,
+                        };
+
+                        self.inline_cache.insert(self.ip, type_hash, target);
+                        (offset, call, expected)
+                    }
+                    Some(..) => {
+                        return Err(self.missing_instance_function(args, hash));
+                    }
                     None => {
-                        return Err(VmError::from(VmErrorKind::MissingInstanceFunction {
-                            instance: instance.type_info()?,
-                            hash,
-                        }));
+                        let handler = match self.context.lookup(hash) {
+                            Some(handler) => handler,
+                            None if inst_fn == Protocol::IS_EMPTY.hash => {
+                                return self.call_derived_is_empty(type_hash, args, hash);
+                            }
+                            None => {
+                                return Err(self.missing_instance_function(args, hash));
+                            }
+                        };
+
+                        let target = CachedFn::Handler(handler.clone());
+                        self.inline_cache.insert(self.ip, type_hash, target);
+                        let _ip = crate::env::set_ip(self.ip);
+                        self.context.call_handler(handler, &mut self.stack, args)?;
+                        return Ok(());
                     }
-                };
-
-                handler(&mut self.stack, args)?;
+                }
             }
-        }
+        };
 
+        Self::check_args(args, expected)?;
+        self.call_offset_fn(offset, call, args)?;
         Ok(())
     }
 
+    /// Call `is_empty` on a type which doesn't implement it, by deriving it
+    /// from its native [Protocol::LEN] implementation.
+    #[cold]
+    fn call_derived_is_empty(
+        &mut self,
+        type_hash: Hash,
+        args: usize,
+        hash: Hash,
+    ) -> Result<(), VmError> {
+        let len = Hash::instance_function(type_hash, Protocol::LEN);
+
+        let handler = match self.context.lookup(len) {
+            Some(handler) if args == 1 => handler,
+            _ => return Err(self.missing_instance_function(args, hash)),
+        };
+
+        let _ip = crate::env::set_ip(self.ip);
+        self.context.call_handler(handler, &mut self.stack, args)?;
+        let len = usize::from_value(self.stack.pop()?)?;
+        self.stack.push(len == 0);
+        Ok(())
+    }
+
+    /// Construct an error indicating that the instance function with the given
+    /// `hash` is missing for the instance at `args` from the top of the stack.
+    #[cold]
+    fn missing_instance_function(&self, args: usize, hash: Hash) -> VmError {
+        let instance = match self.stack.at_offset_from_top(args) {
+            Ok(instance) => instance,
+            Err(error) => return VmError::from(error),
+        };
+
+        match instance.type_info() {
+            Ok(instance) => VmError::from(VmErrorKind::MissingInstanceFunction { instance, hash }),
+            Err(error) => error,
+        }
+    }
+
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_call_fn(&mut self, args: usize) -> Result<Option<VmHalt>, VmError> {
         let function = self.stack.pop()?;
@@ -2679,7 +3554,7 @@ impl Vm {
             }
         };
 
-        self.op_call(hash, args)?;
+        self.op_call(hash, args, false)?;
         Ok(None)
     }
 
@@ -2710,6 +3585,103 @@ impl Vm {
         Ok(())
     }
 
+    /// Test if the given instruction is about to write to a watched field.
+    fn is_watched_write(&self, inst: Inst) -> Result<bool, VmError> {
+        let (target, field) = match inst {
+            Inst::ObjectIndexSet { slot } => (
+                self.stack.at_offset_from_top(1)?,
+                self.unit.lookup_string(slot)?.as_str(),
+            ),
+            Inst::Assign {
+                target: InstTarget::Field(slot),
+                ..
+            } => (
+                self.stack.at_offset_from_top(2)?,
+                self.unit.lookup_string(slot)?.as_str(),
+            ),
+            Inst::TupleIndexSet { index } => {
+                let target = self.stack.at_offset_from_top(1)?;
+                return Ok(self.is_watched(target, |f| f.parse::<usize>().ok() == Some(index)));
+            }
+            Inst::Assign {
+                target: InstTarget::TupleField(index),
+                ..
+            } => {
+                let target = self.stack.at_offset_from_top(2)?;
+                return Ok(self.is_watched(target, |f| f.parse::<usize>().ok() == Some(index)));
+            }
+            Inst::IndexSet => {
+                return self.is_watched_index(
+                    self.stack.at_offset_from_top(2)?,
+                    self.stack.at_offset_from_top(1)?,
+                );
+            }
+            Inst::Assign {
+                target: InstTarget::Index,
+                ..
+            } => {
+                return self.is_watched_index(
+                    self.stack.at_offset_from_top(3)?,
+                    self.stack.at_offset_from_top(2)?,
+                );
+            }
+            _ => return Ok(false),
+        };
+
+        Ok(self.is_watched(target, |f| f == field))
+    }
+
+    /// Test if writing to the given index of the target is watched.
+    ///
+    /// String indexes are matched against the watched field, while integer
+    /// indexes are matched against its decimal representation.
+    fn is_watched_index(&self, target: &Value, index: &Value) -> Result<bool, VmError> {
+        Ok(match index {
+            Value::String(string) => {
+                let string = string.borrow_ref()?;
+                self.is_watched(target, |f| f == string.as_str())
+            }
+            Value::StaticString(string) => self.is_watched(target, |f| f == string.as_str()),
+            Value::Integer(index) => {
+                self.is_watched(target, |f| f.parse::<i64>().ok() == Some(*index))
+            }
+            _ => false,
+        })
+    }
+
+    /// Test if any watchpoint on the given target matches the field.
+    fn is_watched(&self, target: &Value, matches: impl Fn(&str) -> bool) -> bool {
+        self.watchpoints
+            .iter()
+            .any(|(t, f)| matches(f) && is_same_shared(t, target))
+    }
+
+    /// Test if there's a breakpoint at the current instruction whose condition
+    /// holds.
+    fn is_breakpoint_hit(&self) -> bool {
+        let breakpoint = match self.breakpoints.iter().find(|b| b.ip == self.ip) {
+            Some(breakpoint) => breakpoint,
+            None => return false,
+        };
+
+        let condition = match &breakpoint.condition {
+            Some(condition) => condition,
+            None => return true,
+        };
+
+        match condition.test(&self.frame_view(self.call_frames.len())) {
+            Ok(hit) => hit,
+            Err(error) => {
+                log::warn!(
+                    "breakpoint condition at instruction {} failed, halting: {}",
+                    self.ip,
+                    error
+                );
+                true
+            }
+        }
+    }
+
     /// Evaluate a single instruction.
     pub(crate) fn run(&mut self) -> Result<VmHalt, VmError> {
         // NB: set up environment so that native function can access context and
@@ -2728,10 +3700,34 @@ impl Vm {
 
             log::trace!("{}: {}", self.ip, inst);
 
+            if !self.breakpoints.is_empty()
+                && !mem::take(&mut self.resume_breakpoint)
+                && self.suspended.is_none()
+                && self.is_breakpoint_hit()
+            {
+                let ip = self.ip;
+                let span = self
+                    .unit
+                    .debug_info()
+                    .and_then(|debug| debug.instruction_at(ip))
+                    .map(|inst| inst.span);
+
+                self.resume_breakpoint = true;
+                return Ok(VmHalt::Breakpoint { ip, span });
+            }
+
+            let watched = !self.watchpoints.is_empty() && self.is_watched_write(inst)?;
+
             match inst {
                 Inst::Not => {
                     self.op_not()?;
                 }
+                Inst::BoolNot => {
+                    self.op_bool_not()?;
+                }
+                Inst::BitNot => {
+                    self.op_bit_not()?;
+                }
                 Inst::Neg => {
                     self.op_neg()?;
                 }
@@ -2739,7 +3735,10 @@ impl Vm {
                     self.op_closure(hash, count)?;
                 }
                 Inst::Call { hash, args } => {
-                    self.op_call(hash, args)?;
+                    if let Some(suspended) = self.op_call(hash, args, true)? {
+                        self.suspended = Some(suspended);
+                        continue;
+                    }
                 }
                 Inst::CallInstance { hash, args } => {
                     self.op_call_instance(hash, args)?;
@@ -2817,8 +3816,8 @@ impl Vm {
                 Inst::Clean { count } => {
                     self.op_clean(count)?;
                 }
-                Inst::Copy { offset } => {
-                    self.op_copy(offset)?;
+                Inst::LoadLocal { slot } => {
+                    self.op_load_local(slot)?;
                 }
                 Inst::Move { offset } => {
                     self.op_move(offset)?;
@@ -2829,8 +3828,8 @@ impl Vm {
                 Inst::Dup => {
                     self.op_dup()?;
                 }
-                Inst::Replace { offset } => {
-                    self.op_replace(offset)?;
+                Inst::StoreLocal { slot } => {
+                    self.op_store_local(slot)?;
                 }
                 Inst::Jump { offset } => {
                     self.op_jump(offset)?;
@@ -2850,6 +3849,12 @@ impl Vm {
                 Inst::Vec { count } => {
                     self.op_vec(count)?;
                 }
+                Inst::VecRepeat => {
+                    self.op_vec_repeat()?;
+                }
+                Inst::VecExtend => {
+                    self.op_vec_extend()?;
+                }
                 Inst::Tuple { count } => {
                     self.op_tuple(count)?;
                 }
@@ -2916,12 +3921,18 @@ impl Vm {
                 Inst::EqInteger { integer } => {
                     self.op_eq_integer(integer)?;
                 }
+                Inst::EqFloat { float } => {
+                    self.op_eq_float(float)?;
+                }
                 Inst::EqBool { boolean } => {
                     self.op_eq_bool(boolean)?;
                 }
                 Inst::EqStaticString { slot } => {
                     self.op_eq_static_string(slot)?;
                 }
+                Inst::EqStaticBytes { slot } => {
+                    self.op_eq_static_bytes(slot)?;
+                }
                 Inst::MatchSequence {
                     type_check,
                     len,
@@ -2964,11 +3975,50 @@ impl Vm {
                 }
             }
 
+            if watched {
+                let ip = self.ip;
+                let span = self
+                    .unit
+                    .debug_info()
+                    .and_then(|debug| debug.instruction_at(ip))
+                    .map(|inst| inst.span);
+
+                self.advance();
+                return Ok(VmHalt::Watchpoint { ip, span });
+            }
+
             self.advance();
         }
     }
 }
 
+/// Test if the two values refer to the same shared object, struct, variant or
+/// external value.
+fn is_same_shared(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Vec(a), Value::Vec(b)) => Shared::ptr_eq(a, b),
+        (Value::Tuple(a), Value::Tuple(b)) => Shared::ptr_eq(a, b),
+        (Value::Object(a), Value::Object(b)) => Shared::ptr_eq(a, b),
+        (Value::Struct(a), Value::Struct(b)) => Shared::ptr_eq(a, b),
+        (Value::Variant(a), Value::Variant(b)) => Shared::ptr_eq(a, b),
+        (Value::Any(a), Value::Any(b)) => Shared::ptr_eq(a, b),
+        _ => false,
+    }
+}
+
+/// Clone the given value, making a copy of any built-in container like a
+/// vector or an object. Elements of the container are not cloned.
+fn clone_container(value: &Value) -> Result<Value, VmError> {
+    Ok(match value {
+        Value::String(string) => Value::from(string.borrow_ref()?.clone()),
+        Value::Bytes(bytes) => Value::from(bytes.borrow_ref()?.clone()),
+        Value::Vec(vec) => Value::from(vec.borrow_ref()?.clone()),
+        Value::Tuple(tuple) => Value::from(tuple.borrow_ref()?.clone()),
+        Value::Object(object) => Value::from(object.borrow_ref()?.clone()),
+        value => value.clone(),
+    })
+}
+
 /// A call frame.
 ///
 /// This is used to store the return point after an instruction has been run.
@@ -2976,6 +4026,8 @@ impl Vm {
 pub struct CallFrame {
     /// The stored instruction pointer.
     ip: usize,
+    /// The offset of the function which was executing.
+    entry: Option<usize>,
     /// The top of the stack at the time of the call to ensure stack isolation
     /// across function calls.
     ///
@@ -2995,3 +4047,106 @@ impl CallFrame {
         self.stack_bottom
     }
 }
+
+/// A breakpoint set on an instruction.
+#[derive(Debug, Clone)]
+struct Breakpoint {
+    /// The instruction pointer of the breakpoint.
+    ip: usize,
+    /// The condition which has to hold for the breakpoint to halt execution.
+    condition: Option<BreakpointCondition>,
+}
+
+/// A native breakpoint condition.
+type ConditionFn = dyn Fn(&FrameView<'_>) -> Result<bool, VmError> + Send + Sync;
+
+/// The condition of a breakpoint, evaluated against the executing frame.
+#[derive(Clone)]
+enum BreakpointCondition {
+    /// A native function called with a view of the frame.
+    Native(Arc<ConditionFn>),
+    /// A script function called with an object of the named variables in the
+    /// frame.
+    Function(Rc<Function>),
+}
+
+impl BreakpointCondition {
+    /// Test if the condition holds for the given frame.
+    fn test(&self, frame: &FrameView<'_>) -> Result<bool, VmError> {
+        match self {
+            Self::Native(condition) => condition(frame),
+            Self::Function(function) => {
+                let mut locals = Object::new();
+
+                for (name, value) in frame.locals() {
+                    if let Some(name) = name {
+                        locals.insert(name.to_owned(), value.clone());
+                    }
+                }
+
+                function.call((locals,))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BreakpointCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Native(..) => write!(f, "Native"),
+            Self::Function(function) => f.debug_tuple("Function").field(function).finish(),
+        }
+    }
+}
+
+/// A view into an active call frame, as produced by [Vm::frames].
+#[derive(Debug, Clone)]
+pub struct FrameView<'a> {
+    /// The item of the function executing in the frame.
+    item: Option<&'a Item>,
+    /// The instruction pointer of the frame.
+    ip: usize,
+    /// Values on the stack of the frame.
+    values: &'a [Value],
+    /// Names of variables in scope, indexed by stack offset.
+    names: vec::Vec<Option<&'a str>>,
+}
+
+impl<'a> FrameView<'a> {
+    /// Get the item of the function executing in the frame, if known.
+    pub fn item(&self) -> Option<&'a Item> {
+        self.item
+    }
+
+    /// Get the instruction pointer of the frame.
+    ///
+    /// For the frame which is currently executing this is the next
+    /// instruction to execute, for other frames it's the instruction which
+    /// called into the frame above it.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Get the values on the stack of the frame, starting at its bottom.
+    pub fn values(&self) -> &'a [Value] {
+        self.values
+    }
+
+    /// Iterate over the values on the stack of the frame together with the
+    /// name of the variable they belong to, if any.
+    pub fn locals(&self) -> impl Iterator<Item = (Option<&'a str>, &'a Value)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(offset, value)| (self.names.get(offset).copied().flatten(), value))
+    }
+
+    /// Get the value of the variable with the given name.
+    ///
+    /// If multiple variables in scope share the name, the most recently
+    /// declared one is returned.
+    pub fn get(&self, name: &str) -> Option<&'a Value> {
+        let offset = self.names.iter().rposition(|n| *n == Some(name))?;
+        self.values.get(offset)
+    }
+}
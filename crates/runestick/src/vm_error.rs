@@ -1,7 +1,7 @@
 use crate::{panic::BoxedPanic, CallFrame};
 use crate::{
-    AccessError, Hash, Item, Key, Panic, Protocol, StackError, TypeInfo, TypeOf, Unit, Value,
-    VmHaltInfo,
+    AccessError, Any, Hash, Item, Key, Location, Panic, Protocol, RawStr, StackError, TypeInfo,
+    TypeOf, Unit, Value, VmHaltInfo,
 };
 use std::fmt;
 use std::sync::Arc;
@@ -53,6 +53,17 @@ impl VmError {
         Self::from(VmErrorKind::ExpectedAny { actual })
     }
 
+    /// Construct an expected error for the external type `T`.
+    pub fn expected_external<T>(actual: TypeInfo) -> Self
+    where
+        T: Any,
+    {
+        Self::from(VmErrorKind::Expected {
+            expected: TypeInfo::Any(RawStr::from_str(std::any::type_name::<T>())),
+            actual,
+        })
+    }
+
     /// Access the underlying error kind.
     pub fn kind(&self) -> &VmErrorKind {
         &*self.kind
@@ -95,6 +106,20 @@ impl VmError {
         }
     }
 
+    /// Get the source location of the instruction which raised the error.
+    ///
+    /// This is only available if the error has been unwound and the unit it
+    /// was raised in has debug information.
+    pub fn location(&self) -> Option<Location> {
+        let (unit, ip) = match &*self.kind {
+            VmErrorKind::Unwound { unit, ip, .. } => (unit, *ip),
+            _ => return None,
+        };
+
+        let inst = unit.debug_info()?.instruction_at(ip)?;
+        Some(Location::new(inst.source_id, inst.span))
+    }
+
     /// Unpack an unwinded error, if it is present.
     pub fn into_unwound(self) -> (Self, Option<(Arc<Unit>, usize, Vec<CallFrame>)>) {
         match *self.kind {
@@ -127,6 +152,7 @@ impl VmError {
     fn is_critical(&self) -> bool {
         match &*self.kind {
             VmErrorKind::Panic { .. } => true,
+            VmErrorKind::NativePanic { .. } => true,
             VmErrorKind::Unwound { .. } => true,
             _ => false,
         }
@@ -170,10 +196,21 @@ pub enum VmErrorKind {
     },
     #[error("panicked: {reason}")]
     Panic { reason: Panic },
+    #[error("native function panicked: {message}")]
+    NativePanic { message: String },
+    /// An error raised by a native function, which retains its message and
+    /// source chain.
+    #[error(transparent)]
+    Custom {
+        #[from]
+        error: Box<dyn std::error::Error + Send + Sync>,
+    },
     #[error("no running virtual machines")]
     NoRunningVm,
     #[error("halted for unexpected reason `{halt}`")]
     Halted { halt: VmHaltInfo },
+    #[error("virtual machine holds debug state which can't be sent to another thread")]
+    UnsendableDebugState,
     #[error("failed to format argument")]
     FormatError,
     #[error("stack error: {error}")]
@@ -187,12 +224,16 @@ pub enum VmErrorKind {
     Underflow,
     #[error("division by zero")]
     DivideByZero,
+    #[error("negative exponent")]
+    NegativeExponent,
     #[error("missing constant with hash `{hash}`")]
     MissingConst { hash: Hash },
     #[error("missing entry `{item}` with hash `{hash}`")]
     MissingEntry { item: Item, hash: Hash },
     #[error("missing function with hash `{hash}`")]
     MissingFunction { hash: Hash },
+    #[error("function with hash `{hash}` can't be replaced by one with a different signature")]
+    FunctionSignatureMismatch { hash: Hash },
     #[error("missing instance function `{hash}` for `{instance}`")]
     MissingInstanceFunction { hash: Hash, instance: TypeInfo },
     #[error("instruction pointer is out-of-bounds")]
@@ -253,6 +294,8 @@ pub enum VmErrorKind {
         value: TypeInfo,
         test_type: TypeInfo,
     },
+    #[error("missing conversion from `{from}` to `{to}`")]
+    MissingConversion { from: TypeInfo, to: TypeInfo },
     #[error("`{actual_type}` cannot be called since it's not a function")]
     UnsupportedCallFn { actual_type: TypeInfo },
     #[error("missing index by static string slot `{slot}` in object")]
@@ -306,6 +349,14 @@ pub enum VmErrorKind {
         from: VmIntegerRepr,
         to: &'static str,
     },
+    #[error("value is nested too deeply, the maximum depth is `{max}`")]
+    TooDeep { max: usize },
+    #[error("cannot repeat a value `{count}` times, the maximum is `{max}`")]
+    VecRepeatTooLarge { count: usize, max: usize },
+    #[error("failed to allocate a vector with `{count}` elements")]
+    VecAllocationFailed { count: usize },
+    #[error("failed to convert value `{from}` to float `{to}`")]
+    ValueToFloatCoercionError { from: f64, to: &'static str },
     #[error("expected a tuple of length `{expected}`, but found one with length `{actual}`")]
     ExpectedTupleLength { actual: usize, expected: usize },
     #[error("unexpectedly ran out of items to iterate over")]
@@ -326,6 +377,10 @@ pub enum VmErrorKind {
     IndexOutOfBounds,
     #[error("unsupported range")]
     UnsupportedRange,
+    #[error("byte index {offset} is not a char boundary")]
+    NotCharBoundary { offset: usize },
+    #[error("handle `{index}` refers to a value which has been removed")]
+    StaleHandle { index: usize },
 }
 
 impl VmErrorKind {
@@ -1,6 +1,7 @@
 use crate::budget;
+use crate::debug_limit;
 use crate::internal;
-use crate::{GeneratorState, Value, Vm, VmError, VmErrorKind, VmHalt, VmHaltInfo};
+use crate::{GeneratorState, Value, Vm, VmDebugState, VmError, VmErrorKind, VmHalt, VmHaltInfo};
 use std::future::Future;
 
 /// The execution environment for a virtual machine.
@@ -57,6 +58,20 @@ impl VmExecution {
 
     /// Resume the current execution with support for async instructions.
     pub async fn async_resume(&mut self) -> Result<GeneratorState, VmError> {
+        Self::into_generator_state(self.async_resume_debug().await?)
+    }
+
+    /// Resume the current execution without support for async instructions.
+    ///
+    /// If any async instructions are encountered, this will error.
+    pub fn resume(&mut self) -> Result<GeneratorState, VmError> {
+        Self::into_generator_state(self.resume_debug()?)
+    }
+
+    /// Resume the current execution with support for async instructions,
+    /// halting with [VmDebugState::Halted] when a watchpoint or breakpoint is
+    /// hit.
+    pub async fn async_resume_debug(&mut self) -> Result<VmDebugState, VmError> {
         loop {
             let len = self.vms.len();
             let vm = self.vm_mut()?;
@@ -71,7 +86,10 @@ impl VmExecution {
                     vm_call.into_execution(self)?;
                     continue;
                 }
-                VmHalt::Yielded => return Ok(GeneratorState::Yielded(vm.stack_mut().pop()?)),
+                VmHalt::Yielded => return Ok(VmDebugState::Yielded(vm.stack_mut().pop()?)),
+                halt @ VmHalt::Watchpoint { .. } | halt @ VmHalt::Breakpoint { .. } => {
+                    return Ok(VmDebugState::Halted(halt))
+                }
                 halt => {
                     return Err(VmError::from(VmErrorKind::Halted {
                         halt: halt.into_info(),
@@ -83,17 +101,19 @@ impl VmExecution {
                 let value = vm.stack_mut().pop()?;
                 debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
                 self.vms.clear();
-                return Ok(GeneratorState::Complete(value));
+                return Ok(VmDebugState::Complete(value));
             }
 
             self.pop_vm()?;
         }
     }
 
-    /// Resume the current execution without support for async instructions.
+    /// Resume the current execution without support for async instructions,
+    /// halting with [VmDebugState::Halted] when a watchpoint or breakpoint is
+    /// hit.
     ///
     /// If any async instructions are encountered, this will error.
-    pub fn resume(&mut self) -> Result<GeneratorState, VmError> {
+    pub fn resume_debug(&mut self) -> Result<VmDebugState, VmError> {
         loop {
             let len = self.vms.len();
             let vm = self.vm_mut()?;
@@ -104,7 +124,10 @@ impl VmExecution {
                     vm_call.into_execution(self)?;
                     continue;
                 }
-                VmHalt::Yielded => return Ok(GeneratorState::Yielded(vm.stack_mut().pop()?)),
+                VmHalt::Yielded => return Ok(VmDebugState::Yielded(vm.stack_mut().pop()?)),
+                halt @ VmHalt::Watchpoint { .. } | halt @ VmHalt::Breakpoint { .. } => {
+                    return Ok(VmDebugState::Halted(halt))
+                }
                 halt => {
                     return Err(VmError::from(VmErrorKind::Halted {
                         halt: halt.into_info(),
@@ -116,7 +139,7 @@ impl VmExecution {
                 let value = vm.stack_mut().pop()?;
                 debug_assert!(vm.stack().is_empty(), "the final vm should be empty");
                 self.vms.clear();
-                return Ok(GeneratorState::Complete(value));
+                return Ok(VmDebugState::Complete(value));
             }
 
             self.pop_vm()?;
@@ -209,9 +232,27 @@ impl VmExecution {
         Ok(())
     }
 
+    /// Convert the state of a debug execution into a generator state, erroring
+    /// if execution was halted for debugging.
+    fn into_generator_state(state: VmDebugState) -> Result<GeneratorState, VmError> {
+        match state {
+            VmDebugState::Halted(halt) => Err(VmError::from(VmErrorKind::Halted {
+                halt: halt.into_info(),
+            })),
+            VmDebugState::Yielded(value) => Ok(GeneratorState::Yielded(value)),
+            VmDebugState::Complete(value) => Ok(GeneratorState::Complete(value)),
+        }
+    }
+
     #[inline]
     fn run(vm: &mut Vm) -> Result<VmHalt, VmError> {
-        match vm.run() {
+        // NB: debug limits set by the script only last as long as it's
+        // executing.
+        let scope = debug_limit::Scope::enter(vm.debug_limits);
+        let result = vm.run();
+        vm.debug_limits = scope.exit();
+
+        match result {
             Ok(reason) => Ok(reason),
             Err(error) => Err(error.into_unwinded(vm.unit(), vm.ip(), vm.call_frames().to_vec())),
         }
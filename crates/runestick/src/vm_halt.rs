@@ -1,4 +1,4 @@
-use crate::{Awaited, VmCall};
+use crate::{Awaited, Span, Value, VmCall};
 use std::fmt;
 
 /// The reason why the virtual machine execution stopped.
@@ -13,7 +13,25 @@ pub enum VmHalt {
     /// The virtual machine awaited on the given future.
     Awaited(Awaited),
     /// Call into a new virtual machine.
-    VmCall(VmCall),
+    VmCall(Box<VmCall>),
+    /// The instruction at the given instruction pointer wrote to a watched
+    /// field. The span of the instruction is available if the unit has debug
+    /// info.
+    Watchpoint {
+        /// The instruction pointer of the writing instruction.
+        ip: usize,
+        /// The span of the writing instruction.
+        span: Option<Span>,
+    },
+    /// Execution reached a breakpoint at the given instruction pointer, which
+    /// hasn't been executed yet. The span of the instruction is available if
+    /// the unit has debug info.
+    Breakpoint {
+        /// The instruction pointer of the breakpoint.
+        ip: usize,
+        /// The span of the breakpoint instruction.
+        span: Option<Span>,
+    },
 }
 
 impl VmHalt {
@@ -25,10 +43,25 @@ impl VmHalt {
             Self::Yielded => VmHaltInfo::Yielded,
             Self::Awaited(..) => VmHaltInfo::Awaited,
             Self::VmCall(..) => VmHaltInfo::VmCall,
+            Self::Watchpoint { ip, span } => VmHaltInfo::Watchpoint { ip, span },
+            Self::Breakpoint { ip, span } => VmHaltInfo::Breakpoint { ip, span },
         }
     }
 }
 
+/// The state of an execution resumed for debugging through
+/// [VmExecution::resume_debug][crate::VmExecution::resume_debug].
+#[derive(Debug)]
+pub enum VmDebugState {
+    /// Execution halted for debugging, like when a watched field was written
+    /// to. Execution can be continued by resuming it again.
+    Halted(VmHalt),
+    /// The execution yielded the given value.
+    Yielded(Value),
+    /// The execution completed with the given value.
+    Complete(Value),
+}
+
 /// The reason why the virtual machine execution stopped.
 #[derive(Debug, Clone, Copy)]
 pub enum VmHaltInfo {
@@ -42,6 +75,21 @@ pub enum VmHaltInfo {
     Awaited,
     /// Received instruction to push the inner virtual machine.
     VmCall,
+    /// The instruction at the given instruction pointer wrote to a watched
+    /// field.
+    Watchpoint {
+        /// The instruction pointer of the writing instruction.
+        ip: usize,
+        /// The span of the writing instruction.
+        span: Option<Span>,
+    },
+    /// Execution reached a breakpoint at the given instruction pointer.
+    Breakpoint {
+        /// The instruction pointer of the breakpoint.
+        ip: usize,
+        /// The span of the breakpoint instruction.
+        span: Option<Span>,
+    },
 }
 
 impl fmt::Display for VmHaltInfo {
@@ -52,6 +100,8 @@ impl fmt::Display for VmHaltInfo {
             Self::Yielded => write!(f, "yielded"),
             Self::Awaited => write!(f, "awaited"),
             Self::VmCall => write!(f, "calling into other vm"),
+            Self::Watchpoint { ip, .. } => write!(f, "watchpoint hit at instruction {}", ip),
+            Self::Breakpoint { ip, .. } => write!(f, "breakpoint hit at instruction {}", ip),
         }
     }
 }
@@ -1,5 +1,5 @@
 use rune::{Diagnostics, Options, Sources};
-use runestick::{Any, Context, Module, Protocol, Source, Vm};
+use runestick::{Any, Context, Module, Protocol, Source, Value, Vm};
 use std::sync::Arc;
 
 #[derive(Debug, Default, Any)]
@@ -44,7 +44,7 @@ fn main() -> runestick::Result<()> {
 
     let mut foo = Foo::default();
 
-    let output = vm.call(&["main"], (&mut foo,))?;
+    let output: Value = vm.call(&["main"], (&mut foo,))?;
     println!("output: {:?}", output);
     println!("output: {:?}", foo);
     Ok(())
@@ -0,0 +1,50 @@
+//! Utilities for counting the allocations performed by the current thread.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// An allocator which counts the number of allocations performed by the
+/// current thread, and the number of bytes they allocated.
+struct CountingAllocator;
+
+thread_local!(static ALLOCATIONS: Cell<usize> = const { Cell::new(0) });
+thread_local!(static ALLOCATED_BYTES: Cell<usize> = const { Cell::new(0) });
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        let _ = ALLOCATED_BYTES.try_with(|c| c.set(c.get() + layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        let _ = ALLOCATED_BYTES.try_with(|c| c.set(c.get() + new_size));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Count the number of allocations performed by the current thread while
+/// running the given closure.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let output = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    (output, after - before)
+}
+
+/// Count the number of bytes allocated by the current thread while running
+/// the given closure.
+pub fn count_allocated_bytes<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATED_BYTES.with(Cell::get);
+    let output = f();
+    let after = ALLOCATED_BYTES.with(Cell::get);
+    (output, after - before)
+}
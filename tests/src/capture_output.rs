@@ -1,6 +1,6 @@
 //! Utilities related to testing
 
-use runestick::{ContextError, Module, Panic, Stack, Value, VmError};
+use runestick::{ContextError, Module, Stack, Value, VmError};
 use std::io::Write as _;
 
 /// Provide a bunch of `std` functions that can be used during tests to capture output.
@@ -24,12 +24,14 @@ pub fn drain_output() -> Option<String> {
     String::from_utf8(o).ok()
 }
 
-fn print_impl(m: &str) -> Result<(), Panic> {
-    write!(OUT.lock(), "{}", m).map_err(Panic::custom)
+fn print_impl(value: Value) -> Result<(), VmError> {
+    let out = value.display_string()?;
+    write!(OUT.lock(), "{}", out).map_err(VmError::panic)
 }
 
-fn println_impl(m: &str) -> Result<(), Panic> {
-    writeln!(OUT.lock(), "{}", m).map_err(Panic::custom)
+fn println_impl(value: Value) -> Result<(), VmError> {
+    let out = value.display_string()?;
+    writeln!(OUT.lock(), "{}", out).map_err(VmError::panic)
 }
 
 fn dbg_impl(stack: &mut Stack, args: usize) -> Result<(), VmError> {
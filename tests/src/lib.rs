@@ -15,6 +15,7 @@ use runestick::{Item, Source, Unit};
 use std::sync::Arc;
 use thiserror::Error;
 
+pub mod allocations;
 pub mod capture_output;
 
 /// Macro internals.
@@ -0,0 +1,30 @@
+use rune::{ast, Spanned};
+use runestick::Span;
+
+#[test]
+fn test_labeled_while_span() {
+    let expr = rune::parse_all::<ast::ExprWhile>("'label: while x {}").unwrap();
+    assert_eq!(expr.span(), Span::new(0, 18));
+
+    let expr = rune::parse_all::<ast::Expr>("'label: while x {}").unwrap();
+    assert_eq!(expr.span(), Span::new(0, 18));
+
+    let block = rune::parse_all::<ast::Block>("{ 'label: while x {} }").unwrap();
+    assert_eq!(block.statements[0].span(), Span::new(2, 20));
+}
+
+#[test]
+fn test_attributed_expr_span() {
+    let expr = rune::parse_all::<ast::Expr>("#[attr] 'label: while x {}").unwrap();
+    assert_eq!(expr.span(), Span::new(0, 26));
+
+    let expr = rune::parse_all::<ast::Expr>("#[attr] 1 + 2").unwrap();
+    assert_eq!(expr.span(), Span::new(0, 13));
+
+    let expr = rune::parse_all::<ast::Expr>("#[attr] [1, 2]").unwrap();
+    assert_eq!(expr.span(), Span::new(0, 14));
+
+    let block = rune::parse_all::<ast::Block>("{ #[attr] loop {} #[attr] let a = 1; }").unwrap();
+    assert_eq!(block.statements[0].span(), Span::new(2, 17));
+    assert_eq!(block.statements[1].span(), Span::new(18, 36));
+}
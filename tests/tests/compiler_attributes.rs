@@ -1,4 +1,5 @@
 use rune_tests::*;
+use runestick::{Hash, Protocol};
 
 #[test]
 fn test_bad_attributes() {
@@ -10,3 +11,123 @@ fn test_bad_attributes() {
         }
     };
 }
+
+#[test]
+fn test_bad_deprecated() {
+    assert_compile_error! {
+        r#"#[deprecated("a", "b")] fn foo() {}"#,
+        span, CompileErrorKind::Custom { message } => {
+            assert_eq!(message, "expected a single deprecation message");
+            assert_eq!(span, Span::new(12, 22));
+        }
+    };
+}
+
+#[test]
+fn test_derive_struct() {
+    assert!(rune! { bool =>
+        #[derive(Debug, PartialEq)]
+        struct Point { x, y }
+
+        pub fn main() {
+            Point { x: 1, y: 2 } == Point { x: 1, y: 2 } && Point { x: 1, y: 2 } != Point { x: 2, y: 1 }
+        }
+    });
+
+    // NB: derived debug formatting uses the order fields are declared in,
+    // unlike the default which orders them by name.
+    let out: String = rune! { String =>
+        #[derive(Debug)]
+        struct Point { y, x }
+
+        #[derive(Debug)]
+        struct Pair(a, b);
+
+        #[derive(Debug)]
+        struct Empty;
+
+        pub fn main() {
+            format!("{:?} {:?} {:?}", Point { y: 1, x: Pair("a", Empty) }, Pair(2, 3), Empty)
+        }
+    };
+
+    assert_eq!(
+        out,
+        r#"Point { y: 1, x: Pair("a", Empty) } Pair(2, 3) Empty"#
+    );
+
+    assert_compile_error! {
+        r#"#[derive(PartialEq, Hash)] struct Point { x, y }"#,
+        span, UnsupportedDerive { name } => {
+            assert_eq!(&*name, "Hash");
+            assert_eq!(span, Span::new(20, 24));
+        }
+    };
+}
+
+#[test]
+fn test_derive_enum() {
+    assert!(rune! { bool =>
+        #[derive(PartialEq)]
+        enum Shape { Circle(r), Rect { w, h }, Empty }
+
+        pub fn main() {
+            Shape::Circle(1) == Shape::Circle(1)
+                && Shape::Circle(1) != Shape::Circle(2)
+                && Shape::Rect { w: 1, h: 2 } == Shape::Rect { w: 1, h: 2 }
+                && Shape::Rect { w: 1, h: 2 } != Shape::Rect { w: 2, h: 1 }
+                && Shape::Empty == Shape::Empty
+                && Shape::Circle(1) != Shape::Empty
+                && Shape::Empty != Shape::Rect { w: 1, h: 2 }
+        }
+    });
+
+    let out: String = rune! { String =>
+        #[derive(Debug)]
+        enum Shape { Circle(r), Rect { w, h }, Empty }
+
+        pub fn main() {
+            format!("{:?} {:?} {:?}", Shape::Circle(1), Shape::Rect { w: 1, h: 2 }, Shape::Empty)
+        }
+    };
+
+    assert_eq!(out, "Circle(1) Rect { w: 1, h: 2 } Empty");
+
+    assert_compile_error! {
+        r#"#[derive(Clone)] enum Shape { Empty }"#,
+        span, UnsupportedDerive { name } => {
+            assert_eq!(&*name, "Clone");
+            assert_eq!(span, Span::new(9, 14));
+        }
+    };
+}
+
+#[test]
+fn test_derive_protocols() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        #[derive(Debug, PartialEq)]
+        struct Point { x, y }
+
+        #[derive(PartialEq)]
+        enum Shape { Empty }
+
+        struct Plain;
+        "#,
+    )
+    .unwrap();
+
+    let has = |ty: &[&str], protocol: Protocol| {
+        let hash = Hash::instance_function(Hash::type_hash(ty), protocol);
+        unit.lookup(hash).is_some()
+    };
+
+    assert!(has(&["Point"], Protocol::STRING_DEBUG));
+    assert!(has(&["Point"], Protocol::EQ));
+    assert!(!has(&["Shape"], Protocol::STRING_DEBUG));
+    assert!(has(&["Shape"], Protocol::EQ));
+    assert!(!has(&["Plain"], Protocol::EQ));
+}
@@ -16,3 +16,17 @@ fn test_fn_const_async() {
         }
     };
 }
+
+#[test]
+fn test_fn_generics_are_ignored() {
+    assert_eq! {
+        rune! { i64 =>
+            fn add<T: Add, U>(a, b) where U: Add + Copy, { a + b }
+
+            pub fn main() {
+                add(1, 2)
+            }
+        },
+        3,
+    };
+}
@@ -39,3 +39,42 @@ fn test_number_literals() {
         }
     };
 }
+
+#[test]
+fn test_char_escapes() {
+    assert_eq!(rune_s!(char => r#"pub fn main() { '\x41' }"#), 'A');
+    assert_eq!(rune_s!(char => r#"pub fn main() { '\x7F' }"#), '\x7F');
+    assert_eq!(rune_s!(char => r#"pub fn main() { '\u{1F600}' }"#), '😀');
+    assert_eq!(
+        rune_s!(String => r#"pub fn main() { "\x41\u{1F600}" }"#),
+        "A😀"
+    );
+
+    assert_compile_error! {
+        r#"pub fn main() { '\x80' }"#,
+        span, CompileErrorKind::ResolveError { error: BadHexEscapeChar } => {
+            assert_eq!(span, Span::new(19, 21));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { '\x4g' }"#,
+        span, CompileErrorKind::ResolveError { error: BadByteEscape } => {
+            assert_eq!(span, Span::new(19, 21));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { "a\u{110000}" }"#,
+        span, CompileErrorKind::ResolveError { error: BadUnicodeEscape } => {
+            assert_eq!(span, Span::new(20, 28));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { '\q' }"#,
+        span, CompileErrorKind::ResolveError { error: BadEscapeSequence } => {
+            assert_eq!(span, Span::new(17, 19));
+        }
+    };
+}
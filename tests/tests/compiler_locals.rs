@@ -0,0 +1,87 @@
+use rune::termcolor::Buffer;
+use rune::{DumpInstructions as _, Sources};
+use rune_tests::*;
+use runestick::Source;
+
+/// Locals are addressed by fixed slots relative to the current call frame,
+/// and shadowing a variable allocates a new slot.
+#[test]
+fn test_local_slots() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let source = r#"
+    pub fn main() {
+        let a = 1;
+        let b = 2;
+        let a = a + b;
+        { let a = 10; b = a; }
+        a + b
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("main", source));
+
+    // The same output as `--dump-unit`, with the instruction pointer and
+    // comments stripped.
+    let mut out = Buffer::no_color();
+    unit.dump_instructions(&mut out, &sources, false).unwrap();
+    let out = String::from_utf8(out.into_inner()).unwrap();
+
+    let instructions = out
+        .lines()
+        .filter_map(|line| line.split(" = ").nth(1))
+        .map(|inst| inst.split(" //").next().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        instructions,
+        vec![
+            "push 1",
+            "push 2",
+            "op +, offset(0), offset(1)",
+            "push 10",
+            "load-local 3",
+            "store-local 1",
+            "pop",
+            "op +, offset(2), offset(1)",
+            "clean 3",
+            "return",
+        ]
+    );
+}
+
+#[test]
+fn test_shadowed_locals() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let a = 1;
+                let b = 2;
+                let a = a + b;
+                { let a = 10; b = a; }
+                a + b
+            }
+        },
+        13,
+    };
+
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                let a = 1;
+                let out = 0;
+
+                for a in 0..4 {
+                    let a = a * 2;
+                    out += a;
+                }
+
+                out + a
+            }
+        },
+        13,
+    };
+}
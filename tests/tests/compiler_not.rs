@@ -0,0 +1,46 @@
+use rune_tests::*;
+use runestick::Inst;
+
+/// The instructions emitted for the body of `main` in the given source.
+fn instructions(source: &str) -> Vec<Inst> {
+    let context = runestick::Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(&context, source).unwrap();
+    unit.iter_instructions().collect()
+}
+
+/// Boolean and integer operands which are known during compilation use
+/// dedicated instructions, everything else dispatches on the type of the
+/// operand at runtime.
+#[test]
+fn test_not_instruction() {
+    let is_not = |inst: &Inst| matches!(inst, Inst::Not);
+    let is_bool_not = |inst: &Inst| matches!(inst, Inst::BoolNot);
+    let is_bit_not = |inst: &Inst| matches!(inst, Inst::BitNot);
+
+    let insts = instructions(r#"pub fn main() { !true }"#);
+    assert!(insts.iter().any(is_bool_not));
+    assert!(!insts.iter().any(is_not));
+    assert!(!insts.iter().any(is_bit_not));
+
+    let insts = instructions(r#"pub fn main() { !(!false) }"#);
+    assert_eq!(insts.iter().filter(|inst| is_bool_not(inst)).count(), 2);
+
+    let insts = instructions(r#"pub fn main() { !5 }"#);
+    assert!(insts.iter().any(is_bit_not));
+    assert!(!insts.iter().any(is_not));
+
+    let insts = instructions(r#"pub fn main() { !(!-5) }"#);
+    assert_eq!(insts.iter().filter(|inst| is_bit_not(inst)).count(), 2);
+
+    let insts = instructions(r#"pub fn main(a) { !a }"#);
+    assert!(insts.iter().any(is_not));
+
+    let insts = instructions(r#"pub fn main() { !-true }"#);
+    assert!(insts.iter().any(is_not));
+
+    assert_eq!(rune!(bool => pub fn main() { !true }), false);
+    assert_eq!(rune!(bool => pub fn main() { !(!false) }), false);
+    assert_eq!(rune!(i64 => pub fn main() { !5 }), !5);
+    assert_eq!(rune!(i64 => pub fn main() { !(!-5) }), -5);
+    assert_eq!(rune!(i64 => pub fn main() { let a = 5; !a }), !5);
+}
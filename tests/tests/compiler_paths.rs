@@ -56,6 +56,94 @@ fn test_super_use() {
     };
 }
 
+#[test]
+fn test_super_super() {
+    assert_eq! {
+        rune! { i64 =>
+            pub mod a {
+                pub mod b {
+                    pub mod c {
+                        pub fn foo() {
+                            use super::super::VALUE as A;
+                            A + super::super::value() + super::super::super::root()
+                        }
+                    }
+                }
+
+                const VALUE = 1;
+
+                pub fn value() { 2 }
+            }
+
+            fn root() { 4 }
+
+            pub fn main() { a::b::c::foo() }
+        },
+        7,
+    };
+}
+
+#[test]
+fn test_super_above_root() {
+    assert_compile_error! {
+        r#"pub mod a { pub fn foo() { super::super::root() } } fn root() { 1 } pub fn main() { a::foo() }"#,
+        span, UnsupportedSuper => {
+            assert_eq!(span, Span::new(34, 39));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub mod a { use super::super::root; } fn root() { 1 } pub fn main() {}"#,
+        span, UnsupportedSuper => {
+            assert_eq!(span, Span::new(23, 28));
+        }
+    };
+}
+
+#[test]
+fn test_self_paths() {
+    assert_eq! {
+        rune! { i64 =>
+            struct Counter { value }
+
+            impl Counter {
+                fn new() { Counter { value: 1 } }
+
+                fn with_value(value) {
+                    let counter = Self::new();
+                    counter.value = value;
+                    counter
+                }
+            }
+
+            pub mod a {
+                use self::b::value;
+
+                fn sibling() { 1 }
+
+                pub fn foo() { self::sibling() + value() }
+
+                mod b {
+                    pub fn value() { 1 }
+                }
+            }
+
+            pub fn main() { Counter::with_value(40).value + a::foo() }
+        },
+        42,
+    };
+}
+
+#[test]
+fn test_self_type_outside_impl() {
+    assert_compile_error! {
+        r#"fn new() { 1 } pub fn main() { Self::new() }"#,
+        span, UnsupportedSelfType => {
+            assert_eq!(span, Span::new(31, 35));
+        }
+    };
+}
+
 #[test]
 fn test_unsupported_leading_path() {
     assert_compile_error! {
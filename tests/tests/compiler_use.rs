@@ -120,3 +120,58 @@ fn test_reexport_fn() {
 
     assert_eq!(result, 8);
 }
+
+#[test]
+fn test_use_alias_fn() {
+    let result = rune! { i64 =>
+        mod a {
+            pub fn add(a, b) { a + b }
+        }
+
+        use self::a::add as plus;
+
+        pub fn main() {
+            plus(1, 2)
+        }
+    };
+
+    assert_eq!(result, 3);
+}
+
+#[test]
+fn test_use_alias_conflict() {
+    assert_compile_error! {
+        r#"
+        mod a { pub fn add(a, b) { a + b } }
+        use self::a::add as plus;
+        fn plus() {}
+        pub fn main() { plus() }
+        "#,
+        span, QueryError { error: AmbiguousItem { locations, .. } } => {
+            assert_eq!(span, Span::new(125, 129));
+            assert_eq!(locations.len(), 2);
+        }
+    };
+}
+
+#[test]
+fn test_use_group() {
+    let result = rune! { i64 =>
+        mod a {
+            pub fn one() { 1 }
+            pub fn two() { 2 }
+
+            pub mod b {
+                pub fn four() { 4 }
+            }
+        }
+
+        use self::a::{one, two, b::four,};
+
+        pub fn main() {
+            one() + two() + four()
+        }
+    };
+
+    assert_eq!(result, 7);
+}
@@ -1,4 +1,6 @@
+use rune::{Diagnostics, Options, Sources, Spanned as _};
 use rune_tests::*;
+use runestick::{Context, Item, Source};
 
 #[test]
 fn test_let_pattern_might_panic() {
@@ -29,3 +31,137 @@ fn test_remove_variant_parens() {
         }
     };
 }
+
+#[test]
+fn test_deprecated() {
+    assert_warnings! {
+        r#"
+        #[deprecated("use `bar` instead")]
+        fn foo() { 1 }
+
+        #[deprecated("use a tuple instead")]
+        struct Point { x, y }
+
+        pub fn main() { foo() + foo() + Point { x: 1, y: 2 }.x }
+        "#,
+        Deprecated { item, message, span } => {
+            assert_eq!(item, Item::with_item(&["foo"]));
+            assert_eq!(&*message, "use `bar` instead");
+            assert_eq!(span, Span::new(168, 171));
+        },
+        Deprecated { item, span, .. } => {
+            assert_eq!(item, Item::with_item(&["foo"]));
+            assert_eq!(span, Span::new(176, 179));
+        },
+        Deprecated { item, message, span } => {
+            assert_eq!(item, Item::with_item(&["Point"]));
+            assert_eq!(&*message, "use a tuple instead");
+            assert_eq!(span, Span::new(184, 189));
+        }
+    };
+}
+
+#[test]
+fn test_shadowed_const() {
+    assert_warnings! {
+        r#"const X = 1; pub fn main() { let X = 2; X }"#,
+        rune::WarningKind::ShadowedConst { item, span } => {
+            assert_eq!(item, Item::with_item(&["X"]));
+            assert_eq!(span, Span::new(33, 34));
+        }
+    };
+}
+
+#[test]
+fn test_constant_condition() {
+    assert_warnings! {
+        r#"pub fn main() { if 1 > 2 { 1 } else { 2 } }"#,
+        ConstantCondition { span, value, .. } => {
+            assert_eq!(span, Span::new(19, 24));
+            assert!(!value);
+        }
+    };
+
+    assert_warnings! {
+        r#"pub fn main(x) { while "a" == "a" { break; } if x < x { 1 } else { 2 } }"#,
+        ConstantCondition { span, value, .. } => {
+            assert_eq!(span, Span::new(23, 33));
+            assert!(value);
+        },
+        ConstantCondition { span, value, .. } => {
+            assert_eq!(span, Span::new(48, 53));
+            assert!(!value);
+        }
+    };
+}
+
+#[test]
+fn test_shadowed_const_error() {
+    let context = Context::with_default_modules().unwrap();
+
+    let mut options = Options::default();
+    options.parse_option("shadowed-const-error").unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "main",
+        r#"const X = 1; pub fn main() { let X = 2; X }"#,
+    ));
+
+    let mut diagnostics = Diagnostics::new();
+    let result = rune::load_sources(&context, &options, &mut sources, &mut diagnostics);
+    assert!(result.is_err());
+
+    let error = match diagnostics.into_diagnostics().into_iter().next() {
+        Some(rune::Diagnostic::Error(error)) => error,
+        diagnostic => panic!("expected error but was {:?}", diagnostic),
+    };
+
+    match error.into_kind() {
+        rune::ErrorKind::CompileError(error) => {
+            assert_eq!(error.span(), Span::new(33, 34));
+
+            match error.into_kind() {
+                CompileErrorKind::ShadowedConstError { item } => {
+                    assert_eq!(item, Item::with_item(&["X"]))
+                }
+                kind => panic!("unexpected error {:?}", kind),
+            }
+        }
+        kind => panic!("unexpected error {:?}", kind),
+    }
+}
+
+#[test]
+fn test_warnings_as_errors() {
+    let context = Context::with_default_modules().unwrap();
+
+    let mut options = Options::default();
+    options.warnings_as_errors(true);
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("main", r#"pub fn main() { `Hello World` }"#));
+
+    let mut diagnostics = Diagnostics::new();
+    let result = rune::load_sources(&context, &options, &mut sources, &mut diagnostics);
+    assert!(result.is_err());
+    assert!(diagnostics.has_error());
+    assert!(!diagnostics.has_warning());
+
+    let mut it = diagnostics.into_diagnostics().into_iter();
+
+    let error = match it.next() {
+        Some(rune::Diagnostic::Error(error)) => error,
+        diagnostic => panic!("expected error but was {:?}", diagnostic),
+    };
+
+    match error.into_kind() {
+        rune::ErrorKind::Warning(warning) => match warning.into_kind() {
+            TemplateWithoutExpansions { span, .. } => assert_eq!(span, Span::new(16, 29)),
+            kind => panic!("unexpected warning {:?}", kind),
+        },
+        kind => panic!("unexpected error {:?}", kind),
+    }
+
+    assert!(it.next().is_none());
+}
@@ -105,4 +105,33 @@ fn test_number_formatting() {
     test_case!("{:/<13b}", 42);
     test_case!("{:/^13b}", 42);
     test_case!("{:/>13b}", 42);
+
+    // Every argument is formatted on its own.
+    test_case!("{} {}", 2, 3);
+    test_case!("{:?} {:x} {:b}", 2, 10, 3);
+}
+
+#[test]
+fn test_matches() {
+    let out: (bool, bool, bool, bool) = rune! { (bool, bool, bool, bool) =>
+        pub fn main() {
+            let opt = Some(2);
+            let n = 0;
+
+            if matches!(opt, Some(_)) {
+                n += 1;
+            }
+
+            let a = n == 1;
+
+            (
+                a,
+                matches!(opt, Some(n) if n > 1),
+                matches!(None, Some(_)),
+                matches!([1, 2, 3], [1, ..],),
+            )
+        }
+    };
+
+    assert_eq!(out, (true, true, false, true));
 }
@@ -0,0 +1,89 @@
+//! Tests for deriving `Any` on enums, tuple structs and unit structs, which
+//! are exposed as opaque types.
+
+use rune_tests::*;
+use runestick::{Any, ContextError, Module};
+
+#[derive(Any)]
+enum Shape {
+    Circle(f64),
+    Square {
+        #[rune(copy)]
+        side: f64,
+    },
+}
+
+impl Shape {
+    fn area(&self) -> f64 {
+        match self {
+            Shape::Circle(r) => std::f64::consts::PI * r * r,
+            Shape::Square { side } => side * side,
+        }
+    }
+}
+
+#[derive(Any)]
+struct Meters(#[rune(copy)] f64);
+
+impl Meters {
+    fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+#[derive(Any)]
+struct Marker;
+
+fn make_module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate("shapes");
+    module.ty::<Shape>()?;
+    module.ty::<Meters>()?;
+    module.ty::<Marker>()?;
+    module.function(&["square"], |side: f64| Shape::Square { side })?;
+    module.function(&["circle"], Shape::Circle)?;
+    module.inst_fn("area", Shape::area)?;
+    module.inst_fn("value", Meters::value)?;
+    Ok(module)
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(
+        rune_n! {
+            make_module().unwrap(),
+            (),
+            f64 => pub fn main() { shapes::square(3.0).area() }
+        },
+        9.0
+    );
+
+    assert_eq!(
+        rune_n! {
+            make_module().unwrap(),
+            (Shape::Circle(1.0),),
+            String => pub fn main(shape) { std::any::type_name_of_val(shape) }
+        },
+        "::shapes::Shape"
+    );
+}
+
+#[test]
+fn test_tuple_and_unit_struct() {
+    assert_eq!(
+        rune_n! {
+            make_module().unwrap(),
+            (Meters(4.5),),
+            f64 => pub fn main(m) { m.value() }
+        },
+        4.5
+    );
+
+    assert_eq!(
+        rune_n! {
+            make_module().unwrap(),
+            (Marker,),
+            String => pub fn main(m) { std::any::type_name_of_val(m) }
+        },
+        "::shapes::Marker"
+    );
+}
@@ -79,7 +79,7 @@ fn test_external_ops() {
                 foo.derived = $initial;
                 foo.custom = $initial;
 
-                let output = vm.clone().call(&["type"], (&mut foo,)).unwrap();
+                let output: Value = vm.clone().call(&["type"], (&mut foo,)).unwrap();
 
                 assert_eq!(foo.value, $expected, "{} != {} (value)", foo.value, $expected);
                 assert_eq!(foo.field, $expected, "{} != {} (field)", foo.value, $expected);
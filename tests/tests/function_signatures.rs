@@ -0,0 +1,122 @@
+use rune_tests::*;
+use runestick::debug::DebugArgs;
+use runestick::{ContextSignature, Hash, Item, Module};
+
+fn add(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+#[test]
+fn test_function_signatures() {
+    let mut module = Module::new();
+    module.function(&["add"], add).unwrap();
+    module.function_arg_names(&["add"], &["a", "b"]).unwrap();
+
+    assert!(module.function_arg_names(&["missing"], &["a"]).is_err());
+    assert!(module.function_arg_names(&["add"], &["a"]).is_err());
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    match context.lookup_signature(Hash::type_hash(&["add"])) {
+        Some(ContextSignature::Function {
+            args, arg_names, ..
+        }) => {
+            assert_eq!(*args, Some(2));
+            assert_eq!(arg_names.as_deref(), Some(&["a".into(), "b".into()][..]));
+        }
+        signature => panic!("unexpected signature: {:?}", signature),
+    }
+
+    let vm = vm_with_source(
+        &context,
+        r#"
+        pub fn main(first, second) {
+            let f = |value| value + first;
+            f(second)
+        }
+        "#,
+    )
+    .unwrap();
+
+    let unit = vm.unit();
+
+    let main = unit.lookup_signature(Hash::type_hash(&["main"])).unwrap();
+    assert!(matches!(main.args, DebugArgs::Named(..)));
+    assert_eq!(
+        main.args.names(),
+        Some(&["first".into(), "second".into()][..])
+    );
+
+    let closure = unit
+        .debug_info()
+        .unwrap()
+        .functions
+        .values()
+        .find(|s| matches!(s.args, DebugArgs::Closure(..)))
+        .unwrap();
+
+    assert!(closure.path.starts_with(&Item::with_item(&["main"])));
+    assert_eq!(closure.args.names(), Some(&["value".into()][..]));
+}
+
+#[test]
+fn test_variadic_function_signature() {
+    let mut module = Module::new();
+    module.raw_fn(&["none"], |_, _| Ok(())).unwrap();
+    module.raw_fn(&["some"], |_, _| Ok(())).unwrap();
+    module.function_arg_names(&["none"], &[]).unwrap();
+    module.function_arg_names(&["some"], &["first"]).unwrap();
+
+    let mut context = runestick::Context::new();
+    context.install(&module).unwrap();
+
+    let signature = |name| {
+        context
+            .lookup_signature(Hash::type_hash(&[name]))
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(signature("none"), "none(...)");
+    assert_eq!(signature("some"), "some(first, ...)");
+}
+
+#[test]
+fn test_iter_function_info() {
+    let mut module = Module::new();
+    module.function(&["add"], add).unwrap();
+    module.async_function(&["wait"], || async {}).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let info = |item: Item| {
+        context
+            .iter_function_info()
+            .find(|(i, ..)| *i == item)
+            .map(|(_, hash, args, is_async)| (hash, args, is_async))
+    };
+
+    assert_eq!(
+        info(Item::with_crate_item("std", &["io", "dbg"])),
+        Some((
+            Hash::type_hash(&Item::with_crate_item("std", &["io", "dbg"])),
+            None,
+            false
+        ))
+    );
+    assert_eq!(
+        info(Item::with_item(&["add"])),
+        Some((Hash::type_hash(&["add"]), Some(2), false))
+    );
+    assert_eq!(
+        info(Item::with_item(&["wait"])),
+        Some((Hash::type_hash(&["wait"]), Some(0), true))
+    );
+    assert!(matches!(
+        info(Item::with_crate_item("std", &["string", "String", "len"])),
+        Some((_, Some(1), false))
+    ));
+    assert_eq!(info(Item::with_item(&["missing"])), None);
+}
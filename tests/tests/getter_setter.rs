@@ -1,5 +1,5 @@
 use rune::{Diagnostics, Options, Sources};
-use runestick::{Any, Context, Module, Source, Value, Vm};
+use runestick::{Any, Context, Module, Source, Value, Vm, VmErrorKind};
 use std::sync::Arc;
 
 #[derive(Any, Debug, Default)]
@@ -10,6 +10,12 @@ struct Foo {
     string: String,
 }
 
+#[derive(Any, Debug, Default)]
+struct Response {
+    #[rune(get, set, copy, name = "status")]
+    http_status: i64,
+}
+
 #[test]
 fn test_getter_setter() {
     let mut module = Module::new();
@@ -46,10 +52,59 @@ fn test_getter_setter() {
         string: String::from("Hello"),
     };
 
-    let output = vm.call(&["main"], (&mut foo,)).unwrap();
+    let output: Value = vm.call(&["main"], (&mut foo,)).unwrap();
 
     assert_eq!(foo.number, 43);
     assert_eq!(foo.string, "Hello World");
 
     assert!(matches!(output, Value::Unit));
 }
+
+#[test]
+fn test_renamed_getter_setter() {
+    let mut module = Module::new();
+    module.ty::<Response>().unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "test",
+        r#"
+        pub fn main(response) {
+            response.status = response.status + 4;
+        }
+
+        pub fn original(response) {
+            response.http_status
+        }
+        "#,
+    ));
+
+    let mut diagnostics = Diagnostics::new();
+
+    let unit = rune::load_sources(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &mut diagnostics,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let mut response = Response { http_status: 200 };
+
+    let _: () = vm.clone().call(&["main"], (&mut response,)).unwrap();
+    assert_eq!(response.http_status, 204);
+
+    let error = vm
+        .call::<Value>(&["original"], (&mut response,))
+        .unwrap_err();
+
+    assert!(matches!(
+        error.into_unwound().0.into_kind(),
+        VmErrorKind::UnsupportedObjectSlotIndexGet { .. }
+    ));
+}
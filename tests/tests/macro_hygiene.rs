@@ -0,0 +1,51 @@
+use rune::{quote, Parser, TokenStream};
+use rune_tests::*;
+use runestick::Module;
+
+/// Expands `add_tmp!(expr)` into `{ let tmp = 100; expr + tmp }`.
+fn add_tmp(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream);
+    let expr = p.parse_all::<rune::ast::Expr>()?;
+
+    Ok(quote!({
+        let tmp = 100;
+        #expr + tmp
+    })
+    .into_token_stream())
+}
+
+fn module() -> Module {
+    let mut module = Module::new();
+    module.macro_(&["add_tmp"], add_tmp).unwrap();
+    module
+}
+
+#[test]
+fn test_macro_variables_are_hygienic() {
+    let out: (i64, i64) = rune_n! {
+        module(),
+        (),
+        (i64, i64) => pub fn main() {
+            let tmp = 1;
+            let out = add_tmp!(tmp);
+            (tmp, out)
+        }
+    };
+
+    assert_eq!(out, (1, 101));
+}
+
+#[test]
+fn test_macro_variables_in_closures_are_hygienic() {
+    let out: i64 = rune_n! {
+        module(),
+        (),
+        i64 => pub fn main() {
+            let tmp = 2;
+            let f = || add_tmp!(tmp * 10);
+            f()
+        }
+    };
+
+    assert_eq!(out, 120);
+}
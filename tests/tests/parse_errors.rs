@@ -0,0 +1,40 @@
+use rune_tests::*;
+
+#[test]
+fn test_expected_one_of() {
+    assert_parse_error! {
+        r#"pub fn main() { 1 + }"#,
+        span, ExpectedOneOf { actual, expected } => {
+            assert_eq!(span, Span::new(20, 21));
+            assert_eq!(actual, "}");
+            assert!(expected.len() > 1);
+            assert!(expected.contains(&"path"));
+            assert!(expected.contains(&"literal"));
+            assert!(expected.contains(&"("));
+
+            let mut sorted = expected.to_vec();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(&sorted[..], &expected[..]);
+        }
+    };
+
+    let error = rune::parse_all::<rune::ast::Expr>("1 + ;").unwrap_err();
+    let message = error.to_string();
+    assert!(message.starts_with("expected one of: [!, #, &, (, *, -, .., ..=, "));
+    assert!(message.ends_with("], but got `;`"));
+}
+
+#[test]
+fn test_expected_one_of_after_modifier() {
+    assert_parse_error! {
+        r#"pub fn main() { async 1 }"#,
+        span, ExpectedOneOf { expected, .. } => {
+            assert_eq!(span, Span::new(22, 23));
+            assert!(expected.contains(&"{"));
+            assert!(expected.contains(&"move"));
+            assert!(!expected.contains(&"async"));
+            assert!(!expected.contains(&"path"));
+        }
+    };
+}
@@ -1,5 +1,5 @@
 use rune::{Diagnostics, Options, Sources};
-use runestick::{Any, AnyObj, Context, Module, Shared, Source, Vm, VmError};
+use runestick::{Any, AnyObj, Context, Module, Shared, Source, Value, Vm, VmError};
 use std::sync::Arc;
 
 #[test]
@@ -44,5 +44,5 @@ fn test_reference_error() {
 
     // This should error, because we're trying to acquire an `Ref` out of a
     // passed in reference.
-    assert!(vm.call(&["main"], (&mut foo,)).is_err());
+    assert!(vm.call::<Value>(&["main"], (&mut foo,)).is_err());
 }
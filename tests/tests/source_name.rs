@@ -0,0 +1,43 @@
+use rune::termcolor::Buffer;
+use rune::{Diagnostics, EmitDiagnostics as _, Options, Sources};
+use rune_tests::*;
+use runestick::{Context, Source};
+
+#[test]
+fn test_pathless_source_mod() {
+    assert_compile_error! {
+        r#"mod foo;"#,
+        span, UnsupportedModuleSource { name } => {
+            assert_eq!(span, Span::new(0, 8));
+            assert_eq!(name, "main");
+        }
+    };
+}
+
+#[test]
+fn test_named_source_diagnostics() {
+    let context = Context::with_default_modules().unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("my-script", r#"mod foo; pub fn main() {}"#));
+
+    let mut diagnostics = Diagnostics::new();
+    let result = rune::load_sources(
+        &context,
+        &Options::default(),
+        &mut sources,
+        &mut diagnostics,
+    );
+    assert!(result.is_err());
+
+    let mut out = Buffer::no_color();
+    diagnostics.emit_diagnostics(&mut out, &sources).unwrap();
+    let out = String::from_utf8(out.into_inner()).unwrap();
+
+    assert!(
+        out.contains("cannot load modules from `my-script`"),
+        "{}",
+        out
+    );
+    assert!(out.contains("my-script:1:1"), "{}", out);
+}
@@ -107,6 +107,8 @@ fn test_tokens() {
         assert_quote!([SlashEq], quote!(/=));
         assert_quote!([Star], quote!(*));
         assert_quote!([StarEq], quote!(*=));
+        assert_quote!([StarStar], quote!(**));
+        assert_quote!([StarStarEq], quote!(**=));
         assert_quote!([Static], quote!(static));
         assert_quote!([Struct], quote!(struct));
         assert_quote!([Super], quote!(super));
@@ -89,3 +89,44 @@ fn test_range_into_iter() {
         }
     }
 }
+
+#[test]
+fn test_range_sum() {
+    let out = rune! { i64 =>
+        pub fn main() {
+            let sum = 0;
+
+            for n in 0..=5 {
+                sum += n;
+            }
+
+            sum
+        }
+    };
+
+    assert_eq!(out, 15);
+}
+
+#[test]
+fn test_range_without_start_iteration() {
+    assert_compile_error! {
+        r#"pub fn main() { for n in ..10 {} }"#,
+        span, UnsupportedRangeIteration => {
+            assert_eq!(span, Span::new(25, 29));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { for n in ..=10 {} }"#,
+        span, UnsupportedRangeIteration => {
+            assert_eq!(span, Span::new(25, 30));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { for n in ((..10)) {} }"#,
+        span, UnsupportedRangeIteration => {
+            assert_eq!(span, Span::new(27, 31));
+        }
+    };
+}
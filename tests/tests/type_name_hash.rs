@@ -0,0 +1,16 @@
+use runestick::{Hash, Item};
+
+#[test]
+fn test_of_type_name() {
+    let item = Item::with_crate_item("std", &["option", "Option"]);
+
+    // NB: the hash must stay the same across runs, since it's stored in
+    // compiled units.
+    assert_eq!(Hash::of_type_name(&item).to_string(), "0x87c6bc319365962f");
+    assert_eq!(Hash::of_type_name(&item), Hash::of_type_name(&item.clone()));
+    assert_eq!(Hash::of_type_name(&item), Hash::type_hash(&item));
+    assert_ne!(
+        Hash::of_type_name(&item),
+        Hash::of_type_name(&Item::with_item(&["std", "option", "Option"]))
+    );
+}
@@ -89,3 +89,21 @@ fn test_enum() {
         vec!["E".to_owned(), "E".to_owned(), "E".to_owned()]
     )
 }
+
+#[test]
+fn test_type_id_of_val() {
+    assert_eq!(
+        rune_s! { (bool, bool) => r#"
+            use std::any::TypeId;
+            struct Foo;
+
+            pub fn main() {
+                let a = `${TypeId::of_val(1)}`;
+                let b = `${TypeId::of_val(2)}`;
+                let c = `${TypeId::of_val(Foo)}`;
+                (a == b, a == c)
+            }
+        "#},
+        (true, false)
+    )
+}
@@ -0,0 +1,48 @@
+use rune::{Diagnostics, Options, Sources};
+use runestick::{Context, Source, Span};
+
+fn compile(source: &str, options: &Options) -> runestick::Unit {
+    let context = Context::with_default_modules().unwrap();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("main", source));
+
+    let mut diagnostics = Diagnostics::new();
+    rune::load_sources(&context, options, &mut sources, &mut diagnostics).unwrap()
+}
+
+#[test]
+fn test_embed_sources() {
+    let source = r#"pub fn main(a) { a + 42 }"#;
+
+    let mut options = Options::default();
+    options.embed_sources(true);
+    let unit = compile(source, &options);
+
+    let debug = unit.debug_info().unwrap();
+
+    let (ip, inst) = debug
+        .instructions
+        .iter()
+        .enumerate()
+        .find(|(_, inst)| !inst.span.is_empty() && source[inst.span.range()] == *"42")
+        .unwrap();
+
+    assert_eq!(
+        unit.source_snippet(inst.source_id, inst.span),
+        Some("42"),
+        "snippet for instruction {}",
+        ip
+    );
+
+    let start = source.find("a + 42").unwrap();
+    assert_eq!(
+        unit.source_snippet(0, Span::new(start, start + 6)),
+        Some("a + 42")
+    );
+    assert_eq!(unit.source_snippet(1, Span::new(0, 1)), None);
+    assert_eq!(unit.source_snippet(0, Span::new(0, 1000)), None);
+
+    let unit = compile(source, &Options::default());
+    assert_eq!(unit.source_snippet(0, Span::new(0, 6)), None);
+}
@@ -0,0 +1,96 @@
+use rune_tests::*;
+use runestick::{Hash, Inst, PanicReason, Unit, UnitFn, Vm};
+use std::sync::Arc;
+
+/// Build a copy of `unit` where every instruction is moved `by` positions
+/// further into the instruction stream.
+fn relocate(unit: &Unit, by: usize) -> Unit {
+    let mut instructions = vec![
+        Inst::Panic {
+            reason: PanicReason::NotImplemented,
+        };
+        by
+    ];
+
+    instructions.extend(unit.iter_instructions());
+
+    let functions = unit
+        .iter_functions()
+        .map(|(hash, f)| {
+            let f = match *f {
+                UnitFn::Offset { offset, call, args } => UnitFn::Offset {
+                    offset: offset + by,
+                    call,
+                    args,
+                },
+                f => f,
+            };
+
+            (hash, f)
+        })
+        .collect();
+
+    Unit::new(
+        instructions,
+        functions,
+        unit.iter_static_strings().cloned().collect(),
+        Vec::new(),
+        Vec::new(),
+        Default::default(),
+        Default::default(),
+        None,
+        Default::default(),
+    )
+}
+
+#[test]
+fn test_relocated_unit() {
+    let context = runestick::Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn sum(n) {
+            let out = 0;
+
+            while n > 0 {
+                out += n;
+                n -= 1;
+            }
+
+            out
+        }
+
+        pub fn main(n) {
+            let out = 0;
+
+            for i in 0..n {
+                if i % 2 == 0 {
+                    continue;
+                }
+
+                out += sum(i);
+            }
+
+            match out { 0 => -1, n => n }
+        }
+        "#,
+    )
+    .unwrap();
+
+    let runtime = Arc::new(context.runtime());
+
+    let unit = Arc::new(unit);
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let expected = vm.execute(&["main"], (10,)).unwrap().complete().unwrap();
+    let expected = i64::from_value(expected).unwrap();
+    assert_eq!(expected, 95);
+
+    let relocated = relocate(&unit, 17);
+    assert!(relocated.lookup(Hash::type_hash(&["main"])).is_some());
+
+    let vm = Vm::new(runtime, Arc::new(relocated));
+    let output = vm.execute(&["main"], (10,)).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), expected);
+}
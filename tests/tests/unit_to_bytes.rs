@@ -0,0 +1,53 @@
+use rune_tests::*;
+use runestick::{Context, Unit, UnitFormatError, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_unit_to_bytes() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        const STATUS = ("done", [1, 2.5]);
+
+        struct Point { x, y }
+
+        fn add(a, b) {
+            Point { x: a.x + b.x, y: a.y + b.y }
+        }
+
+        pub fn main(n) {
+            let p = add(Point { x: n, y: 2 }, Point { x: 3, y: 4 });
+            let (status, _) = STATUS;
+            (p.x, p.y, status)
+        }
+        "#,
+    )
+    .unwrap();
+
+    let bytes = unit.to_bytes().unwrap();
+    drop(unit);
+
+    let unit = Unit::from_bytes(&bytes).unwrap();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let output = <(i64, i64, String)>::from_value(vm.call(&["main"], (1i64,)).unwrap()).unwrap();
+    assert_eq!(output, (4, 6, String::from("done")));
+}
+
+#[test]
+fn test_unit_from_bytes_version_mismatch() {
+    let mut bytes = Unit::default().to_bytes().unwrap();
+    bytes[0] = bytes[0].wrapping_add(1);
+
+    assert!(matches!(
+        Unit::from_bytes(&bytes),
+        Err(UnitFormatError::UnsupportedVersion { expected: 1, .. })
+    ));
+
+    assert!(matches!(
+        Unit::from_bytes(&[]),
+        Err(UnitFormatError::Bincode { .. })
+    ));
+}
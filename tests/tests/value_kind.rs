@@ -0,0 +1,36 @@
+use rune_tests::*;
+use runestick::{Value, ValueKind};
+
+#[test]
+fn test_value_kind() {
+    let values: Vec<Value> = rune! { Vec<Value> =>
+        pub fn main() {
+            [(), true, b'a', 'a', 1, 1.0, "hello", #{}, [], (1, 2), Some(1), Ok(1), || 1]
+        }
+    };
+
+    let kinds = values.iter().map(Value::kind).collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        [
+            ValueKind::Unit,
+            ValueKind::Bool,
+            ValueKind::Byte,
+            ValueKind::Char,
+            ValueKind::Integer,
+            ValueKind::Float,
+            ValueKind::StaticString,
+            ValueKind::Object,
+            ValueKind::Vec,
+            ValueKind::Tuple,
+            ValueKind::Option,
+            ValueKind::Result,
+            ValueKind::Function,
+        ]
+    );
+
+    let value = Value::from(String::from("hello"));
+    let _guard = value.clone().into_string().unwrap().into_mut().unwrap();
+    assert_eq!(value.kind(), ValueKind::String);
+}
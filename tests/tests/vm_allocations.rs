@@ -0,0 +1,281 @@
+//! Tests which make sure that the virtual machine doesn't allocate where it
+//! shouldn't.
+
+use rune_tests::allocations::{count_allocated_bytes, count_allocations};
+use rune_tests::*;
+
+#[test]
+fn test_numeric_loop_does_not_allocate() {
+    let vm = rune_vm! {
+        pub fn main(n) {
+            let sum = 0;
+            let i = 0;
+
+            while i < n {
+                let c = 'a';
+                let b = i % 2 == 0;
+
+                if b && c == 'a' {
+                    sum += i * 2;
+                } else {
+                    sum -= 1;
+                }
+
+                i += 1;
+            }
+
+            sum
+        }
+    };
+
+    let run = |n: i64| {
+        count_allocations(|| {
+            let mut execution = vm.clone().execute(&["main"], (n,)).unwrap();
+            i64::from_value(execution.complete().unwrap()).unwrap()
+        })
+    };
+
+    let (a, small) = run(10);
+    let (b, large) = run(10_000);
+
+    assert_eq!(a, 35);
+    assert_eq!(b, 49_985_000);
+    // NB: the only allocations are the ones needed to set up the virtual
+    // machine, so they shouldn't scale with the number of iterations.
+    assert_eq!(small, large);
+}
+
+#[test]
+fn test_passing_vec_does_not_copy() {
+    let vm = rune_vm! {
+        fn sum(v) {
+            let s = 0;
+            let i = 0;
+
+            while i < v.len() {
+                s += v[i];
+                i += 1;
+            }
+
+            s
+        }
+
+        pub fn main(v) {
+            sum(v) + sum(v)
+        }
+    };
+
+    let run = |n: i64| {
+        let v = (0..n).collect::<Vec<i64>>().to_value().unwrap();
+
+        count_allocations(|| {
+            let mut execution = vm.clone().execute(&["main"], (v,)).unwrap();
+            i64::from_value(execution.complete().unwrap()).unwrap()
+        })
+    };
+
+    let (a, small) = run(10);
+    let (b, large) = run(10_000);
+
+    assert_eq!(a, 90);
+    assert_eq!(b, 99_990_000);
+    // NB: vectors are passed by reference, so the size of the vector shouldn't
+    // affect the number of allocations.
+    assert_eq!(small, large);
+}
+
+#[test]
+fn test_vec_mutation_is_shared() {
+    // NB: mutation happens through the shared reference. To get a separate copy
+    // the vector has to be explicitly cloned.
+    assert_eq! {
+        rune! { (usize, usize) =>
+            fn push(v) {
+                v.push(1);
+            }
+
+            pub fn main() {
+                let v = [];
+                push(v);
+                let c = v.clone();
+                push(c);
+                (v.len(), c.len())
+            }
+        },
+        (1, 2),
+    };
+}
+
+#[test]
+fn test_vec_clone_is_independent() {
+    // NB: mutating a clone must never be visible through the original.
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3];
+                let c = v.clone();
+                c[0] = 10;
+                c.push(4);
+                (v, c)
+            }
+        },
+        (vec![1, 2, 3], vec![10, 2, 3, 4]),
+    };
+}
+
+#[test]
+fn test_reading_cloned_vec_does_not_copy() {
+    let vm = rune_vm! {
+        fn sum(v) {
+            let s = 0;
+            let i = 0;
+
+            while i < v.len() {
+                s += v[i];
+                i += 1;
+            }
+
+            s
+        }
+
+        pub fn main(v) {
+            let c = v.clone();
+            sum(c) + sum(v)
+        }
+    };
+
+    let run = |n: i64| {
+        let v = (0..n).collect::<Vec<i64>>().to_value().unwrap();
+
+        count_allocated_bytes(|| {
+            let mut execution = vm.clone().execute(&["main"], (v,)).unwrap();
+            i64::from_value(execution.complete().unwrap()).unwrap()
+        })
+    };
+
+    let (a, small) = run(10);
+    let (b, large) = run(10_000);
+
+    assert_eq!(a, 90);
+    assert_eq!(b, 99_990_000);
+    // NB: the clone shares the elements of the vector, so the size of the
+    // vector shouldn't affect the number of allocated bytes.
+    assert_eq!(small, large);
+}
+
+#[test]
+fn test_mutating_cloned_vec_copies() {
+    let vm = rune_vm! {
+        pub fn main(v) {
+            let c = v.clone();
+            c.push(0);
+            (v.len(), c.len())
+        }
+    };
+
+    let run = |n: i64| {
+        let v = (0..n).collect::<Vec<i64>>().to_value().unwrap();
+
+        count_allocated_bytes(|| {
+            let mut execution = vm.clone().execute(&["main"], (v,)).unwrap();
+            <(usize, usize)>::from_value(execution.complete().unwrap()).unwrap()
+        })
+    };
+
+    let (a, small) = run(10);
+    let (b, large) = run(10_000);
+
+    assert_eq!(a, (10, 11));
+    assert_eq!(b, (10_000, 10_001));
+    // NB: the clone copies the elements when it's mutated.
+    assert!(large - small >= 9_990 * std::mem::size_of::<Value>());
+}
+
+#[test]
+fn test_vec_mutation_during_iteration() {
+    // NB: the iterator shares the elements of the vector, which are copied
+    // when the vector is mutated, so the iterator doesn't see the mutation.
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [1, 2, 3];
+                let out = [];
+
+                for n in v {
+                    v.push(n);
+                    out.push(n);
+                }
+
+                (v, out)
+            }
+        },
+        (vec![1, 2, 3, 1, 2, 3], vec![1, 2, 3]),
+    };
+}
+
+#[test]
+fn test_string_building_is_amortized() {
+    let vm = rune_vm! {
+        pub fn with_capacity(n) {
+            let s = String::with_capacity(n);
+            let i = 0;
+
+            while i < n {
+                s.push('a');
+                i += 1;
+            }
+
+            s
+        }
+
+        pub fn push_str(n) {
+            let s = String::new();
+            let i = 0;
+
+            while i < n {
+                s += "a";
+                i += 1;
+            }
+
+            s
+        }
+
+        pub fn naive(n) {
+            let s = "";
+            let i = 0;
+
+            while i < n {
+                s = s + "a";
+                i += 1;
+            }
+
+            s
+        }
+    };
+
+    let run = |name: &str, n: i64| {
+        count_allocations(|| {
+            let mut execution = vm.clone().execute(&[name], (n,)).unwrap();
+            String::from_value(execution.complete().unwrap()).unwrap()
+        })
+    };
+
+    let (expected, naive) = run("naive", 10_000);
+    assert_eq!(expected, "a".repeat(10_000));
+    // NB: every concatenation produces a new string.
+    assert!(naive >= 10_000, "{} allocations", naive);
+
+    let (small_string, small) = run("with_capacity", 10);
+    let (string, large) = run("with_capacity", 10_000);
+    assert_eq!(small_string, "a".repeat(10));
+    assert_eq!(string, expected);
+    // NB: the string is allocated up front, so the number of allocations
+    // shouldn't scale with its length.
+    assert_eq!(small, large);
+
+    let (string, pushed) = run("push_str", 10_000);
+    assert_eq!(string, expected);
+    // NB: `+=` appends in place, so the string only grows a logarithmic number
+    // of times.
+    assert!(pushed < large + 64, "{} allocations", pushed);
+}
@@ -166,3 +166,64 @@ fn test_bitwise_not() {
         !0b10100,
     };
 }
+
+#[test]
+fn test_div_panic_option() {
+    use rune::{Diagnostics, Options, Sources};
+    use runestick::{Context, Source, Vm};
+    use std::sync::Arc;
+
+    fn run(options: &Options, source: &str) -> VmError {
+        let context = Context::with_default_modules().unwrap();
+
+        let mut sources = Sources::new();
+        sources.insert(Source::new("test", source));
+
+        let mut diagnostics = Diagnostics::new();
+        let unit = rune::load_sources(&context, options, &mut sources, &mut diagnostics).unwrap();
+
+        let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+        vm.call::<Value>(&["main"], ()).unwrap_err()
+    }
+
+    let sources = [
+        r#"pub fn main() { let a = 10; let b = 0; a / b }"#,
+        r#"pub fn main() { let a = 10; let b = 0; a /= b; a }"#,
+        r#"pub fn main() { let a = 10; let b = 0; a % b }"#,
+        r#"pub fn main() { let a = 10; let b = 0; a %= b; a }"#,
+    ];
+
+    for source in &sources {
+        let (error, _) = run(&Options::default(), source).into_unwound();
+        assert_matches!(error.into_kind(), DivideByZero);
+
+        let mut options = Options::default();
+        options.panic_on_divide_by_zero(true);
+
+        let (error, _) = run(&options, source).into_unwound();
+
+        match error.into_kind() {
+            Panic { reason } => assert_eq!(reason.to_string(), "attempt to divide by zero"),
+            kind => panic!("expected panic but got {:?}", kind),
+        }
+    }
+}
+
+#[test]
+fn test_pow() {
+    assert_eq!(rune_s!(i64 => r#"pub fn main() { 2 ** 10 }"#), 1024);
+    assert!(rune_s!(bool => r#"pub fn main() { 2 ** 3 ** 2 == 512 }"#));
+    assert_eq!(rune_s!(i64 => r#"pub fn main() { 2 * 3 ** 2 }"#), 18);
+    assert_eq!(
+        rune_s!(i64 => r#"pub fn main() { let a = 2; a **= 3; a }"#),
+        8
+    );
+    assert_eq!(
+        rune_s!(f64 => r#"pub fn main() { 2.0 ** 0.5 }"#),
+        2f64.powf(0.5)
+    );
+
+    assert_vm_error!(r#"pub fn main() { 2 ** 64 }"#, Overflow => {});
+    assert_vm_error!(r#"pub fn main() { 2 ** -1 }"#, NegativeExponent => {});
+    assert_vm_error!(r#"pub fn main() { let a = 2; a **= -1; a }"#, NegativeExponent => {});
+}
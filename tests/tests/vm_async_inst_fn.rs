@@ -0,0 +1,106 @@
+#![cfg(feature = "futures-executor")]
+
+use rune_tests::*;
+use runestick::{Any, Context, Module, Vm, VmErrorKind};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+
+/// A future which is pending the first time it's polled.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<()> {
+        if std::mem::replace(&mut self.0, true) {
+            return Poll::Ready(());
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[derive(Debug, Any)]
+struct Client {
+    url: String,
+}
+
+impl Client {
+    async fn fetch(&self) -> String {
+        YieldNow(false).await;
+        format!("response from {}", self.url)
+    }
+
+    fn set_url(&mut self, url: &str) {
+        self.url = url.to_owned();
+    }
+}
+
+fn vm(source: &str) -> Vm {
+    let mut module = Module::new();
+    module.ty::<Client>().unwrap();
+    module.async_inst_fn("fetch", Client::fetch).unwrap();
+    module.inst_fn("set_url", Client::set_url).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let (unit, _) = compile_source(&context, source).unwrap();
+    Vm::new(Arc::new(context.runtime()), Arc::new(unit))
+}
+
+#[test]
+fn test_async_inst_fn_borrowing_self() {
+    let vm = vm(r#"
+        pub async fn main(client) {
+            let a = client.fetch().await;
+            client.set_url("b");
+            let b = client.fetch().await;
+            (a, b)
+        }
+        "#);
+
+    let client = Client {
+        url: String::from("a"),
+    };
+
+    let output: (String, String) =
+        futures_executor::block_on(vm.async_call(&["main"], (client,))).unwrap();
+
+    assert_eq!(
+        output,
+        (
+            String::from("response from a"),
+            String::from("response from b")
+        )
+    );
+}
+
+#[test]
+fn test_async_inst_fn_borrowed_until_resolved() {
+    let vm = vm(r#"
+        pub async fn main(client) {
+            let f = client.fetch();
+            client.set_url("b");
+            f.await
+        }
+        "#);
+
+    let client = Client {
+        url: String::from("a"),
+    };
+
+    let error =
+        futures_executor::block_on(vm.async_call::<String>(&["main"], (client,))).unwrap_err();
+
+    // NB: the receiver of `fetch` is still borrowed, so it can't be borrowed
+    // mutably by `set_url`.
+    match error.into_unwound().0.into_kind() {
+        VmErrorKind::BadArgument { error, arg: 0 } => {
+            assert!(matches!(error.into_kind(), VmErrorKind::AccessError { .. }));
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
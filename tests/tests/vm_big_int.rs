@@ -0,0 +1,69 @@
+use rune_tests::*;
+use runestick::{Unit, Vm};
+use std::sync::Arc;
+
+fn compile(context: &runestick::Context) -> Arc<Unit> {
+    let (unit, _) = compile_source(
+        context,
+        r#"
+        fn factorial(n) {
+            let out = 1;
+
+            while n > 1 {
+                out *= n;
+                n -= 1;
+            }
+
+            out
+        }
+
+        pub fn main(n) {
+            let big = factorial(n);
+            let back = big / factorial(n - 1);
+            (`${big}`, big > 5, 5 < big, big == big * 1, back)
+        }
+        "#,
+    )
+    .unwrap();
+
+    Arc::new(unit)
+}
+
+#[test]
+fn test_promote_integers() {
+    let context = runestick::Context::with_default_modules().unwrap();
+    let unit = compile(&context);
+
+    let mut runtime = context.runtime();
+    runtime.set_promote_integers(true);
+
+    let vm = Vm::new(Arc::new(runtime), unit);
+    let output = vm.execute(&["main"], (25i64,)).unwrap().complete().unwrap();
+    let output = <(String, bool, bool, bool, i64)>::from_value(output).unwrap();
+
+    assert_eq!(
+        output,
+        (
+            "15511210043330985984000000".to_owned(),
+            true,
+            true,
+            true,
+            25
+        )
+    );
+}
+
+#[test]
+fn test_overflow_without_promotion() {
+    let context = runestick::Context::with_default_modules().unwrap();
+    let unit = compile(&context);
+
+    let vm = Vm::new(Arc::new(context.runtime()), unit);
+    let error = vm
+        .execute(&["main"], (25i64,))
+        .unwrap()
+        .complete()
+        .unwrap_err();
+
+    assert!(matches!(error.into_unwound().0.into_kind(), Overflow));
+}
@@ -0,0 +1,210 @@
+use rune_tests::*;
+use runestick::{Context, Function, Item, Source, Span, Vm, VmDebugState, VmError, VmHalt};
+use std::sync::Arc;
+
+#[test]
+fn test_breakpoint_item() {
+    let context = Context::with_default_modules().unwrap();
+
+    let source = r#"
+    fn inner(n) {
+        let b = n * 2;
+        b + 1
+    }
+
+    pub fn main() {
+        let out = 0;
+
+        for n in 0..3 {
+            out += inner(n);
+        }
+
+        out
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    let file = Source::new("main", source);
+    let inner = Item::with_item(&["inner"]);
+
+    // Line 3 is `b + 1`, line 4 only contains a closing brace.
+    let vm = execution.vm_mut().unwrap();
+    assert!(vm.set_breakpoint_item(&inner, 3, &file).is_some());
+    assert!(vm.set_breakpoint_item(&inner, 4, &file).is_none());
+    assert!(vm
+        .set_breakpoint_item(&Item::with_item(&["missing"]), 3, &file)
+        .is_none());
+
+    let mut halts = Vec::new();
+
+    let output = loop {
+        match execution.resume_debug().unwrap() {
+            VmDebugState::Complete(output) => break output,
+            VmDebugState::Halted(VmHalt::Breakpoint { ip, span }) => {
+                let vm = execution.vm().unwrap();
+                assert_eq!(vm.ip(), ip);
+
+                let frames = vm.frames().collect::<Vec<_>>();
+                let top = frames.last().unwrap();
+                assert_eq!(top.item(), Some(&inner));
+
+                let b = top.get("b").cloned().unwrap();
+                halts.push((span.unwrap(), i64::from_value(b).unwrap()));
+            }
+            state => panic!("unexpected state: {:?}", state),
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 9);
+
+    // The first instruction on the line loads the `1` operand, since `b` is
+    // addressed directly on the stack.
+    let start = source.find("b + 1").unwrap() + 4;
+    let span = Span::new(start, start + 1);
+
+    assert_eq!(halts, vec![(span, 0), (span, 2), (span, 4)]);
+}
+
+/// Run a loop summing `0..end` into `total` with a breakpoint set through
+/// `set` on the line of `total += n`, returning the output and the value of
+/// `total` at every halt.
+fn conditional_breakpoint<F>(end: i64, set: F) -> (i64, Vec<i64>)
+where
+    F: FnOnce(&mut Vm, &Item, usize, &Source) -> Option<usize>,
+{
+    let context = Context::with_default_modules().unwrap();
+
+    let source = format!(
+        r#"
+    pub fn main() {{
+        let total = 0;
+
+        for n in 0..{} {{
+            total += n;
+        }}
+
+        total
+    }}
+    "#,
+        end
+    );
+
+    let (unit, _) = compile_source(&context, &source).unwrap();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    let file = Source::new("main", &source);
+    let main = Item::with_item(&["main"]);
+
+    // Line 5 is `total += n`.
+    let breakpoint = set(execution.vm_mut().unwrap(), &main, 5, &file).unwrap();
+
+    let mut halts = Vec::new();
+
+    let output = loop {
+        match execution.resume_debug().unwrap() {
+            VmDebugState::Complete(output) => break output,
+            VmDebugState::Halted(VmHalt::Breakpoint { ip, .. }) => {
+                assert_eq!(ip, breakpoint);
+                let frames = execution.vm().unwrap().frames().collect::<Vec<_>>();
+                let total = frames.last().unwrap().get("total").cloned().unwrap();
+                halts.push(i64::from_value(total).unwrap());
+            }
+            state => panic!("unexpected state: {:?}", state),
+        }
+    };
+
+    (i64::from_value(output).unwrap(), halts)
+}
+
+#[test]
+fn test_conditional_breakpoint_item() {
+    let (output, halts) = conditional_breakpoint(10, |vm, main, line, file| {
+        vm.set_conditional_breakpoint_item(main, line, file, |frame| {
+            let n = frame
+                .get("n")
+                .cloned()
+                .ok_or_else(|| VmError::panic("missing `n`"))?;
+
+            Ok(i64::from_value(n)? == 5)
+        })
+    });
+
+    assert_eq!(output, 45);
+    // The sum of `0..5`, since the breakpoint halts before `total += 5`.
+    assert_eq!(halts, vec![10]);
+}
+
+#[test]
+fn test_conditional_breakpoint_item_error() {
+    // A condition which errors always halts.
+    let (output, halts) = conditional_breakpoint(3, |vm, main, line, file| {
+        vm.set_conditional_breakpoint_item(main, line, file, |_| Err(VmError::panic("oops")))
+    });
+
+    assert_eq!(output, 3);
+    assert_eq!(halts, vec![0, 0, 1]);
+}
+
+#[test]
+fn test_conditional_breakpoint_item_function() {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+
+    let condition: Function = run(
+        &context,
+        r#"pub fn main() { |locals| locals.n == 5 }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    let (output, halts) = conditional_breakpoint(10, |vm, main, line, file| {
+        vm.set_conditional_breakpoint_item_function(main, line, file, condition)
+    });
+
+    assert_eq!(output, 45);
+    assert_eq!(halts, vec![10]);
+}
+
+#[test]
+fn test_conditional_breakpoint_send_execute() {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+
+    let condition: Function = run(
+        &context,
+        r#"pub fn main() { |locals| locals.n == 5 }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    let source = r#"
+    pub fn main() {
+        let n = 5;
+        n
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let file = Source::new("main", source);
+    let main = Item::with_item(&["main"]);
+
+    // Line 3 is `n`.
+    let ip = vm
+        .set_conditional_breakpoint_item_function(&main, 3, &file, condition)
+        .unwrap();
+
+    let error = vm.clone().send_execute(&["main"], ()).err().unwrap();
+    assert!(matches!(error.into_kind(), UnsendableDebugState));
+
+    // Native conditions can be sent along with the virtual machine.
+    assert!(vm.clear_breakpoint(ip));
+    vm.set_conditional_breakpoint_item(&main, 3, &file, |_| Ok(true))
+        .unwrap();
+    assert!(vm.send_execute(&["main"], ()).is_ok());
+}
@@ -0,0 +1,84 @@
+use rune_tests::*;
+
+#[test]
+fn test_bytes_eq() {
+    assert!(rune!(bool => pub fn main() { b"hello" == b"hello" }));
+    assert!(!rune!(bool => pub fn main() { b"hello" == b"world" }));
+    assert!(rune!(bool => pub fn main() { b"hello" != b"hell" }));
+    assert!(!rune!(bool => pub fn main() { b"hello" == "hello" }));
+    assert!(!rune!(bool => pub fn main() { 42 == b"hello" }));
+
+    assert!(rune!(bool => pub fn main() {
+        let a = std::bytes::Bytes::new();
+        a.extend(b"abc");
+        a == b"abc"
+    }));
+}
+
+#[test]
+fn test_bytes_as_map_key() {
+    rune! { () =>
+        pub fn main() {
+            use std::collections::HashMap;
+            use std::collections::HashSet;
+
+            let m = HashMap::new();
+            m.insert(b"a", 1);
+            m.insert(b"b", 2);
+
+            assert_eq!(m.get(b"a"), Some(1));
+            assert_eq!(m.get(b"b"), Some(2));
+            assert_eq!(m.get(b"c"), None);
+
+            let s = HashSet::new();
+            s.insert(b"a");
+            assert!(s.contains(b"a"));
+            assert!(!s.contains(b"b"));
+        }
+    };
+}
+
+#[test]
+fn test_bytes_from_native_slices() {
+    let out: Box<[u8]> = rune_n! {
+        runestick::Module::new(),
+        (Box::<[u8]>::from(&b"abc"[..]), &b"def"[..]),
+        Box<[u8]> => pub fn main(a, b) {
+            a.extend(b);
+            a
+        }
+    };
+
+    assert_eq!(&*out, b"abcdef");
+}
+
+#[test]
+fn test_bytes_index_get() {
+    assert_eq!(rune!(i64 => pub fn main() { b"abc"[0] }), 97);
+    assert!(rune!(bool => pub fn main() { b"abc"[0] == 97 }));
+    assert!(rune!(bool => pub fn main() { b"abcd"[1..3] == b"bc" }));
+    assert!(rune!(bool => pub fn main() { b"abcd"[..=1] == b"ab" }));
+    assert!(rune!(bool => pub fn main() { b"abcd"[2..] == b"cd" }));
+
+    assert_vm_error!(
+        r#"pub fn main() { b"abc"[3] }"#,
+        OutOfRange { index, len } => {
+            assert_eq!(index.to_string(), "3");
+            assert_eq!(len.to_string(), "3");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { b"abc"[1..4] }"#,
+        OutOfRange { index, .. } => {
+            assert_eq!(index.to_string(), "4");
+        }
+    );
+}
+
+#[test]
+fn test_bytes_len() {
+    assert_eq!(rune!(usize => pub fn main() { b"abc".len() }), 3);
+    assert!(rune!(bool => pub fn main() { b"".is_empty() }));
+    assert!(!rune!(bool => pub fn main() { b"abc".is_empty() }));
+}
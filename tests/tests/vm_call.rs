@@ -0,0 +1,88 @@
+use rune_tests::*;
+use runestick::{Context, ItemPath, Module, Vm};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context as TaskContext, Poll};
+
+/// A future which is pending the first time it's polled.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<()> {
+        if std::mem::replace(&mut self.0, true) {
+            return Poll::Ready(());
+        }
+
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+async fn delayed(value: i64) -> i64 {
+    YieldNow(false).await;
+    value
+}
+
+fn vm(source: &str) -> Vm {
+    let mut module = Module::new();
+    module.async_function(&["delayed"], delayed).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let (unit, _) = compile_source(&context, source).unwrap();
+    Vm::new(Arc::new(context.runtime()), Arc::new(unit))
+}
+
+#[test]
+fn test_call_with_conversion() {
+    let vm = vm(r#"
+        pub mod math {
+            pub fn add(a, b) { a + b }
+        }
+        "#);
+
+    let output: i64 = vm.clone().call(&["math", "add"], (1i64, 2i64)).unwrap();
+    assert_eq!(output, 3);
+
+    let output = vm
+        .clone()
+        .call::<i64>(&["math", "add"], (40i32, 2u32))
+        .unwrap();
+    assert_eq!(output, 42);
+
+    let output = vm
+        .call::<i64>(ItemPath::new("math::add"), (1i64, 1i64))
+        .unwrap();
+    assert_eq!(output, 2);
+}
+
+#[test]
+fn test_call_conversion_error() {
+    let vm = vm(r#"pub fn add(a, b) { a + b }"#);
+
+    let error = vm.call::<String>(&["add"], (1i64, 2i64)).unwrap_err();
+
+    match error.kind() {
+        runestick::VmErrorKind::Expected { expected, actual } => {
+            assert_eq!(expected.to_string(), "String");
+            assert_eq!(actual.to_string(), "integer");
+        }
+        kind => panic!("unexpected error {:?}", kind),
+    }
+}
+
+#[test]
+#[cfg(feature = "futures-executor")]
+fn test_async_call() {
+    let vm = vm(r#"
+        pub async fn add(a, b) {
+            delayed(a).await + delayed(b).await
+        }
+        "#);
+
+    let output: i64 = futures_executor::block_on(vm.async_call(&["add"], (40i64, 2i64))).unwrap();
+    assert_eq!(output, 42);
+}
@@ -0,0 +1,77 @@
+use rune_tests::*;
+use runestick::{Any, Module, Source};
+use std::cell::Cell;
+
+/// Get the byte offset of the native function call site.
+fn call_site() -> Option<usize> {
+    let location = runestick::call_site()?;
+    Some(location.span.start.into_usize())
+}
+
+#[test]
+fn test_native_call_site() {
+    let source = r#"
+        pub fn main() {
+            let a = call_site();
+
+            let b = [1].iter().map(|_| {
+                call_site()
+            }).next();
+
+            (a, b)
+        }
+    "#;
+
+    let mut module = Module::new();
+    module.function(&["call_site"], call_site).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let vm = vm_with_source(&context, source).unwrap();
+    let output = vm.execute(&["main"], ()).unwrap().complete().unwrap();
+    let (a, b) = <(Option<usize>, Option<Option<usize>>)>::from_value(output).unwrap();
+
+    let source = Source::new("main", source);
+    let line = |offset: usize| source.position_to_unicode_line_char(offset).0;
+
+    assert_eq!(a.map(line), Some(2));
+    assert_eq!(b.flatten().map(line), Some(5));
+    assert!(runestick::call_site().is_none());
+}
+
+thread_local!(static DROPPED_AT: Cell<Option<Option<usize>>> = const { Cell::new(None) });
+
+/// A value which records the call site when it's dropped.
+#[derive(Any)]
+struct Probe;
+
+impl Drop for Probe {
+    fn drop(&mut self) {
+        DROPPED_AT.with(|dropped_at| dropped_at.set(Some(call_site())));
+    }
+}
+
+#[test]
+fn test_native_call_site_cleared() {
+    let source = r#"
+        pub fn main() {
+            let probe = probe();
+            1
+        }
+    "#;
+
+    let mut module = Module::new();
+    module.ty::<Probe>().unwrap();
+    module.function(&["probe"], || Probe).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let vm = vm_with_source(&context, source).unwrap();
+    vm.execute(&["main"], ()).unwrap().complete().unwrap();
+
+    // NB: the probe is dropped by the virtual machine after the call to
+    // `probe` has returned, so there is no call site.
+    assert_eq!(DROPPED_AT.with(Cell::get), Some(None));
+}
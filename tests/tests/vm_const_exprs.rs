@@ -266,3 +266,15 @@ fn test_const_block() {
 
     assert_eq!(result, "Hello World");
 }
+
+#[test]
+fn test_const_block_stmt() {
+    assert_eq! {
+        rune! { i64 =>
+            pub fn main() {
+                const { 1 + 2 }
+            }
+        },
+        3,
+    };
+}
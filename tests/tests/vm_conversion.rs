@@ -0,0 +1,177 @@
+use rune_tests::*;
+use runestick::{Any, Context, ContextError, Module, TypeOf as _, VmErrorKind};
+use std::sync::Arc;
+
+#[derive(Debug, Any)]
+struct Celsius(f64);
+
+#[derive(Debug, Any)]
+struct Fahrenheit(f64);
+
+#[derive(Debug, Any)]
+struct Kelvin(f64);
+
+fn context() -> Arc<Context> {
+    let mut module = Module::new();
+    module.ty::<Celsius>().unwrap();
+    module.ty::<Fahrenheit>().unwrap();
+    module.ty::<Kelvin>().unwrap();
+    module.function(&["Celsius", "new"], Celsius).unwrap();
+    module.function(&["Fahrenheit", "new"], Fahrenheit).unwrap();
+    module.function(&["Kelvin", "new"], Kelvin).unwrap();
+    module.inst_fn("degrees", |c: &Celsius| c.0).unwrap();
+    module.inst_fn("degrees", |f: &Fahrenheit| f.0).unwrap();
+    module
+        .conversion(|c: &Celsius| Fahrenheit(c.0 * 1.8 + 32.0))
+        .unwrap();
+    module
+        .conversion(|f: &Fahrenheit| Celsius((f.0 - 32.0) / 1.8))
+        .unwrap();
+    module
+        .conversion(|k: &Kelvin| Celsius(k.0 - 273.0))
+        .unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_conversion() {
+    let context = context();
+
+    let out: f64 = run(
+        &context,
+        r#"
+        pub fn main() {
+            let celsius = Celsius::new(100.0);
+            let fahrenheit = celsius as Fahrenheit;
+            fahrenheit.degrees()
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(out, 212.0);
+
+    let out: f64 = run(
+        &context,
+        r#"pub fn main() { (Fahrenheit::new(32.0) as Celsius).degrees() }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(out, 0.0);
+
+    let out: f64 = run(
+        &context,
+        r#"pub fn main() { (Kelvin::new(300.0) as Celsius).degrees() }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(out, 27.0);
+
+    // NB: converting a value into its own type is a no-op.
+    let out: f64 = run(
+        &context,
+        r#"pub fn main() { (Celsius::new(21.0) as Celsius).degrees() }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(out, 21.0);
+}
+
+#[test]
+fn test_missing_conversion() {
+    let context = context();
+
+    // NB: only the conversion from `Kelvin` into `Celsius` is registered.
+    let error = run::<_, _, ()>(
+        &context,
+        r#"pub fn main() { Celsius::new(100.0) as Kelvin }"#,
+        &["main"],
+        (),
+    )
+    .unwrap_err();
+
+    match error {
+        RunError::VmError(error) => match error.into_unwound().0.into_kind() {
+            VmErrorKind::MissingConversion { from, to } => {
+                assert_eq!(from.to_string(), "Celsius");
+                assert_eq!(to.to_string(), Kelvin::type_info().to_string());
+            }
+            kind => panic!("unexpected error: {:?}", kind),
+        },
+        error => panic!("unexpected error: {:?}", error),
+    }
+
+    let error = run::<_, _, ()>(
+        &context,
+        r#"struct Rankine; pub fn main() { Celsius::new(100.0) as Rankine }"#,
+        &["main"],
+        (),
+    )
+    .unwrap_err();
+
+    match error {
+        RunError::VmError(error) => match error.into_unwound().0.into_kind() {
+            VmErrorKind::MissingConversion { from, to } => {
+                assert_eq!(from.to_string(), "Celsius");
+                assert_eq!(to.to_string(), "Rankine");
+            }
+            kind => panic!("unexpected error: {:?}", kind),
+        },
+        error => panic!("unexpected error: {:?}", error),
+    }
+}
+
+#[test]
+fn test_conflicting_conversion() {
+    let mut module = Module::new();
+    module.ty::<Celsius>().unwrap();
+    module.ty::<Fahrenheit>().unwrap();
+    module.conversion(|c: &Celsius| Fahrenheit(c.0)).unwrap();
+
+    assert!(matches!(
+        module.conversion(|c: &Celsius| Fahrenheit(c.0)),
+        Err(ContextError::ConflictingConversion { .. })
+    ));
+
+    let mut module = Module::new();
+    module.ty::<Celsius>().unwrap();
+    module.conversion(|c: &Celsius| Fahrenheit(c.0)).unwrap();
+
+    let mut context = Context::new();
+    match context.install(&module) {
+        Err(ContextError::MissingConversionType { type_info }) => {
+            assert_eq!(type_info.to_string(), Fahrenheit::type_info().to_string());
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_conversion_install_order() {
+    let mut types = Module::new();
+    types.ty::<Fahrenheit>().unwrap();
+
+    let mut conversions = Module::new();
+    conversions.ty::<Celsius>().unwrap();
+    conversions
+        .conversion(|c: &Celsius| Fahrenheit(c.0))
+        .unwrap();
+
+    // NB: the types used by a conversion have to be installed before it.
+    let mut context = Context::new();
+    assert!(matches!(
+        context.install(&conversions),
+        Err(ContextError::MissingConversionType { .. })
+    ));
+
+    let mut context = Context::new();
+    context.install(&types).unwrap();
+    context.install(&conversions).unwrap();
+}
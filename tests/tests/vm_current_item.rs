@@ -0,0 +1,46 @@
+use rune_tests::*;
+use runestick::{Context, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_current_item() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn inner(n) { n + 1 }
+        pub fn main() { let a = inner(1); a + std::int::max(a, 2) }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    assert_eq!(vm.current_item(), None);
+
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+    let mut items = Vec::new();
+
+    let output = loop {
+        let item = execution.vm().unwrap().current_item().cloned();
+
+        if items.last() != Some(&item) {
+            items.push(item);
+        }
+
+        if let Some(output) = execution.step().unwrap() {
+            break output;
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 4);
+
+    assert_eq!(
+        items,
+        vec![
+            Some(Item::with_item(&["main"])),
+            Some(Item::with_item(&["inner"])),
+            Some(Item::with_item(&["main"])),
+        ]
+    );
+}
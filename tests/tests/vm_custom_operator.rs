@@ -0,0 +1,132 @@
+use rune::{Parser, TokenStream};
+use rune_tests::*;
+use runestick::{Context, ContextError, Module};
+use std::sync::Arc;
+
+fn concat_digits(a: i64, b: i64) -> i64 {
+    a * 10 + b
+}
+
+#[test]
+fn test_custom_operator() {
+    let mut module = Module::new();
+    module.binary_operator("<>", 10, concat_digits).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    let out: i64 = run(&context, r#"pub fn main() { 1 <> 2 }"#, &["main"], ()).unwrap();
+    assert_eq!(out, 12);
+
+    let out: i64 = run(&context, r#"pub fn main() { 1 <> 2 * 3 }"#, &["main"], ()).unwrap();
+    assert_eq!(out, 16);
+
+    let out: i64 = run(&context, r#"pub fn main() { 1 <> 2 <> 3 }"#, &["main"], ()).unwrap();
+    assert_eq!(out, 123);
+
+    let out: bool = run(&context, r#"pub fn main() { 1 <> 2 == 12 }"#, &["main"], ()).unwrap();
+    assert!(out);
+}
+
+#[test]
+fn test_unknown_custom_operator() {
+    let context = Context::with_default_modules().unwrap();
+    assert!(compile_source(&context, r#"pub fn main() { 1 <> 2 }"#).is_err());
+
+    let mut module = Module::new();
+    module.binary_operator("<>", 10, concat_digits).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    // NB: the operator is only recognized without whitespace in it.
+    assert!(compile_source(&context, r#"pub fn main() { 1 < > 2 }"#).is_err());
+}
+
+/// Compile a source with the given operator registered, returning the invalid
+/// operator reported by the compiler, if any.
+fn invalid_operator(op: &str) -> Option<Box<str>> {
+    let mut module = Module::new();
+    module.binary_operator(op, 10, concat_digits).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let diagnostics = match compile_source(&context, r#"pub fn main() { 1 }"#) {
+        Ok(..) => return None,
+        Err(diagnostics) => diagnostics,
+    };
+
+    match diagnostics.into_diagnostics().into_iter().next() {
+        Some(rune::Diagnostic::Error(error)) => match error.into_kind() {
+            rune::ErrorKind::CompileError(error) => match error.into_kind() {
+                CompileErrorKind::InvalidOperator { op } => Some(op),
+                kind => panic!("unexpected error: {:?}", kind),
+            },
+            kind => panic!("unexpected error: {:?}", kind),
+        },
+        diagnostic => panic!("unexpected diagnostic: {:?}", diagnostic),
+    }
+}
+
+#[test]
+fn test_invalid_custom_operator() {
+    // NB: these are lexed as a single builtin token, or could be read as a
+    // builtin binary operator followed by a unary one.
+    for op in &[
+        "foo", "==", "<=", "->", "<<=", "<-", "==!", "+*", "||", "<>>>", "<$>", "a>", "<?", "//",
+    ] {
+        assert_eq!(invalid_operator(op).as_deref(), Some(*op));
+    }
+
+    assert_eq!(invalid_operator("<>"), None);
+    assert_eq!(invalid_operator("<>>"), None);
+    assert_eq!(invalid_operator("~>"), None);
+
+    let mut module = Module::new();
+    module.binary_operator("<>", 10, concat_digits).unwrap();
+
+    assert!(matches!(
+        module.binary_operator("<>", 10, concat_digits),
+        Err(ContextError::ConflictingOperator { .. })
+    ));
+}
+
+/// Expands to its input unchanged.
+fn identity(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    Ok(stream.clone())
+}
+
+/// Parses its input as an expression, and expands to it.
+fn parse_expr(stream: &TokenStream) -> runestick::Result<TokenStream> {
+    let mut p = Parser::from_token_stream(stream);
+    p.parse_all::<rune::ast::Expr>()?;
+    Ok(stream.clone())
+}
+
+#[test]
+fn test_custom_operator_in_macros() {
+    let mut module = Module::new();
+    module.binary_operator("<>", 10, concat_digits).unwrap();
+    module.macro_(&["identity"], identity).unwrap();
+    module.macro_(&["parse_expr"], parse_expr).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    // The output of a macro is parsed with the custom operators in scope.
+    let out: i64 = run(
+        &context,
+        r#"pub fn main() { identity!(1 <> 2) }"#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+    assert_eq!(out, 12);
+
+    // NB: a parser constructed by a macro over its input doesn't know about
+    // custom operators, so it sees `<` followed by `>`.
+    assert!(compile_source(&context, r#"pub fn main() { parse_expr!(1 <> 2) }"#).is_err());
+}
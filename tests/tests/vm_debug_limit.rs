@@ -0,0 +1,131 @@
+use rune_tests::*;
+use runestick::debug_limit::{self, DebugLimits};
+use runestick::Object;
+
+#[test]
+fn test_debug_limit_vec() {
+    let value: Value = rune!(Value => pub fn main() { [[1, [2, [3, [4]]]], 5, 6, 7, 8] });
+
+    assert_eq!(format!("{:?}", value), "[[1, [2, [3, [4]]]], 5, 6, 7, 8]");
+
+    let limits = DebugLimits {
+        max_depth: 2,
+        max_elements: 3,
+    };
+
+    assert_eq!(
+        debug_limit::with(limits, || format!("{:?}", value)),
+        "[[1, [... 2 more]], 5, 6, ... 2 more]"
+    );
+}
+
+#[test]
+fn test_debug_limit_object() {
+    let value: Value = rune!(Value => pub fn main() { #{a: #{b: #{c: 1}}, d: (1, 2, 3)} });
+
+    let limits = DebugLimits {
+        max_depth: 1,
+        max_elements: 2,
+    };
+
+    assert_eq!(
+        debug_limit::with(limits, || format!("{:?}", value)),
+        "{\"a\": {... 1 more}, \"d\": (... 3 more)}"
+    );
+}
+
+#[test]
+fn test_debug_limit_struct() {
+    let value: Value = rune!(Value =>
+        struct Point { x, y, z }
+        pub fn main() { Point { x: 1, y: 2, z: [3, 4] } }
+    );
+
+    let limits = DebugLimits {
+        max_depth: 1,
+        max_elements: 2,
+    };
+
+    assert_eq!(
+        debug_limit::with(limits, || format!("{:?}", value)),
+        "Point { x: 1, y: 2, ... 1 more }"
+    );
+}
+
+#[test]
+fn test_debug_limit_format() {
+    let limits = DebugLimits {
+        max_depth: 1,
+        max_elements: 2,
+    };
+
+    let out: String = debug_limit::with(
+        limits,
+        || rune!(String => pub fn main() { format!("{:?}", [1, [2, 3], 4, 5]) }),
+    );
+
+    assert_eq!(out, "[1, [... 2 more], ... 2 more]");
+}
+
+#[test]
+fn test_debug_limit_pretty() {
+    let object: Object = rune!(Object => pub fn main() { #{a: 1, b: 2, c: 3} });
+
+    let limits = DebugLimits {
+        max_depth: 1,
+        max_elements: 2,
+    };
+
+    assert_eq!(
+        debug_limit::with(limits, || format!("{:#?}", object)),
+        "{\n    \"a\": 1,\n    \"b\": 2,\n    ... 1 more,\n}"
+    );
+}
+
+#[test]
+fn test_debug_limit_tuple_struct_and_variants() {
+    let value: Value = rune!(Value =>
+        struct Point(x, y, z);
+        enum Enum { Tuple(a, b, c), Struct { a, b, c } }
+
+        pub fn main() {
+            [Point(1, 2, [3]), Enum::Tuple(1, 2, [3]), Enum::Struct { a: 1, b: [2], c: 3 }]
+        }
+    );
+
+    let limits = DebugLimits {
+        max_depth: 2,
+        max_elements: 2,
+    };
+
+    assert_eq!(
+        debug_limit::with(limits, || format!("{:?}", value)),
+        "[Point(1, 2, ... 1 more), Enum::Tuple(1, 2, ... 1 more), ... 1 more]"
+    );
+
+    let limits = DebugLimits {
+        max_depth: 1,
+        max_elements: 3,
+    };
+
+    assert_eq!(
+        debug_limit::with(limits, || format!("{:?}", value)),
+        "[Point(... 3 more), Enum::Tuple(... 3 more), Enum::Struct { ... 3 more }]"
+    );
+}
+
+#[test]
+fn test_debug_limit_script() {
+    let out: String = rune!(String => pub fn main() {
+        std::io::set_debug_limits(1, 2);
+        format!("{:?}", [1, [2, 3], 4, 5])
+    });
+
+    assert_eq!(out, "[1, [... 2 more], ... 2 more]");
+
+    // NB: limits set by the script don't outlive its execution.
+    assert_eq!(
+        debug_limit::set(DebugLimits::unlimited()),
+        DebugLimits::unlimited()
+    );
+}
@@ -0,0 +1,33 @@
+use rune_tests::*;
+use runestick::Hash;
+
+#[test]
+fn test_runtime_error_location() {
+    let source = r#"
+        pub fn main() {
+            let a = 1;
+            let b = "two";
+            let c = a + b;
+            c
+        }
+    "#;
+
+    let context = runestick::Context::with_default_modules().unwrap();
+    let vm = vm_with_source(&context, source).unwrap();
+    let unit = vm.unit().clone();
+
+    let error = vm.execute(&["main"], ()).unwrap().complete().unwrap_err();
+    let location = error.location().expect("error should have a location");
+
+    let start = source.find("a + b").unwrap();
+    assert_eq!(location.span, Span::new(start, start + "a + b".len()));
+
+    let ip = match error.as_unwound() {
+        (_, Some((_, ip, _))) => ip,
+        _ => panic!("expected unwound error"),
+    };
+
+    let (hash, signature) = unit.debug_info().unwrap().function_containing(ip).unwrap();
+    assert_eq!(hash, Hash::type_hash(&["main"]));
+    assert_eq!(signature.path.to_string(), "main");
+}
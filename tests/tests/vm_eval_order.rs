@@ -0,0 +1,42 @@
+use rune_tests::*;
+
+/// Elements of literals are evaluated left-to-right in source order,
+/// regardless of the order in which they are stored.
+#[test]
+fn test_literal_eval_order() {
+    let out: Vec<i64> = rune! { Vec<i64> =>
+        struct Point { x, y }
+
+        fn record(order, n) {
+            order.push(n);
+            n
+        }
+
+        pub fn main() {
+            let order = [];
+
+            let v = [record(order, 0), record(order, 1), record(order, 2)];
+            assert_eq!(v, [0, 1, 2]);
+
+            let v = [record(order, 3), ..[record(order, 4)], record(order, 5)];
+            assert_eq!(v, [3, 4, 5]);
+
+            let o = #{c: record(order, 6), a: record(order, 7), b: record(order, 8)};
+            assert_eq!(o.c, 6);
+            assert_eq!(o.a, 7);
+            assert_eq!(o.b, 8);
+
+            let p = Point { y: record(order, 9), x: record(order, 10) };
+            assert_eq!(p.y, 9);
+            assert_eq!(p.x, 10);
+
+            let t = (record(order, 11), record(order, 12));
+            assert_eq!(t.0, 11);
+            assert_eq!(t.1, 12);
+
+            order
+        }
+    };
+
+    assert_eq!(out, (0..=12).collect::<Vec<_>>());
+}
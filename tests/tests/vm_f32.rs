@@ -0,0 +1,61 @@
+use rune_tests::*;
+use runestick::{Module, Vm};
+use std::sync::Arc;
+
+fn scale(value: f32) -> f32 {
+    value * 2.0
+}
+
+#[test]
+fn test_f32_round_trip() {
+    let mut module = Module::new();
+    module.function(&["scale"], scale).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        pub fn main(value) { scale(value + 0.25) }
+        pub fn big() { 1e300 }
+        "#,
+    )
+    .unwrap();
+
+    let unit = Arc::new(unit);
+    let runtime = Arc::new(context.runtime());
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let output = vm
+        .execute(&["main"], (1.1f32,))
+        .unwrap()
+        .complete()
+        .unwrap();
+    let output = f32::from_value(output).unwrap();
+    assert!((output - 2.7f32).abs() <= f32::EPSILON * 4.0);
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let output = vm
+        .execute(&["main"], (f32::INFINITY,))
+        .unwrap()
+        .complete()
+        .unwrap();
+    assert_eq!(f32::from_value(output).unwrap(), f32::INFINITY);
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let output = vm
+        .execute(&["main"], (f32::NAN,))
+        .unwrap()
+        .complete()
+        .unwrap();
+    assert!(f32::from_value(output).unwrap().is_nan());
+
+    let vm = Vm::new(runtime, unit);
+    let output = vm.execute(&["big"], ()).unwrap().complete().unwrap();
+
+    assert!(matches!(
+        f32::from_value(output).unwrap_err().into_kind(),
+        ValueToFloatCoercionError { .. }
+    ));
+}
@@ -0,0 +1,58 @@
+use rune_tests::*;
+use runestick::{Any, ContextError, Module};
+
+#[derive(Debug, Any)]
+struct Port {
+    #[rune(get)]
+    number: i64,
+}
+
+#[derive(Debug, Any)]
+struct PortError {
+    #[rune(get)]
+    input: String,
+}
+
+impl Port {
+    fn parse(input: &str) -> Result<Self, PortError> {
+        match input.parse::<u16>() {
+            Ok(number) => Ok(Self {
+                number: number as i64,
+            }),
+            Err(..) => Err(PortError {
+                input: input.to_owned(),
+            }),
+        }
+    }
+}
+
+fn make_native_module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate("native_crate");
+    module.ty::<Port>()?;
+    module.ty::<PortError>()?;
+    module.function(&["Port", "parse"], Port::parse)?;
+    Ok(module)
+}
+
+#[test]
+fn test_fallible_constructor() {
+    let output = rune_n! {
+        make_native_module().expect("failed making native module"),
+        (),
+        (i64, String) => pub fn main() {
+            let ok = match native_crate::Port::parse("8080") {
+                Ok(port) => port.number,
+                Err(error) => panic("bad port"),
+            };
+
+            let err = match native_crate::Port::parse("not a port") {
+                Ok(port) => panic("unexpected port"),
+                Err(error) => error.input,
+            };
+
+            (ok, err)
+        }
+    };
+
+    assert_eq!(output, (8080, String::from("not a port")));
+}
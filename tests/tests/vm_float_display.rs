@@ -0,0 +1,41 @@
+use rune_tests::*;
+
+#[test]
+fn test_float_display() {
+    let out = rune_s! { (String, String, String, String, String, String) => r#"
+        pub fn main() {
+            let nan = 0.0 / 0.0;
+            let inf = 1.0 / 0.0;
+
+            (`${1.0}`, `${nan}`, `${inf}`, `${-inf}`, `${-0.0}`, `${0.1 + 0.2}`)
+        }
+    "#};
+
+    assert_eq!(
+        out,
+        (
+            "1.0".to_owned(),
+            "NaN".to_owned(),
+            "inf".to_owned(),
+            "-inf".to_owned(),
+            "-0.0".to_owned(),
+            "0.30000000000000004".to_owned(),
+        )
+    );
+}
+
+#[test]
+fn test_float_display_exponent() {
+    let out = rune_s! { (String, String, String) => r#"
+        pub fn main() {
+            let big = std::float::parse("1e300")?;
+            let small = std::float::parse("1.5e-10")?;
+            (`${big}`, `${small}`, `${100.0}`)
+        }
+    "#};
+
+    assert_eq!(
+        out,
+        ("1e300".to_owned(), "1.5e-10".to_owned(), "100.0".to_owned())
+    );
+}
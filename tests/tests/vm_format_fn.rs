@@ -0,0 +1,48 @@
+use rune_tests::*;
+
+#[test]
+fn test_format_fn() {
+    assert_eq!(
+        rune!(String => pub fn main() { format("{} + {} = {}", 1, 2, 3) }),
+        "1 + 2 = 3"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { format("{{{}}} {}", "a", 'b') }),
+        "{a} b"
+    );
+
+    assert_eq!(
+        rune!(String => pub fn main() { format("no arguments") }),
+        "no arguments"
+    );
+}
+
+#[test]
+fn test_format_fn_errors() {
+    assert_vm_error!(
+        r#"pub fn main() { format("{} {}", 1) }"#,
+        BadArgumentCount { actual, expected } => {
+            assert_eq!(actual, 1);
+            assert_eq!(expected, 2);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { format("{}", 1, 2) }"#,
+        BadArgumentCount { actual, expected } => {
+            assert_eq!(actual, 2);
+            assert_eq!(expected, 1);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { format("{ }", 1) }"#,
+        Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "unmatched `{` in format string, use `{{` to escape it"
+            );
+        }
+    );
+}
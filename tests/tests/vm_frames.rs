@@ -0,0 +1,63 @@
+use rune_tests::*;
+use runestick::{Context, Item, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_frames() {
+    let context = Context::with_default_modules().unwrap();
+
+    let source = r#"
+    fn inner(n) { let b = n * 2; b + 1 }
+    pub fn main() { let a = 10; inner(a) }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+
+    // Set a breakpoint at the first instruction of the `b + 1` expression.
+    let start = source.find("b + 1").unwrap();
+
+    let breakpoint = unit
+        .debug_info()
+        .unwrap()
+        .instructions
+        .iter()
+        .position(|inst| inst.span.start.into_usize() == start)
+        .unwrap();
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    while execution.vm().unwrap().ip() != breakpoint {
+        assert!(execution.step().unwrap().is_none());
+    }
+
+    let vm = execution.vm().unwrap();
+    let frames = vm.frames().collect::<Vec<_>>();
+    assert_eq!(frames.len(), 2);
+
+    let top = &frames[1];
+    assert_eq!(top.item(), Some(&Item::with_item(&["inner"])));
+    assert_eq!(top.ip(), breakpoint);
+
+    let b = top.get("b").cloned().unwrap();
+    assert_eq!(i64::from_value(b).unwrap(), 20);
+
+    let n = top.get("n").cloned().unwrap();
+    assert_eq!(i64::from_value(n).unwrap(), 10);
+
+    let names = top
+        .locals()
+        .filter_map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["n", "b"]);
+
+    let outer = &frames[0];
+    assert_eq!(outer.item(), Some(&Item::with_item(&["main"])));
+    assert!(outer.get("b").is_none());
+
+    let a = outer.get("a").cloned().unwrap();
+    assert_eq!(i64::from_value(a).unwrap(), 10);
+
+    let output = execution.complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 21);
+}
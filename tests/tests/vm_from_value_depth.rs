@@ -0,0 +1,61 @@
+use rune_tests::*;
+use runestick::{FromValue, Value};
+
+#[derive(FromValue)]
+struct Node {
+    children: Vec<Node>,
+}
+
+impl Node {
+    /// The number of nodes below this one.
+    fn depth(&self) -> usize {
+        self.children
+            .iter()
+            .map(|c| c.depth() + 1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+fn nested(depth: i64) -> Value {
+    rune_n! {
+        runestick::Module::new(),
+        (depth,),
+        Value => pub fn main(depth) {
+            let node = #{ children: [] };
+
+            for _ in 0..depth {
+                node = #{ children: [node] };
+            }
+
+            node
+        }
+    }
+}
+
+#[test]
+fn test_from_value_depth() {
+    assert_eq!(Node::from_value(nested(50)).unwrap().depth(), 50);
+
+    let error = match Node::from_value(nested(1000)) {
+        Ok(..) => panic!("expected conversion to fail"),
+        Err(error) => error,
+    };
+
+    assert!(matches!(
+        error.into_kind(),
+        TooDeep {
+            max: runestick::depth::DEFAULT_MAX_DEPTH
+        }
+    ));
+
+    let value = nested(50);
+
+    let error = match runestick::depth::with(10, || Node::from_value(value)) {
+        Ok(..) => panic!("expected conversion to fail"),
+        Err(error) => error,
+    };
+
+    assert!(matches!(error.into_kind(), TooDeep { max: 10 }));
+    assert_eq!(Node::from_value(nested(50)).unwrap().depth(), 50);
+}
@@ -0,0 +1,25 @@
+use rune_tests::*;
+use runestick::Any;
+
+#[derive(Debug, Any)]
+struct External;
+
+#[test]
+fn test_expected_integer_found_string() {
+    let error = i64::from_value(Value::from(String::from("hello"))).unwrap_err();
+
+    assert_eq!(error.to_string(), "expected `integer`, but found `String`");
+}
+
+#[test]
+fn test_expected_external_found_string() {
+    let error = External::from_value(Value::from(String::from("hello"))).unwrap_err();
+    let message = error.to_string();
+
+    assert!(message.starts_with("expected `"), "{}", message);
+    assert!(
+        message.ends_with("External`, but found `String`"),
+        "{}",
+        message
+    );
+}
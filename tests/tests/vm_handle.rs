@@ -0,0 +1,108 @@
+use rune_tests::*;
+use runestick::{Context, Handle, Handles, Module, VmError};
+use std::sync::{Arc, Mutex};
+
+type Connections = &'static Mutex<Handles<Connection>>;
+
+struct Connection {
+    name: String,
+}
+
+fn context(handles: Connections) -> Arc<Context> {
+    let mut module = Module::new();
+    module.ty::<Handle<Connection>>().unwrap();
+
+    module
+        .function(&["name"], move |handle: &Handle<Connection>| {
+            let handles = handles.lock().unwrap();
+            Ok::<_, VmError>(handles.get(handle)?.name.clone())
+        })
+        .unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    Arc::new(context)
+}
+
+#[test]
+fn test_handle_round_trip() {
+    let handles: Connections = Box::leak(Box::new(Mutex::new(Handles::new())));
+    let context = context(handles);
+
+    let handle = handles.lock().unwrap().insert(Connection {
+        name: String::from("primary"),
+    });
+
+    let (out, name): (Handle<Connection>, String) = run(
+        &context,
+        r#"pub fn main(conn) { let name = name(conn); (conn, name) }"#,
+        &["main"],
+        (handle,),
+    )
+    .unwrap();
+
+    assert_eq!(out, handle);
+    assert_eq!(name, "primary");
+}
+
+#[test]
+fn test_handle_opaque() {
+    let handles: Connections = Box::leak(Box::new(Mutex::new(Handles::new())));
+    let context = context(handles);
+
+    let handle = handles.lock().unwrap().insert(Connection {
+        name: String::from("primary"),
+    });
+
+    let (error, _) = run::<_, _, ()>(
+        &context,
+        r#"pub fn main(conn) { conn.index }"#,
+        &["main"],
+        (handle,),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error")
+    .into_unwound();
+
+    assert!(
+        matches!(error.kind(), UnsupportedObjectSlotIndexGet { .. }),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn test_stale_handle() {
+    let handles: Connections = Box::leak(Box::new(Mutex::new(Handles::new())));
+    let context = context(handles);
+
+    let handle = handles.lock().unwrap().insert(Connection {
+        name: String::from("primary"),
+    });
+
+    {
+        let mut handles = handles.lock().unwrap();
+        handles.remove(&handle).unwrap();
+
+        // Reusing the slot doesn't make the old handle valid again.
+        handles.insert(Connection {
+            name: String::from("secondary"),
+        });
+    }
+
+    let (error, _) = run::<_, _, String>(
+        &context,
+        r#"pub fn main(conn) { name(conn) }"#,
+        &["main"],
+        (handle,),
+    )
+    .unwrap_err()
+    .expect_vm_error("expected vm error")
+    .into_unwound();
+
+    assert!(
+        matches!(error.kind(), StaleHandle { index: 0 }),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,320 @@
+use rune_tests::*;
+use runestick::{Context, Generator, GeneratorState, Hash, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_replace_function() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn value() { "old" }
+        pub fn main() { value() }
+        fn values() { yield value(); yield value(); }
+        pub fn gen() { values() }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        pub fn value() { `${"n"}ew` }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let output: String = vm.clone().call(&["main"], ()).unwrap();
+    assert_eq!(output, "old");
+
+    let mut generator: Generator = vm.clone().call(&["gen"], ()).unwrap();
+
+    let value = generator.next().unwrap().unwrap();
+    assert_eq!(String::from_value(value).unwrap(), "old");
+
+    vm.replace_function(&["value"], &new).unwrap();
+
+    let output: String = vm.clone().call(&["main"], ()).unwrap();
+    assert_eq!(output, "new");
+
+    // NB: the generator started before the swap keeps running on the old unit.
+    let value = generator.next().unwrap().unwrap();
+    assert_eq!(String::from_value(value).unwrap(), "old");
+}
+
+#[test]
+fn test_replace_function_signature_mismatch() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(&context, r#"pub fn value() { 1 }"#).unwrap();
+    let (new, _) = compile_source(&context, r#"pub fn value(a) { a }"#).unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let error = vm.replace_function(&["value"], &new).unwrap_err();
+    assert!(matches!(
+        error.into_kind(),
+        FunctionSignatureMismatch { hash } if hash == Hash::type_hash(&["value"])
+    ));
+
+    let error = vm.replace_function(&["missing"], &new).unwrap_err();
+    assert!(matches!(error.into_kind(), MissingFunction { .. }));
+}
+
+#[test]
+fn test_replace_function_field_assign() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn update(o) { o }
+        pub fn main() { let o = #{x: 0, y: 0}; update(o); (o.x, o.y) }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) =
+        compile_source(&context, r#"pub fn update(o) { o.y += 2; o.x = 1; o }"#).unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    vm.replace_function(&["update"], &new).unwrap();
+
+    let output: (i64, i64) = vm.call(&["main"], ()).unwrap();
+    assert_eq!(output, (1, 2));
+}
+
+#[test]
+fn test_replace_function_closure() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn apply(n) { n }
+        pub fn main() { apply(10) }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        pub fn apply(n) {
+            let add = 1;
+            let inc = |v| { let nested = |v| v + add; nested(v) };
+            let double = |v| v * 2;
+            double(inc(n)) + 1
+        }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    vm.replace_function(&["apply"], &new).unwrap();
+
+    let output: i64 = vm.call(&["main"], ()).unwrap();
+    assert_eq!(output, 23);
+}
+
+#[test]
+fn test_replace_function_changed_closure() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn apply(n) { let c = |v| v + 1; c(n) }
+        pub fn main() { apply(3) }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        pub fn apply(n) { let c = |v| v * 100; c(n) }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let output: i64 = vm.clone().call(&["main"], ()).unwrap();
+    assert_eq!(output, 4);
+
+    vm.replace_function(&["apply"], &new).unwrap();
+
+    let output: i64 = vm.call(&["main"], ()).unwrap();
+    assert_eq!(output, 300);
+}
+
+#[test]
+fn test_replace_function_changed_captures() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn apply(n) { let a = 1; let c = |v| v + a; c(n) }
+        pub fn main() { apply(3) }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        pub fn apply(n) { let a = 10; let b = 20; let c = |v| v * a + b; c(n) }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let output: i64 = vm.clone().call(&["main"], ()).unwrap();
+    assert_eq!(output, 4);
+
+    vm.replace_function(&["apply"], &new).unwrap();
+
+    let output: i64 = vm.call(&["main"], ()).unwrap();
+    assert_eq!(output, 50);
+}
+
+#[test]
+fn test_replace_function_clears_inline_cache() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        struct Foo;
+        impl Foo { fn value(self) { "old" } }
+        pub fn main() { Foo.value() }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        struct Foo;
+        impl Foo { fn value(self) { "new" } }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    // NB: step through, since completing the execution drops the vm.
+    let output = loop {
+        if let Some(output) = execution.step().unwrap() {
+            break output;
+        }
+    };
+
+    assert_eq!(String::from_value(output).unwrap(), "old");
+
+    let mut vm = execution.vm().unwrap().clone();
+    assert_eq!(vm.inline_cache().misses(), 1);
+
+    vm.replace_function(&["Foo", "value"], &new).unwrap();
+    assert_eq!(vm.inline_cache().misses(), 0);
+
+    let output: String = vm.call(&["main"], ()).unwrap();
+    assert_eq!(output, "new");
+}
+
+#[test]
+fn test_replace_function_reuses_body() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn value() { 1 }
+        pub fn main() { value() }
+        "#,
+    )
+    .unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let instructions = vm.unit().iter_instructions().count();
+    let functions = vm.unit().debug_info().unwrap().functions_rev.len();
+
+    for n in 2..5 {
+        let (new, _) = compile_source(&context, &format!("pub fn value() {{ {} }}", n)).unwrap();
+        vm.replace_function(&["value"], &new).unwrap();
+
+        let output: i64 = vm.clone().call(&["main"], ()).unwrap();
+        assert_eq!(output, n);
+    }
+
+    // NB: the new bodies fit where the old one was.
+    assert_eq!(vm.unit().iter_instructions().count(), instructions);
+    assert_eq!(
+        vm.unit().debug_info().unwrap().functions_rev.len(),
+        functions
+    );
+
+    // A larger body doesn't fit, so it's appended and the old entry is removed.
+    let (new, _) = compile_source(&context, r#"pub fn value() { let a = 2; a * 3 }"#).unwrap();
+    vm.replace_function(&["value"], &new).unwrap();
+
+    let output: i64 = vm.clone().call(&["main"], ()).unwrap();
+    assert_eq!(output, 6);
+    assert!(vm.unit().iter_instructions().count() > instructions);
+    assert_eq!(
+        vm.unit().debug_info().unwrap().functions_rev.len(),
+        functions
+    );
+}
+
+#[test]
+fn test_replace_executing_function() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn value() { "old" }
+        pub fn main() { yield value(); yield value(); }
+        "#,
+    )
+    .unwrap();
+
+    let (new, _) = compile_source(
+        &context,
+        r#"
+        pub fn value() { "new" }
+        pub fn main() { yield "replaced"; }
+        "#,
+    )
+    .unwrap();
+
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    match execution.resume().unwrap() {
+        GeneratorState::Yielded(value) => assert_eq!(String::from_value(value).unwrap(), "old"),
+        state => panic!("unexpected state: {:?}", state),
+    }
+
+    // NB: `main` is executing, so it finishes on its old body.
+    let vm = execution.vm_mut().unwrap();
+    vm.replace_function(&["value"], &new).unwrap();
+    vm.replace_function(&["main"], &new).unwrap();
+
+    // NB: resuming past a yield expects a value on the stack, like
+    // `Generator::resume` provides.
+    vm.stack_mut().push(());
+
+    match execution.resume().unwrap() {
+        GeneratorState::Yielded(value) => assert_eq!(String::from_value(value).unwrap(), "new"),
+        state => panic!("unexpected state: {:?}", state),
+    }
+}
@@ -0,0 +1,83 @@
+use rune_tests::*;
+
+#[test]
+fn test_index_field_mutation() {
+    let out: (i64, i64, i64) = rune! { (i64, i64, i64) =>
+        struct Point { x }
+
+        pub fn main() {
+            let v = [#{ x: 1 }, Point { x: 2 }];
+            v[0].x = 10;
+            v[1].x += 5;
+
+            let o = #{ inner: [#{ x: 3 }] };
+            o["inner"][0].x = 30;
+
+            (v[0].x, v[1].x, o.inner[0].x)
+        }
+    };
+
+    assert_eq!(out, (10, 7, 30));
+}
+
+#[test]
+fn test_index_assign_op() {
+    let out: (i64, i64, i64, i64) = rune! { (i64, i64, i64, i64) =>
+        pub fn main() {
+            let v = [1, [2], (3,)];
+            let o = #{ a: 4 };
+            let key = "a";
+
+            v[0] += 1;
+            v[1][0] *= { let n = 10; n };
+            v[2][0] -= 1;
+            o[key] += 1;
+
+            (v[0], v[1][0], v[2][0], o.a)
+        }
+    };
+
+    assert_eq!(out, (2, 20, 2, 5));
+}
+
+#[test]
+fn test_index_assign_op_protocol() {
+    let out: (i64, i64, i64) = rune! { (i64, i64, i64) =>
+        use std::collections::{HashMap, VecDeque};
+
+        pub fn main() {
+            let m = HashMap::new();
+            m["a"] = 1;
+            m["b"] = 0x0f;
+
+            m["a"] += 41;
+            m["b"] &= 0x3c;
+
+            let d = VecDeque::new();
+            d.push_back(10);
+            d[0] *= 2;
+
+            (m["a"], d[0], m["b"])
+        }
+    };
+
+    assert_eq!(out, (42, 20, 0x0c));
+}
+
+#[test]
+fn test_index_assign_op_errors() {
+    assert_vm_error!(
+        "pub fn main() { let v = [1]; v[1] += 1; }",
+        MissingIndex { .. } => {}
+    );
+
+    assert_vm_error!(
+        "pub fn main() { let v = [1]; v[0] += v; }",
+        UnsupportedBinaryOperation { .. } => {}
+    );
+
+    assert_vm_error!(
+        "pub fn main() { let m = std::collections::HashMap::new(); m[1] += 1; }",
+        MissingIndexKey { .. } => {}
+    );
+}
@@ -0,0 +1,86 @@
+use rune_tests::*;
+use runestick::{Any, Hash, Module, Protocol};
+
+#[derive(Any)]
+struct Buffer {
+    data: Vec<u8>,
+}
+
+impl Buffer {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+fn new_buffer(len: usize) -> Buffer {
+    Buffer { data: vec![0; len] }
+}
+
+#[test]
+fn test_len_protocol() {
+    assert_eq!(Protocol::LEN.hash, Hash::instance_fn_name("len"));
+
+    let out: (usize, usize, usize, usize, usize) = rune! { (usize, usize, usize, usize, usize) =>
+        pub fn main() {
+            let string = String::from_str("abc");
+            ([1, 2].len(), #{ a: 1 }.len(), "hello".len(), string.len(), b"ab".len())
+        }
+    };
+
+    assert_eq!(out, (2, 1, 5, 3, 2));
+}
+
+#[test]
+fn test_len_protocol_any() {
+    let mut module = Module::new();
+    module.ty::<Buffer>().unwrap();
+    module.function(&["new_buffer"], new_buffer).unwrap();
+    module.inst_fn(Protocol::LEN, Buffer::len).unwrap();
+
+    let out: usize = rune_n! {
+        module,
+        (),
+        usize => pub fn main() { new_buffer(4).len() }
+    };
+
+    assert_eq!(out, 4);
+}
+
+#[test]
+fn test_len_unsupported() {
+    assert_vm_error!(
+        "pub fn main() { 42.len() }",
+        MissingInstanceFunction { .. } => {}
+    );
+}
+
+#[test]
+fn test_is_empty_derived_from_len() {
+    assert_eq!(Protocol::IS_EMPTY.hash, Hash::instance_fn_name("is_empty"));
+
+    let out: (bool, bool, bool, bool, bool) = rune! { (bool, bool, bool, bool, bool) =>
+        pub fn main() {
+            ("".is_empty(), [].is_empty(), [1].is_empty(), #{}.is_empty(), b"a".is_empty())
+        }
+    };
+
+    assert_eq!(out, (true, true, false, true, false));
+
+    let mut module = Module::new();
+    module.ty::<Buffer>().unwrap();
+    module.function(&["new_buffer"], new_buffer).unwrap();
+    module.inst_fn(Protocol::LEN, Buffer::len).unwrap();
+
+    let out: (bool, bool) = rune_n! {
+        module,
+        (),
+        (bool, bool) => pub fn main() { (new_buffer(0).is_empty(), new_buffer(2).is_empty()) }
+    };
+
+    assert_eq!(out, (true, false));
+
+    assert_vm_error!(
+        "pub fn main() { 42.is_empty() }",
+        MissingInstanceFunction { .. } => {}
+    );
+}
@@ -0,0 +1,40 @@
+use rune_tests::allocations::count_allocations;
+use rune_tests::*;
+use runestick::{Context, Vm};
+use std::sync::Arc;
+
+/// Count the allocations performed when calling `main` in the given source.
+fn allocations(source: &str) -> usize {
+    let context = Arc::new(Context::with_default_modules().unwrap());
+    let (unit, _) = compile_source(&context, source).unwrap();
+    // NB: the stack has room for all literal elements, so it doesn't have to
+    // grow while they're being pushed.
+    let vm = Vm::with_stack_capacity(Arc::new(context.runtime()), Arc::new(unit), 64);
+
+    let (_, count) = count_allocations(|| {
+        let mut execution = vm.execute(&["main"], ()).unwrap();
+        execution.complete().unwrap()
+    });
+
+    count
+}
+
+#[test]
+fn test_vec_literal_capacity() {
+    // NB: the backing storage of a literal is allocated once, so its size
+    // doesn't affect the number of allocations.
+    assert_eq!(
+        allocations("pub fn main() { [0, 1, 2, 3, 4, 5, 6, 7, 8, 9] }"),
+        allocations("pub fn main() { [0] }"),
+    );
+
+    assert_eq!(
+        allocations("pub fn main() { (0, 1, 2, 3, 4, 5, 6, 7, 8, 9) }"),
+        allocations("pub fn main() { (0,) }"),
+    );
+
+    assert_eq!(
+        allocations("pub fn main() { [0; 10] }"),
+        allocations("pub fn main() { [0; 1] }"),
+    );
+}
@@ -31,6 +31,9 @@ fn test_literals() {
     assert_eq!(rune!(f64 => pub fn main() { -42.42 }), -42.42);
     assert_eq!(rune!(f64 => pub fn main() { 1.9e10 }), 1.9e10);
     assert_eq!(rune!(f64 => pub fn main() { 1e10 }), 1e10);
+    assert_eq!(rune!(f64 => pub fn main() { 1.5e-3 }), 1.5e-3);
+    assert_eq!(rune!(f64 => pub fn main() { 2.5E+2 }), 2.5E+2);
+    assert_eq!(rune!(i64 => pub fn main() { 0x1e }), 0x1e);
 }
 
 #[test]
@@ -78,3 +81,16 @@ fn test_byte_string_literals() {
         b"a b"[..]
     );
 }
+
+#[test]
+fn test_raw_string_literals() {
+    assert_eq!(
+        rune_s!(String => r#"pub fn main() { r"C:\foo\n" }"#),
+        "C:\\foo\\n"
+    );
+
+    assert_eq!(
+        rune_s!(String => r###"pub fn main() { r##"say "#hi"#"## }"###),
+        "say \"#hi\"#"
+    );
+}
@@ -97,3 +97,46 @@ fn test_struct_matching() {
         3,
     };
 }
+
+#[test]
+fn test_literal_matching() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) =>
+            fn string(s) {
+                match s {
+                    "yes" => 1,
+                    "no" => 2,
+                    _ => 3,
+                }
+            }
+
+            fn bytes(b) {
+                match b {
+                    b"yes" => 1,
+                    b"no" => 2,
+                    _ => 3,
+                }
+            }
+
+            fn chars(c) {
+                match c {
+                    'y' => 1,
+                    'n' => 2,
+                    _ => 3,
+                }
+            }
+
+            pub fn main() {
+                let dynamic = String::from_str("no");
+
+                (
+                    [string("yes"), string(dynamic), string("maybe")],
+                    [bytes(b"yes"), bytes(b"no"), bytes(b"maybe")],
+                    [chars('y'), chars('n'), chars('m')],
+                    [string(b"yes"), bytes("yes"), chars("y")],
+                )
+            }
+        },
+        (vec![1, 2, 3], vec![1, 2, 3], vec![1, 2, 3], vec![3, 3, 3]),
+    };
+}
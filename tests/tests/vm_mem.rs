@@ -0,0 +1,50 @@
+use rune_tests::*;
+
+#[test]
+fn test_mem_take() {
+    let out: (i64, bool, i64, bool) = rune! { (i64, bool, i64, bool) =>
+        pub fn main() {
+            let object = #{ field: 42 };
+            let field = std::mem::take(object, "field");
+
+            let vec = [1, 2, 3];
+            let element = std::mem::take(vec, 1);
+
+            (field, object.field is unit, element, vec[1] is unit)
+        }
+    };
+
+    assert_eq!(out, (42, true, 2, true));
+}
+
+#[test]
+fn test_mem_replace() {
+    let out: (i64, i64, i64, i64) = rune! { (i64, i64, i64, i64) =>
+        pub fn main() {
+            let object = #{ field: 1 };
+            let old = std::mem::replace(object, "field", 2);
+
+            let vec = [3];
+            let element = std::mem::replace(vec, 0, 4);
+
+            (old, object.field, element, vec[0])
+        }
+    };
+
+    assert_eq!(out, (1, 2, 3, 4));
+}
+
+#[test]
+fn test_mem_take_missing() {
+    assert_vm_error!(
+        r#"pub fn main() { std::mem::take(#{}, "missing") }"#,
+        MissingField { field, .. } => {
+            assert_eq!(field, "missing");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { std::mem::take([1], 1) }"#,
+        OutOfRange { .. } => {}
+    );
+}
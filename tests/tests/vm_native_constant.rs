@@ -0,0 +1,55 @@
+use rune_tests::*;
+use runestick::{ContextError, Module};
+
+fn make_native_module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate("app");
+    module.constant(&["VERSION"], String::from("1.2.3"))?;
+    module.constant(&["MATH_PI"], std::f64::consts::PI)?;
+    Ok(module)
+}
+
+#[test]
+fn test_native_constant() {
+    let output = rune_n! {
+        make_native_module().expect("failed making native module"),
+        (),
+        (String, f64) => pub fn main() {
+            (app::VERSION, app::MATH_PI * 2.0)
+        }
+    };
+
+    assert_eq!(output, (String::from("1.2.3"), std::f64::consts::PI * 2.0));
+}
+
+#[test]
+fn test_native_constant_conflicts() {
+    let mut module = Module::new();
+    module.function(&["value"], || 42i64).unwrap();
+
+    assert!(matches!(
+        module.constant(&["value"], 42i64),
+        Err(ContextError::ConflictingConstantName { .. })
+    ));
+
+    let mut module = Module::new();
+    module.constant(&["value"], 42i64).unwrap();
+
+    assert!(matches!(
+        module.function(&["value"], || 42i64),
+        Err(ContextError::ConflictingFunctionName { .. })
+    ));
+
+    let mut a = Module::new();
+    a.function(&["value"], || 42i64).unwrap();
+
+    let mut b = Module::new();
+    b.constant(&["value"], 42i64).unwrap();
+
+    let mut context = runestick::Context::new();
+    context.install(&a).unwrap();
+
+    assert!(matches!(
+        context.install(&b),
+        Err(ContextError::ConflictingConstantName { .. })
+    ));
+}
@@ -0,0 +1,115 @@
+use rune_tests::*;
+use runestick::{Any, Module, Protocol, Vm, VmErrorKind};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+#[derive(Debug, Any)]
+struct ConfigError {
+    key: String,
+    source: std::num::ParseIntError,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bad config value for `{}`", self.key)
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn parse_config(key: &str, value: &str) -> Result<i64, ConfigError> {
+    value.parse().map_err(|source| ConfigError {
+        key: key.to_owned(),
+        source,
+    })
+}
+
+fn config(key: &str, value: &str) -> Result<i64, Box<dyn Error + Send + Sync>> {
+    Ok(parse_config(key, value)?)
+}
+
+#[test]
+fn test_native_error() {
+    let mut module = Module::new();
+    module.function(&["config"], config).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        pub fn main(value) { config("port", value) + 1 }
+        "#,
+    )
+    .unwrap();
+
+    let unit = Arc::new(unit);
+    let runtime = Arc::new(context.runtime());
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let output = vm
+        .execute(&["main"], (String::from("8080"),))
+        .unwrap()
+        .complete()
+        .unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 8081);
+
+    let vm = Vm::new(runtime, unit);
+    let error = vm
+        .execute(&["main"], (String::from("not a port"),))
+        .unwrap()
+        .complete()
+        .unwrap_err();
+
+    let error = error.into_unwound().0;
+    assert_eq!(error.to_string(), "bad config value for `port`");
+
+    let source = error.source().unwrap();
+    assert_eq!(source.to_string(), "invalid digit found in string");
+
+    match error.into_kind() {
+        VmErrorKind::Custom { error } => assert!(error.is::<ConfigError>()),
+        actual => panic!("unexpected error: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_native_error_value() {
+    let mut module = Module::new();
+    module.ty::<ConfigError>().unwrap();
+    module.function(&["parse_config"], parse_config).unwrap();
+    module
+        .inst_fn(
+            Protocol::STRING_DISPLAY,
+            |error: &ConfigError, buf: &mut String| write!(buf, "{}", error),
+        )
+        .unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+    let context = Arc::new(context);
+
+    // NB: errors which aren't boxed are passed to the script as values, so
+    // their message can be inspected there.
+    let source = r#"
+    pub fn main(value) {
+        match parse_config("port", value) {
+            Ok(port) => `port ${port}`,
+            Err(error) => `error: ${error}`,
+        }
+    }
+    "#;
+
+    let output: String = run(&context, source, &["main"], (String::from("8080"),)).unwrap();
+    assert_eq!(output, "port 8080");
+
+    let output: String = run(&context, source, &["main"], (String::from("x"),)).unwrap();
+    assert_eq!(output, "error: bad config value for `port`");
+}
@@ -0,0 +1,96 @@
+use rune_tests::*;
+use runestick::{Module, Vm};
+use std::sync::Arc;
+
+fn explode(value: i64) -> i64 {
+    if value > 1 {
+        panic!("value {} is too large", value);
+    }
+
+    value
+}
+
+fn explode_static() {
+    panic!("static message");
+}
+
+#[test]
+fn test_catch_native_panics() {
+    let mut module = Module::new();
+    module.function(&["explode"], explode).unwrap();
+    module
+        .function(&["explode_static"], explode_static)
+        .unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        pub fn main(n) { explode(n) }
+        pub fn main_static() { explode_static() }
+        "#,
+    )
+    .unwrap();
+
+    let unit = Arc::new(unit);
+
+    let mut runtime = context.runtime();
+    runtime.set_catch_native_panics(true);
+    let runtime = Arc::new(runtime);
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let output = vm.execute(&["main"], (1i64,)).unwrap().complete().unwrap();
+    assert_eq!(i64::from_value(output).unwrap(), 1);
+
+    let vm = Vm::new(runtime.clone(), unit.clone());
+    let error = vm
+        .execute(&["main"], (2i64,))
+        .unwrap()
+        .complete()
+        .unwrap_err();
+
+    match error.into_unwound().0.into_kind() {
+        NativePanic { message } => assert_eq!(message, "value 2 is too large"),
+        actual => panic!("unexpected error: {:?}", actual),
+    }
+
+    let vm = Vm::new(runtime, unit);
+    let error = vm
+        .execute(&["main_static"], ())
+        .unwrap()
+        .complete()
+        .unwrap_err();
+
+    match error.into_unwound().0.into_kind() {
+        NativePanic { message } => assert_eq!(message, "static message"),
+        actual => panic!("unexpected error: {:?}", actual),
+    }
+}
+
+#[test]
+fn test_catch_native_panics_function_pointer() {
+    let mut module = Module::new();
+    module.function(&["explode"], explode).unwrap();
+
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let (unit, _) = compile_source(&context, r#"pub fn main() { explode }"#).unwrap();
+
+    let mut runtime = context.runtime();
+    runtime.set_catch_native_panics(true);
+
+    let vm = Vm::new(Arc::new(runtime), Arc::new(unit));
+    let output = vm.execute(&["main"], ()).unwrap().complete().unwrap();
+    let function = Function::from_value(output).unwrap();
+
+    assert_eq!(function.call::<_, i64>((1i64,)).unwrap(), 1);
+
+    // NB: the function is called outside of the virtual machine.
+    match function.call::<_, i64>((2i64,)).unwrap_err().into_kind() {
+        NativePanic { message } => assert_eq!(message, "value 2 is too large"),
+        actual => panic!("unexpected error: {:?}", actual),
+    }
+}
@@ -0,0 +1,9 @@
+use rune_tests::*;
+
+#[test]
+fn test_number_separators() {
+    assert_eq!(rune!(i64 => pub fn main() { 1_000_000 }), 1_000_000);
+    assert_eq!(rune!(i64 => pub fn main() { 0b1010_1010 }), 0b1010_1010);
+    assert_eq!(rune!(i64 => pub fn main() { 0xff_ff }), 0xff_ff);
+    assert_eq!(rune!(f64 => pub fn main() { 1_000.5 }), 1_000.5);
+}
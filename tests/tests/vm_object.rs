@@ -0,0 +1,94 @@
+use rune_tests::*;
+
+#[test]
+fn test_object_extend() {
+    let values = rune! { (i64, i64, i64, usize) =>
+        pub fn main() {
+            let a = #{a: 1, b: 2};
+            let b = #{b: 20, c: 30};
+            a.extend(b);
+            (a.a, a.b, a.c, b.len())
+        }
+    };
+
+    assert_eq!(values, (1, 20, 30, 2));
+
+    assert_vm_error!(
+        r#"pub fn main() { let a = #{}; a.extend([1, 2]) }"#,
+        BadArgument { error, arg } => {
+            assert_eq!(arg, 1);
+            assert_eq!(error.to_string(), "expected `Object`, but found `Vec`");
+        }
+    );
+}
+
+#[test]
+fn test_object_merge() {
+    let values = rune! { (i64, i64, i64, usize, usize) =>
+        pub fn main() {
+            let a = #{a: 1, b: 2};
+            let b = #{b: 20, c: 30};
+            let c = std::object::merge(a, b);
+            (c.a, c.b, c.c, a.len(), b.len())
+        }
+    };
+
+    assert_eq!(values, (1, 20, 30, 2, 2));
+
+    let values = rune! { (i64, i64) =>
+        pub fn main() {
+            let a = #{a: 1, b: 2};
+            let c = std::object::merge(a, a);
+            (c.a, c.b)
+        }
+    };
+
+    assert_eq!(values, (1, 2));
+}
+
+#[test]
+fn test_object_iteration_order() {
+    // NB: objects are ordered by key, so iterating over them is deterministic
+    // regardless of the order in which keys were inserted.
+    let values = rune! { Vec<(String, i64)> =>
+        pub fn main() {
+            let object = #{zeta: 1, alpha: 2, mu: 3};
+            object.beta = 4;
+            object["omega"] = 5;
+
+            let out = [];
+
+            for (key, value) in object {
+                out.push((key, value));
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(
+        values,
+        vec![
+            (String::from("alpha"), 2),
+            (String::from("beta"), 4),
+            (String::from("mu"), 3),
+            (String::from("omega"), 5),
+            (String::from("zeta"), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_object_literal_in_condition() {
+    let values = rune! { (i64, bool) =>
+        struct Foo { a }
+
+        pub fn main() {
+            let a = match Foo { a: 1 } { Foo { a } => a };
+            let b = if Foo { a: 2 }.a == 2 { true } else { false };
+            (a, b)
+        }
+    };
+
+    assert_eq!(values, (1, true));
+}
@@ -47,3 +47,88 @@ fn test_match_binding() {
         true,
     };
 }
+
+#[test]
+fn test_negative_number_patterns() {
+    assert_eq! {
+        rune! { Vec<i64> =>
+            fn test(n) {
+                match n {
+                    -1 => 1,
+                    1 => 2,
+                    -2.5 => 3,
+                    2.5 => 4,
+                    _ => 5,
+                }
+            }
+
+            pub fn main() {
+                [test(-1), test(1), test(-2.5), test(2.5), test(-2), test(!1)]
+            }
+        },
+        vec![1, 2, 3, 4, 5, 5],
+    };
+}
+
+#[test]
+fn test_range_patterns() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>, Vec<i64>) =>
+            fn test(n) {
+                match n {
+                    -10..0 => 1,
+                    0..=5 => 2,
+                    0.5..1.5 => 3,
+                    10.. => 4,
+                    _ => 5,
+                }
+            }
+
+            pub fn main() {
+                (
+                    [test(-10), test(-1)],
+                    [test(0), test(5)],
+                    [test(1.0), test(1.5)],
+                    [test(10), test(6), test("10")],
+                )
+            }
+        },
+        (vec![1, 1], vec![2, 2], vec![3, 5], vec![4, 5, 5]),
+    };
+}
+
+#[test]
+fn test_binding_patterns() {
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>, Vec<i64>) =>
+            fn test(value) {
+                match value {
+                    n @ 1..=10 => n * 2,
+                    Some(n @ 1..=10) => n * 3,
+                    [a @ 1..=2, b @ _] => a + b,
+                    _ => 0,
+                }
+            }
+
+            pub fn main() {
+                (
+                    [test(5), test(11)],
+                    [test(Some(4)), test(Some(0))],
+                    [test([2, 40]), test([3, 40])],
+                )
+            }
+        },
+        (vec![10, 0], vec![12, 0], vec![42, 0]),
+    };
+
+    assert_eq! {
+        rune! { (i64, i64, i64, i64) =>
+            pub fn main() {
+                let n @ (a, b) = (1, 2);
+                let (c, d) = n;
+                (a, b, c, d)
+            }
+        },
+        (1, 2, 1, 2),
+    };
+}
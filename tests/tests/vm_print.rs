@@ -0,0 +1,100 @@
+use rune_tests::*;
+use runestick::{Any, Module, Protocol, Vm};
+use std::fmt;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+#[derive(Any)]
+struct Greeting {
+    name: String,
+}
+
+impl Greeting {
+    fn string_display(&self, buf: &mut String) -> fmt::Result {
+        write!(buf, "Hello, {}!", self.name)
+    }
+}
+
+#[derive(Any)]
+struct Broken;
+
+impl Broken {
+    fn string_display(&self, _: &mut String) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+fn make_module() -> Module {
+    let mut module = Module::new();
+    module.ty::<Greeting>().unwrap();
+    module.ty::<Broken>().unwrap();
+    module
+        .function(&["greeting"], |name: String| Greeting { name })
+        .unwrap();
+    module.function(&["broken"], || Broken).unwrap();
+    module
+        .inst_fn(Protocol::STRING_DISPLAY, Greeting::string_display)
+        .unwrap();
+    module
+        .inst_fn(Protocol::STRING_DISPLAY, Broken::string_display)
+        .unwrap();
+    module
+}
+
+#[test]
+fn test_print_display() {
+    let mut context = runestick::Context::with_config(false).unwrap();
+    context
+        .install(&capture_output::output_redirect_module().unwrap())
+        .unwrap();
+    context.install(&make_module()).unwrap();
+
+    let () = run(
+        &Arc::new(context),
+        r#"
+        pub fn main() {
+            let greeting = greeting("World");
+            print(greeting);
+            println(greeting);
+            println(42);
+            dbg([1, 2], "three")
+        }
+        "#,
+        &["main"],
+        (),
+    )
+    .unwrap();
+
+    assert_eq!(
+        capture_output::drain_output().unwrap(),
+        "Hello, World!Hello, World!\n42\n[1, 2]\n\"three\"\n"
+    );
+}
+
+#[test]
+fn test_print_display_error() {
+    let mut context = runestick::Context::with_default_modules().unwrap();
+    context.install(&make_module()).unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        pub fn print_broken() { print(broken()) }
+        pub fn println_broken() { println(broken()) }
+        "#,
+    )
+    .unwrap();
+
+    let unit = Arc::new(unit);
+    let runtime = Arc::new(context.runtime());
+
+    for name in &["print_broken", "println_broken"] {
+        let vm = Vm::new(runtime.clone(), unit.clone());
+        let error = vm.execute(&[*name], ()).unwrap().complete().unwrap_err();
+
+        match error.into_unwound().0.into_kind() {
+            FormatError => (),
+            actual => panic!("unexpected error: {:?}", actual),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use rune_tests::*;
+use runestick::{Context, Module, NativeStep, Stack, Vm, VmError};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+thread_local!(static CALLS: Cell<usize> = const { Cell::new(0) });
+
+/// Sum the numbers up to and including the argument, yielding after every 10
+/// numbers.
+fn sum(stack: &mut Stack, args: usize, state: &mut Option<Value>) -> Result<NativeStep, VmError> {
+    CALLS.with(|calls| calls.set(calls.get() + 1));
+    assert_eq!(args, 1);
+
+    let n = i64::from_value(stack.last()?.clone())?;
+
+    let (mut i, mut acc) = match state.take() {
+        Some(state) => <(i64, i64)>::from_value(state)?,
+        None => (0, 0),
+    };
+
+    for _ in 0..10 {
+        if i == n {
+            stack.pop()?;
+            stack.push(acc);
+            return Ok(NativeStep::Complete);
+        }
+
+        i += 1;
+        acc += i;
+    }
+
+    *state = Some((i, acc).to_value()?);
+    Ok(NativeStep::Yield)
+}
+
+fn vm(source: &str) -> Vm {
+    let mut module = Module::default();
+    module.resumable_fn(&["sum"], sum).unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+    Vm::new(Arc::new(context.runtime()), Arc::new(unit))
+}
+
+#[test]
+fn test_resumable_native() {
+    let vm = vm(r#"pub fn main() { sum(100) + sum(5) }"#);
+
+    let output: i64 = FromValue::from_value(vm.clone().call(&["main"], ()).unwrap()).unwrap();
+    assert_eq!(output, 5050 + 15);
+
+    CALLS.with(|calls| calls.set(0));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+    let mut steps = 0;
+
+    let output = loop {
+        steps += 1;
+
+        if let Some(output) = execution.step().unwrap() {
+            break output;
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 5050 + 15);
+    // Every call to `sum` is a step of its own.
+    assert_eq!(CALLS.with(Cell::get), 11 + 1);
+    assert!(steps > 12, "{}", steps);
+}
+
+#[test]
+fn test_resumable_native_function_pointer() {
+    let vm = vm(r#"pub fn main() { let f = sum; f(100) }"#);
+
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    let output = loop {
+        if let Some(output) = execution.step().unwrap() {
+            break output;
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 5050);
+}
+
+#[test]
+fn test_resumable_native_closure() {
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut module = Module::default();
+
+    module
+        .resumable_fn(&["count"], {
+            let calls = calls.clone();
+
+            move |stack: &mut Stack, args, state: &mut Option<Value>| {
+                assert_eq!(args, 0);
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                if state.take().is_none() {
+                    *state = Some(Value::Unit);
+                    return Ok(NativeStep::Yield);
+                }
+
+                stack.push(calls.load(Ordering::SeqCst) as i64);
+                Ok(NativeStep::Complete)
+            }
+        })
+        .unwrap();
+
+    let mut context = Context::with_default_modules().unwrap();
+    context.install(&module).unwrap();
+
+    let (unit, _) = compile_source(&context, r#"pub fn main() { count() }"#).unwrap();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let output = i64::from_value(vm.call(&["main"], ()).unwrap()).unwrap();
+    assert_eq!(output, 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,112 @@
+use rune_tests::*;
+use runestick::{Context, Module, Stack, Vm, VmError};
+use std::sync::Arc;
+
+/// Run the given virtual machine to completion one instruction at a time,
+/// collecting every distinct stack capacity observed along the way.
+fn capacities(vm: Vm) -> (i64, Vec<usize>) {
+    let mut execution = vm.execute(&["main"], (10,)).unwrap();
+    let mut capacities = vec![execution.vm().unwrap().stack().capacity()];
+
+    let output = loop {
+        let output = execution.step().unwrap();
+        let capacity = execution.vm().unwrap().stack().capacity();
+
+        if capacities.last() != Some(&capacity) {
+            capacities.push(capacity);
+        }
+
+        if let Some(output) = output {
+            break output;
+        }
+    };
+
+    (i64::from_value(output).unwrap(), capacities)
+}
+
+#[test]
+fn test_with_stack_capacity() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = compile_source(
+        &context,
+        r#"
+        fn fib(n) {
+            if n <= 1 {
+                n
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        }
+
+        pub fn main(n) {
+            fib(n)
+        }
+        "#,
+    )
+    .unwrap();
+
+    let context = Arc::new(context.runtime());
+    let unit = Arc::new(unit);
+
+    let (output, grown) = capacities(Vm::new(context.clone(), unit.clone()));
+    assert_eq!(output, 55);
+    assert!(grown.len() > 1, "default stack should grow");
+
+    let (output, reserved) = capacities(Vm::with_stack_capacity(context, unit, 1024));
+    assert_eq!(output, 55);
+    assert_eq!(reserved, vec![1024], "reserved stack should not grow");
+}
+
+fn integers(values: Vec<Value>) -> Vec<i64> {
+    values
+        .into_iter()
+        .map(|v| i64::from_value(v).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_pop_n() {
+    let mut stack = Stack::new();
+    stack.push_iter(vec![1i64, 2, 3, 4]);
+
+    assert_eq!(integers(stack.pop_n(3).unwrap()), vec![2, 3, 4]);
+    assert_eq!(stack.len(), 1);
+
+    assert!(stack.pop_n(2).is_err());
+    assert_eq!(stack.len(), 1);
+
+    assert_eq!(integers(stack.pop_n(0).unwrap()), Vec::<i64>::new());
+    assert_eq!(integers(stack.pop_n(1).unwrap()), vec![1]);
+    assert!(stack.is_empty());
+}
+
+/// Raw function which sums its arguments, after checking that it can't pop
+/// values from outside of the current stack frame.
+fn sum(stack: &mut Stack, args: usize) -> Result<(), VmError> {
+    let len = stack.len();
+    let available = len - stack.stack_bottom();
+    assert!(available < len, "expected values below the stack frame");
+    assert!(stack.pop_n(available + 1).is_err());
+    assert_eq!(stack.len(), len);
+
+    let values = integers(stack.pop_n(args)?);
+    stack.push(values.iter().sum::<i64>());
+    stack.push_iter(values);
+    stack.pop_n(args)?;
+    Ok(())
+}
+
+#[test]
+fn test_pop_n_stack_frame() {
+    let mut module = Module::new();
+    module.raw_fn(&["sum"], sum).unwrap();
+
+    let output = rune_n! {
+        module,
+        (),
+        i64 => fn inner(a) { sum(a, 2, 3) } pub fn main() { let x = 10; inner(1) + x }
+    };
+
+    assert_eq!(output, 16);
+}
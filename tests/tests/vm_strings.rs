@@ -0,0 +1,44 @@
+use rune_tests::*;
+
+#[test]
+fn test_string_len() {
+    assert_eq!(rune!(usize => pub fn main() { "hello".len() }), 5);
+    assert_eq!(rune!(usize => pub fn main() { "héllo".len() }), 6);
+    assert_eq!(rune!(usize => pub fn main() { "héllo".char_len() }), 5);
+    assert_eq!(rune!(usize => pub fn main() { "héllo".chars().count() }), 5);
+    assert_eq!(rune!(usize => pub fn main() { String::from_str("💯").len() }), 4);
+    assert_eq!(rune!(usize => pub fn main() { String::from_str("💯").char_len() }), 1);
+}
+
+#[test]
+fn test_string_slicing() {
+    assert_eq!(rune!(String => pub fn main() { "héllo"[0..1] }), "h");
+    assert_eq!(rune!(String => pub fn main() { "héllo"[0..3] }), "hé");
+    assert_eq!(rune!(String => pub fn main() { "héllo"[3..] }), "llo");
+    assert_eq!(rune!(String => pub fn main() { "héllo"[..=2] }), "hé");
+    assert_eq!(rune!(Option<String> => pub fn main() { "héllo".get(0..2) }), None);
+}
+
+#[test]
+fn test_string_slicing_not_char_boundary() {
+    assert_vm_error!(
+        r#"pub fn main() { "héllo"[0..2] }"#,
+        NotCharBoundary { offset } => {
+            assert_eq!(offset, 2);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "héllo"[2..] }"#,
+        NotCharBoundary { offset } => {
+            assert_eq!(offset, 2);
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "héllo"[..=1] }"#,
+        NotCharBoundary { offset } => {
+            assert_eq!(offset, 2);
+        }
+    );
+}
@@ -1,4 +1,5 @@
 use rune_tests::*;
+use runestick::VmExecution;
 
 #[test]
 fn test_instance_kinds() {
@@ -39,3 +40,96 @@ fn test_instance_kinds() {
         (4, 5, 6, 7),
     };
 }
+
+#[test]
+fn test_instance_fn_inline_cache() {
+    let vm = rune_vm! {
+        struct Foo;
+        struct Bar;
+
+        impl Foo {
+            fn test(self) { 1 }
+        }
+
+        impl Bar {
+            fn test(self) { 10 }
+        }
+
+        pub fn main() {
+            let values = [Foo, Foo, Bar, Bar, Foo];
+            let out = 0;
+
+            for v in values {
+                out += v.test();
+            }
+
+            out
+        }
+    };
+
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+
+    let output = loop {
+        if let Some(output) = execution.step().unwrap() {
+            break output;
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 23);
+
+    // NB: `v.test()` misses once initially and once for each of the two times
+    // the type changes. The iterator's `next` only misses on its first call.
+    let cache = execution.vm().unwrap().inline_cache();
+    assert_eq!(cache.misses(), 4);
+    assert!(cache.hits() > 0);
+}
+
+#[test]
+fn test_instance_fn_inline_cache_clear() {
+    let vm = rune_vm! {
+        struct Foo;
+        struct Bar;
+
+        impl Foo {
+            fn test(self) { 1 }
+        }
+
+        impl Bar {
+            fn test(self) { 10 }
+        }
+
+        pub fn main() {
+            let out = 0;
+
+            for v in [Foo, Bar] {
+                out += v.test();
+            }
+
+            out
+        }
+    };
+
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+    assert_eq!(i64::from_value(run_to_end(&mut execution)).unwrap(), 11);
+
+    let mut vm = execution.vm().unwrap().clone();
+    vm.inline_cache_mut().clear();
+    assert_eq!(vm.inline_cache().hits(), 0);
+    assert_eq!(vm.inline_cache().misses(), 0);
+
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+    assert_eq!(i64::from_value(run_to_end(&mut execution)).unwrap(), 11);
+
+    // NB: every call site has to be resolved again after the cache is
+    // cleared, just like on the first run.
+    let cache = execution.vm().unwrap().inline_cache();
+    assert_eq!(cache.misses(), 3);
+}
+
+fn run_to_end(execution: &mut VmExecution) -> Value {
+    loop {
+        if let Some(output) = execution.step().unwrap() {
+            break output;
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use rune::termcolor::Buffer;
+use rune::{DumpInstructions as _, Sources};
+use runestick::{Context, Vm};
+use std::sync::Arc;
+
+#[test]
+fn test_trace_resolves_operands() {
+    let context = Context::with_default_modules().unwrap();
+
+    let (unit, _) = rune_tests::compile_source(
+        &context,
+        r#"
+        fn greet(s) { s.push_str("hello world"); s }
+        pub fn main() { let s = String::new(); let o = #{a: 1}; o.a = 2; greet(s) }
+        "#,
+    )
+    .unwrap();
+
+    let sources = Sources::new();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let mut execution = vm.execute(&["main"], ()).unwrap();
+    let mut out = Buffer::no_color();
+
+    loop {
+        let vm = execution.vm().unwrap();
+
+        vm.unit()
+            .emit_instruction(&mut out, &sources, vm.ip(), false)
+            .unwrap();
+
+        if execution.step().unwrap().is_some() {
+            break;
+        }
+    }
+
+    let trace = String::from_utf8(out.into_inner()).unwrap();
+
+    assert!(trace.contains(r#"// "hello world""#), "{}", trace);
+    assert!(trace.contains("// {a}"), "{}", trace);
+    assert!(trace.contains(r#"// "a""#), "{}", trace);
+    assert!(trace.contains("// fn greet\n"), "{}", trace);
+    assert!(
+        trace.contains("// fn ::std::string::String::new\n"),
+        "{}",
+        trace
+    );
+}
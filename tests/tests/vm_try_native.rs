@@ -0,0 +1,73 @@
+use rune_tests::*;
+use runestick::{Any, ContextError, Module, Protocol};
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+#[derive(Debug, Any)]
+struct PortError {
+    #[rune(get)]
+    input: String,
+}
+
+impl PortError {
+    fn display(&self, buf: &mut String) -> std::fmt::Result {
+        write!(buf, "`{}` is not a valid port", self.input)
+    }
+}
+
+fn parse_port(input: &str) -> Result<i64, PortError> {
+    match input.parse::<u16>() {
+        Ok(port) => Ok(port as i64),
+        Err(..) => Err(PortError {
+            input: input.to_owned(),
+        }),
+    }
+}
+
+fn make_native_module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate("native_crate");
+    module.ty::<PortError>()?;
+    module.inst_fn(Protocol::STRING_DISPLAY, PortError::display)?;
+    module.function(&["parse_port"], parse_port)?;
+    Ok(module)
+}
+
+#[test]
+fn test_try_native_result() {
+    let mut context = rune_modules::default_context().unwrap();
+    context.install(&make_native_module().unwrap()).unwrap();
+    let context = Arc::new(context);
+
+    let source = r#"
+    fn next_port(input) {
+        let port = native_crate::parse_port(input)?;
+        Ok(port + 1)
+    }
+
+    pub fn main() {
+        let ok = match next_port("8080") {
+            Ok(port) => port,
+            Err(error) => panic("expected a port"),
+        };
+
+        let (input, message) = match next_port("not a port") {
+            Ok(port) => panic("expected an error"),
+            Err(error) => (error.input, `${error}`),
+        };
+
+        (ok, input, message)
+    }
+    "#;
+
+    let output: (i64, String, String) =
+        run_with_diagnostics(&context, source, &["main"], ()).unwrap();
+
+    assert_eq!(
+        output,
+        (
+            8081,
+            String::from("not a port"),
+            String::from("`not a port` is not a valid port")
+        )
+    );
+}
@@ -14,3 +14,52 @@ fn test_mutate_tuples() {
         "Now You Don't !",
     };
 }
+
+#[test]
+fn test_tuple_value_roundtrip() {
+    let value = (1i64, String::from("two"), true).to_value().unwrap();
+    let tuple = <(i64, String, bool)>::from_value(value).unwrap();
+    assert_eq!(tuple, (1, String::from("two"), true));
+
+    let tuple = (
+        1i64, 2i64, 3i64, 4i64, 5i64, 6i64, 7i64, 8i64, 9i64, 10i64, 11i64, 12i64,
+    );
+    let value = tuple.to_value().unwrap();
+    let tuple2 =
+        <(i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64, i64)>::from_value(value).unwrap();
+    assert_eq!(tuple, tuple2);
+
+    let value = (1i64, 2i64).to_value().unwrap();
+
+    match <(i64, i64, i64)>::from_value(value)
+        .unwrap_err()
+        .into_kind()
+    {
+        ExpectedTupleLength { actual, expected } => {
+            assert_eq!(actual, 2);
+            assert_eq!(expected, 3);
+        }
+        kind => panic!("unexpected error: {:?}", kind),
+    }
+}
+
+#[test]
+fn test_tuple_from_native() {
+    fn native() -> (i64, String, bool) {
+        (42, String::from("answer"), true)
+    }
+
+    let mut module = runestick::Module::new();
+    module.function(&["native"], native).unwrap();
+
+    let out: (i64, String, bool) = rune_n! {
+        module,
+        (),
+        (i64, String, bool) => pub fn main() {
+            let (a, b, c) = native();
+            (a + 1, b, !c)
+        }
+    };
+
+    assert_eq!(out, (43, String::from("answer"), false));
+}
@@ -0,0 +1,188 @@
+use rune_tests::*;
+
+#[test]
+fn test_vec_repeat() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [0; 3] }),
+        vec![0, 0, 0],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { let n = 2; [n * 2; n + 1] }),
+        vec![4, 4, 4],
+    };
+
+    assert_eq! {
+        rune!(usize => pub fn main() { [1; 0].len() }),
+        0,
+    };
+
+    // NB: containers are cloned for each element.
+    assert_eq! {
+        rune! { (Vec<i64>, Vec<i64>) =>
+            pub fn main() {
+                let v = [[1]; 2];
+                v[0].push(2);
+                (v[0], v[1])
+            }
+        },
+        (vec![1, 2], vec![1]),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [0; -1] }"#,
+        ValueToIntegerCoercionError { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { [0; "3"] }"#,
+        runestick::VmErrorKind::Expected { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { [0; 1 << 60] }"#,
+        VecRepeatTooLarge { count, max } => {
+            assert_eq!(count, 1 << 60);
+            assert_eq!(max, runestick::Vec::MAX_REPEAT);
+        }
+    );
+}
+
+#[test]
+fn test_vec_spread() {
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let others = [2, 3, 4];
+                [1, ..others, 5]
+            }
+        },
+        vec![1, 2, 3, 4, 5],
+    };
+
+    assert_eq! {
+        rune! { Vec<i64> =>
+            pub fn main() {
+                let a = [1, 2];
+                let b = [];
+                [..a, ..b, 3, ..a.iter().map(|n| n * 10), 4, 5]
+            }
+        },
+        vec![1, 2, 3, 10, 20, 4, 5],
+    };
+
+    assert_eq! {
+        rune! { usize =>
+            pub fn main() {
+                let others = [1, 2, 3];
+                let v = [0, ..others, ..others];
+                others.push(4);
+                v.len()
+            }
+        },
+        7,
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { let n = 1; [0, ..n] }"#,
+        MissingFunction { .. } => {}
+    );
+
+    // NB: a range without a start has to be grouped to be an element.
+    assert_vm_error!(
+        r#"pub fn main() { [..5] }"#,
+        MissingFunction { .. } => {}
+    );
+
+    rune! { () =>
+        pub fn main() {
+            assert_eq!([(..5)][0].end, Some(5));
+            assert_eq!([..][0].end, None);
+            assert_eq!([1, ..][1].start, None);
+        }
+    };
+}
+
+#[test]
+fn test_vec_set_operations() {
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [3, 1, 2, 1, 4].intersection([1, 4, 4, 5]) }),
+        vec![1, 4],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [3, 1, 3].union([2, 1, 4, 2]) }),
+        vec![3, 1, 2, 4],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [3, 1, 2, 3, 5].difference([2, 5]) }),
+        vec![3, 1],
+    };
+
+    assert_eq! {
+        rune!(Vec<i64> => pub fn main() { [].union([]) }),
+        Vec::<i64>::new(),
+    };
+
+    // NB: floats can't be hashed, so these fall back to pairwise comparisons.
+    assert_eq! {
+        rune!(Vec<f64> => pub fn main() { [1.0, 2.0, 2.0].intersection([2.0, 3.0]) }),
+        vec![2.0],
+    };
+
+    assert_eq! {
+        rune!(Vec<f64> => pub fn main() { [1.0, 2.0].union([2.0, 3.0, 3.0]) }),
+        vec![1.0, 2.0, 3.0],
+    };
+
+    assert_eq! {
+        rune!(Vec<f64> => pub fn main() { [1.0, 2.0, 1.0].difference([2.0]) }),
+        vec![1.0],
+    };
+}
+
+#[test]
+fn test_vec_position_and_find() {
+    assert_eq! {
+        rune!(Option<usize> => pub fn main() { [1, 3, 4, 5, 6].position(|n| n % 2 == 0) }),
+        Some(2),
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() { [1, 3, 4, 5, 6].find(|n| n % 2 == 0) }),
+        Some(4),
+    };
+
+    assert_eq! {
+        rune!(Option<usize> => pub fn main() { [1, 3, 5].position(|n| n % 2 == 0) }),
+        None,
+    };
+
+    assert_eq! {
+        rune!(Option<i64> => pub fn main() { [].find(|n| n % 2 == 0) }),
+        None,
+    };
+
+    // NB: the predicate isn't called after the first match.
+    assert_eq! {
+        rune! { (Option<i64>, Vec<i64>) =>
+            pub fn main() {
+                let seen = [];
+                let found = [1, 2, 3, 4].find(|n| { seen.push(n); n % 2 == 0 });
+                (found, seen)
+            }
+        },
+        (Some(2), vec![1, 2]),
+    };
+
+    assert_eq! {
+        rune!(Option<usize> => pub fn main() { [2, "two"].position(|n| n % 2 == 0) }),
+        Some(0),
+    };
+
+    assert_vm_error!(
+        r#"pub fn main() { [1, "two", 4].position(|n| n % 2 == 0) }"#,
+        UnsupportedBinaryOperation { op: "%", .. } => {}
+    );
+}
@@ -0,0 +1,114 @@
+use rune_tests::*;
+use runestick::{Context, Object, Shared, Span, Vm, VmDebugState, VmHalt};
+use std::sync::Arc;
+
+#[test]
+fn test_watchpoint() {
+    let context = Context::with_default_modules().unwrap();
+
+    let source = r#"
+    pub fn main(object) {
+        object.a = 1;
+        object.b = 2;
+        object.a = 3;
+        object.b += 10;
+        object["b"] = 42;
+        object["b"] += 1;
+        object.a
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let shared = Shared::new(Object::new());
+    let object = Value::Object(shared.clone());
+    let other = Value::Object(Shared::new(Object::new()));
+
+    let mut execution = vm.execute(&["main"], (object.clone(),)).unwrap();
+    execution.vm_mut().unwrap().watch(&object, "b");
+    execution.vm_mut().unwrap().watch(&other, "a");
+
+    let mut halts = Vec::new();
+
+    let output = loop {
+        match execution.resume_debug().unwrap() {
+            VmDebugState::Complete(output) => break output,
+            VmDebugState::Halted(VmHalt::Watchpoint { span, .. }) => {
+                let span = span.unwrap();
+                let b = shared.borrow_ref().unwrap().get("b").cloned().unwrap();
+                halts.push((span, i64::from_value(b).unwrap()));
+            }
+            state => panic!("unexpected state: {:?}", state),
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 3);
+
+    // The span of the assigned-to expression in the given statement.
+    let span_of = |statement: &str, target: &str| {
+        let start = source.find(statement).unwrap();
+        Span::new(start, start + target.len())
+    };
+
+    assert_eq!(
+        halts,
+        vec![
+            (span_of("object.b = 2", "object.b"), 2),
+            (span_of("object.b += 10", "object.b += 10"), 12),
+            (span_of("object[\"b\"] = 42", "object[\"b\"]"), 42),
+            (span_of("object[\"b\"] += 1", "object[\"b\"] += 1"), 43),
+        ]
+    );
+}
+
+#[test]
+fn test_watchpoint_index() {
+    let context = Context::with_default_modules().unwrap();
+
+    let source = r#"
+    pub fn main(vec) {
+        vec[0] = 1;
+        vec[1] = 2;
+        vec[0] += 10;
+        vec[0]
+    }
+    "#;
+
+    let (unit, _) = compile_source(&context, source).unwrap();
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let vec = Value::vec(vec![Value::Unit, Value::Unit]);
+
+    let mut execution = vm.execute(&["main"], (vec.clone(),)).unwrap();
+    execution.vm_mut().unwrap().watch(&vec, "0");
+
+    let mut halts = 0;
+
+    let output = loop {
+        match execution.resume_debug().unwrap() {
+            VmDebugState::Complete(output) => break output,
+            VmDebugState::Halted(VmHalt::Watchpoint { .. }) => halts += 1,
+            state => panic!("unexpected state: {:?}", state),
+        }
+    };
+
+    assert_eq!(i64::from_value(output).unwrap(), 11);
+    assert_eq!(halts, 2);
+}
+
+#[test]
+fn test_watchpoint_send_execute() {
+    let context = Context::with_default_modules().unwrap();
+    let (unit, _) = compile_source(&context, r#"pub fn main() { 42 }"#).unwrap();
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let object = Value::Object(Shared::new(Object::new()));
+    vm.watch(&object, "a");
+
+    let error = vm.clone().send_execute(&["main"], ()).err().unwrap();
+    assert!(matches!(error.into_kind(), UnsendableDebugState));
+
+    assert!(vm.unwatch(&object, "a"));
+    assert!(vm.send_execute(&["main"], ()).is_ok());
+}
@@ -0,0 +1,45 @@
+use rune_tests::*;
+
+#[test]
+fn test_while_let_range() {
+    let out = rune! { i64 =>
+        pub fn main() {
+            let it = (0..10).iter();
+            let out = 0;
+
+            while let Some(n) = it.next() {
+                out += n;
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(out, 45);
+}
+
+#[test]
+fn test_while_let_scope() {
+    let out = rune! { i64 =>
+        pub fn main() {
+            let n = 100;
+            let it = (0..3).iter();
+
+            while let Some(n) = it.next() {
+                n;
+            }
+
+            n
+        }
+    };
+
+    assert_eq!(out, 100);
+
+    assert_compile_error! {
+        r#"pub fn main() { let it = (0..3).iter(); while let Some(n) = it.next() {} n }"#,
+        span, MissingLocal { name } => {
+            assert_eq!(span, Span::new(73, 74));
+            assert_eq!(name, "n");
+        }
+    };
+}